@@ -11,7 +11,7 @@
 //! and to eventually come up with new optimization tricks
 //! for the macro generated code while staying correct.
 
-#![allow(dead_code)]
+#![allow(dead_code, clippy::new_without_default)]
 
 use modular_bitfield::prelude::*;
 
@@ -86,7 +86,7 @@ impl Handwritten {
         let mut res = 0;
         res |= (self.data[1] >> 7) as u16;
         res |= (self.data[2] as u16) << 1;
-        res |= (((self.data[3] & 0b0000_1111) as u16) << 9) as u16;
+        res |= ((self.data[3] & 0b0000_1111) as u16) << 9;
         res
     }
 
@@ -4,6 +4,7 @@ use super::{
         ReprKind,
     },
     field_config::{
+        DefaultValue,
         FieldConfig,
         SkipWhich,
     },
@@ -22,13 +23,17 @@ use syn::{
 impl TryFrom<(&mut Config, syn::ItemStruct)> for BitfieldStruct {
     type Error = syn::Error;
 
-    fn try_from((config, item_struct): (&mut Config, syn::ItemStruct)) -> Result<Self> {
+    fn try_from((config, mut item_struct): (&mut Config, syn::ItemStruct)) -> Result<Self> {
         Self::ensure_has_fields(&item_struct)?;
-        Self::ensure_no_generics(&item_struct)?;
+        Self::ensure_supported_generics(&item_struct, config)?;
         Self::extract_attributes(&item_struct.attrs, config)?;
         Self::analyse_config_for_fields(&item_struct, config)?;
+        Self::replace_none_field_types(&mut item_struct, config)?;
+        Self::ensure_supported_zerocopy_derives(&item_struct, config)?;
         config.ensure_no_conflicts()?;
-        Ok(Self { item_struct })
+        let bitfield = Self { item_struct };
+        bitfield.ensure_unique_reflect_variants(config)?;
+        Ok(bitfield)
     }
 }
 
@@ -44,12 +49,31 @@ impl BitfieldStruct {
         Ok(())
     }
 
-    /// Returns an error if the input struct is generic.
-    fn ensure_no_generics(item_struct: &syn::ItemStruct) -> Result<()> {
-        if !item_struct.generics.params.is_empty() {
+    /// Returns an error if the input struct has unsupported generics.
+    ///
+    /// Only generic type parameters are supported. Since the size of the underlying
+    /// byte array cannot depend on generic parameters a generic bitfield struct must
+    /// specify its bit width via the `bits = N` parameter.
+    fn ensure_supported_generics(
+        item_struct: &syn::ItemStruct,
+        config: &Config,
+    ) -> Result<()> {
+        let generics = &item_struct.generics;
+        if generics.params.is_empty() {
+            return Ok(())
+        }
+        for param in &generics.params {
+            if !matches!(param, syn::GenericParam::Type(_)) {
+                return Err(format_err_spanned!(
+                    param,
+                    "encountered invalid generic bitfield struct: only type parameters are supported"
+                ))
+            }
+        }
+        if config.bits.is_none() {
             return Err(format_err_spanned!(
-                item_struct,
-                "encountered invalid generic bitfield struct"
+                generics,
+                "generic bitfield structs require a `bits = N` parameter for their bit width"
             ))
         }
         Ok(())
@@ -106,7 +130,7 @@ impl BitfieldStruct {
         Ok(())
     }
 
-    /// Extracts the `#[derive(Debug)]` annotations from the given `#[bitfield]` struct.
+    /// Extracts the `#[derive(Debug)]`, `#[derive(Default)]` and other annotations from the given `#[bitfield]` struct.
     fn extract_derive_debug_attribute(
         attr: &syn::Attribute,
         config: &mut Config,
@@ -121,9 +145,22 @@ impl BitfieldStruct {
                 syn::NestedMeta::Meta(syn::Meta::Path(path)) => {
                     if path.is_ident("Debug") {
                         config.derive_debug(meta_span)?;
+                    } else if path.is_ident("Default") {
+                        config.derive_default(meta_span)?;
+                    } else if cfg!(feature = "defmt") && Self::is_defmt_format_derive(&path) {
+                        config.derive_defmt(meta_span)?;
                     } else if path.is_ident("BitfieldSpecifier") {
                         config.derive_specifier(meta_span)?;
                     } else {
+                        if path.is_ident("Copy") {
+                            config.derive_copy(meta_span)?;
+                        }
+                        if cfg!(feature = "zerocopy") && Self::is_zerocopy_derive(&path) {
+                            config.derive_zerocopy(meta_span);
+                            if Self::is_zerocopy_bytes_derive(&path) {
+                                config.derive_zerocopy_bytes(meta_span);
+                            }
+                        }
                         // Other derives are going to be re-expanded them into a new
                         // `#[derive(..)]` that is ignored by the rest of this macro.
                         retained_derives
@@ -150,6 +187,108 @@ impl BitfieldStruct {
         Ok(())
     }
 
+    /// Returns `true` if the derive path refers to `defmt::Format`.
+    fn is_defmt_format_derive(path: &syn::Path) -> bool {
+        path.is_ident("Format")
+            || (path.segments.len() == 2
+                && path.segments[0].ident == "defmt"
+                && path.segments[1].ident == "Format")
+    }
+
+    /// Returns `true` if the derive path refers to one of the `zerocopy` traits.
+    ///
+    /// Paths such as `zerocopy::FromBytes` are matched by their last segment.
+    fn is_zerocopy_derive(path: &syn::Path) -> bool {
+        const ZEROCOPY_TRAITS: &[&str] = &[
+            "FromBytes",
+            "FromZeros",
+            "TryFromBytes",
+            "IntoBytes",
+            "KnownLayout",
+            "Immutable",
+            "Unaligned",
+        ];
+        path.segments
+            .last()
+            .map(|segment| ZEROCOPY_TRAITS.iter().any(|name| segment.ident == name))
+            .unwrap_or(false)
+    }
+
+    /// Returns `true` if the derive path refers to one of the `zerocopy` traits that
+    /// read or write the internal bytes of the `#[bitfield]` struct.
+    fn is_zerocopy_bytes_derive(path: &syn::Path) -> bool {
+        const ZEROCOPY_BYTES_TRAITS: &[&str] =
+            &["FromBytes", "TryFromBytes", "IntoBytes", "Immutable"];
+        path.segments
+            .last()
+            .map(|segment| ZEROCOPY_BYTES_TRAITS.iter().any(|name| segment.ident == name))
+            .unwrap_or(false)
+    }
+
+    /// Returns an error if the `#[bitfield]` struct derives `zerocopy` traits but
+    /// may contain bit patterns that are invalid for it.
+    ///
+    /// The `zerocopy` traits require a filled non-generic `#[bitfield]` struct without
+    /// `#[reserved = N]` fields. The bit patterns of the individual fields are checked
+    /// by the generated code. Traits giving access to the internal bytes additionally
+    /// require the bit order to match the layout of the `endian` parameter.
+    fn ensure_supported_zerocopy_derives(
+        item_struct: &syn::ItemStruct,
+        config: &Config,
+    ) -> Result<()> {
+        let derive = match &config.derive_zerocopy {
+            Some(derive) => derive,
+            None => return Ok(()),
+        };
+        if !config.filled_enabled() {
+            return Err(format_err!(
+                derive.span,
+                "encountered invalid zerocopy derive for a bitfield struct with `filled = false`: \
+                 zerocopy derives require all bit patterns to be valid"
+            ))
+        }
+        if !item_struct.generics.params.is_empty() {
+            return Err(format_err!(
+                derive.span,
+                "encountered invalid zerocopy derive for a generic bitfield struct"
+            ))
+        }
+        let reserved = config
+            .field_configs
+            .iter()
+            .filter_map(|(index, field_config)| {
+                field_config.value.reserved.as_ref().map(|reserved| (index, reserved))
+            })
+            .min_by_key(|(index, _)| *index)
+            .map(|(_, reserved)| reserved);
+        if let Some(reserved) = reserved {
+            return Err(format_err!(
+                derive.span,
+                "encountered invalid zerocopy derive for a bitfield struct with #[reserved = N] fields: \
+                 zerocopy derives require all bit patterns to be valid"
+            )
+            .into_combine(format_err!(reserved.span, "#[reserved = N] field here")))
+        }
+        if let (Some(derive), Some(bit_order)) =
+            (&config.derive_zerocopy_bytes, &config.bit_order)
+        {
+            if !config.bit_order_matches_endian() {
+                return Err(format_err!(
+                    derive.span,
+                    "encountered invalid zerocopy derive for a bitfield struct with `bit_order = {}`: \
+                     zerocopy derives require the bit order to match the `endian` parameter",
+                    bit_order.value,
+                )
+                .into_combine(format_err!(
+                    bit_order.span,
+                    "`bit_order = {}` here",
+                    bit_order.value,
+                )))
+            }
+        }
+        Ok(())
+    }
+
     /// Analyses and extracts the `#[repr(uN)]` or other annotations from the given struct.
     fn extract_attributes(
         attributes: &[syn::Attribute],
@@ -180,11 +319,134 @@ impl BitfieldStruct {
         Ok(())
     }
 
+    /// Returns `true` if the arguments of the attribute are enclosed in parentheses.
+    fn is_parenthesized(attr: &syn::Attribute) -> bool {
+        matches!(
+            attr.tokens.clone().into_iter().next(),
+            Some(proc_macro2::TokenTree::Group(group))
+                if group.delimiter() == proc_macro2::Delimiter::Parenthesis
+        )
+    }
+
+    /// Extracts the first and last bit of a `#[bits(N..=M)]` or `#[bits(N..M)]` attribute.
+    ///
+    /// # Errors
+    ///
+    /// If the range is not made of integer literals or does not contain any bits.
+    fn extract_bit_range(range: &syn::ExprRange) -> Result<(usize, usize)> {
+        fn extract_bound(bound: Option<&syn::Expr>, range: &syn::ExprRange) -> Result<usize> {
+            match bound {
+                Some(syn::Expr::Lit(syn::ExprLit {
+                    lit: syn::Lit::Int(lit_int),
+                    ..
+                })) => lit_int.base10_parse::<usize>(),
+                _ => {
+                    Err(format_err_spanned!(
+                        range,
+                        "encountered invalid bit range, expected #[bits(N..=M)] with integer literals"
+                    ))
+                }
+            }
+        }
+        let start = extract_bound(range.from.as_deref(), range)?;
+        let end = extract_bound(range.to.as_deref(), range)?;
+        let last = match range.limits {
+            syn::RangeLimits::Closed(_) => Some(end),
+            syn::RangeLimits::HalfOpen(_) => end.checked_sub(1),
+        };
+        match last {
+            Some(last) if last >= start => Ok((start, last)),
+            _ => {
+                Err(format_err_spanned!(
+                    range,
+                    "encountered empty bit range for field"
+                ))
+            }
+        }
+    }
+
+    /// Replaces the `Option<T>` type of fields with a `#[none = N]` attribute
+    /// by the specifier that encodes `None` as the bit pattern `N`.
+    ///
+    /// # Errors
+    ///
+    /// If a field with a `#[none = N]` attribute is not of type `Option<T>`.
+    fn replace_none_field_types(
+        item_struct: &mut syn::ItemStruct,
+        config: &Config,
+    ) -> Result<()> {
+        for (index, field) in item_struct.fields.iter_mut().enumerate() {
+            let none = match config
+                .field_configs
+                .get(&index)
+                .and_then(|field_config| field_config.value.none.as_ref())
+            {
+                Some(none) => none,
+                None => continue,
+            };
+            let inner = Self::option_inner_type(&field.ty).ok_or_else(|| {
+                format_err!(
+                    none.span,
+                    "encountered #[none = N] attribute on a field that is not of type Option<T>"
+                )
+            })?;
+            let pattern = syn::LitInt::new(&format!("{}_u128", none.value), none.span);
+            field.ty = syn::parse_quote_spanned!(field.ty.span()=>
+                ::modular_bitfield::private::NoneAt<#inner, #pattern>
+            );
+        }
+        Ok(())
+    }
+
+    /// Returns `T` if the given type is `Option<T>`.
+    fn option_inner_type(ty: &syn::Type) -> Option<syn::Type> {
+        let path = match ty {
+            syn::Type::Path(type_path) if type_path.qself.is_none() => &type_path.path,
+            _ => return None,
+        };
+        let segment = path.segments.last()?;
+        if segment.ident != "Option" {
+            return None
+        }
+        match &segment.arguments {
+            syn::PathArguments::AngleBracketed(args) if args.args.len() == 1 => {
+                match &args.args[0] {
+                    syn::GenericArgument::Type(inner) => Some(inner.clone()),
+                    _ => None,
+                }
+            }
+            _ => None,
+        }
+    }
+
     /// Extracts the `#[bits = N]` and `#[skip(..)]` attributes for a given field.
     fn extract_field_config(field: &syn::Field) -> Result<FieldConfig> {
         let mut config = FieldConfig::default();
         for attr in &field.attrs {
-            if attr.path.is_ident("bits") {
+            if attr.path.is_ident("bits") && Self::is_parenthesized(attr) {
+                let range = attr.parse_args::<syn::ExprRange>()?;
+                let span = attr.span();
+                let (start, end) = Self::extract_bit_range(&range)?;
+                config.at(start, span)?;
+                config.bits(end - start + 1, span)?;
+            } else if attr.path.is_ident("at") {
+                let path = &attr.path;
+                let args = &attr.tokens;
+                let name_value: syn::MetaNameValue =
+                    syn::parse2::<_>(quote! { #path #args })?;
+                let span = name_value.span();
+                match name_value.lit {
+                    syn::Lit::Int(lit_int) => {
+                        config.at(lit_int.base10_parse::<usize>()?, span)?;
+                    }
+                    _ => {
+                        return Err(format_err!(
+                            span,
+                            "encountered invalid value type for #[at = N]"
+                        ))
+                    }
+                }
+            } else if attr.path.is_ident("bits") {
                 let path = &attr.path;
                 let args = &attr.tokens;
                 let name_value: syn::MetaNameValue =
@@ -201,6 +463,89 @@ impl BitfieldStruct {
                         ))
                     }
                 }
+            } else if attr.path.is_ident("reserved") {
+                let path = &attr.path;
+                let args = &attr.tokens;
+                let name_value: syn::MetaNameValue =
+                    syn::parse2::<_>(quote! { #path #args })?;
+                let span = name_value.span();
+                match name_value.lit {
+                    syn::Lit::Int(lit_int) => {
+                        config.reserved(lit_int.base10_parse::<u128>()?, span)?;
+                    }
+                    _ => {
+                        return Err(format_err!(
+                            span,
+                            "encountered invalid value type for #[reserved = N]"
+                        ))
+                    }
+                }
+            } else if attr.path.is_ident("default") {
+                let path = &attr.path;
+                let args = &attr.tokens;
+                let name_value: syn::MetaNameValue =
+                    syn::parse2::<_>(quote! { #path #args })?;
+                let span = name_value.span();
+                match name_value.lit {
+                    syn::Lit::Int(lit_int) => {
+                        let digits = lit_int.base10_digits();
+                        let value = match digits.strip_prefix('-') {
+                            Some(digits) => {
+                                DefaultValue {
+                                    magnitude: digits.parse::<u128>().map_err(|err| {
+                                        format_err!(lit_int.span(), "{}", err)
+                                    })?,
+                                    negative: true,
+                                }
+                            }
+                            None => {
+                                DefaultValue {
+                                    magnitude: lit_int.base10_parse::<u128>()?,
+                                    negative: false,
+                                }
+                            }
+                        };
+                        config.default_value(value, span)?;
+                    }
+                    syn::Lit::Bool(lit_bool) => {
+                        let value = DefaultValue {
+                            magnitude: lit_bool.value as u128,
+                            negative: false,
+                        };
+                        config.default_value(value, span)?;
+                    }
+                    _ => {
+                        return Err(format_err!(
+                            span,
+                            "encountered invalid value type for #[default = N]"
+                        ))
+                    }
+                }
+            } else if attr.path.is_ident("const_fn") {
+                if !attr.tokens.is_empty() {
+                    return Err(format_err!(
+                        attr.span(),
+                        "encountered invalid format for #[const_fn] field attribute"
+                    ))
+                }
+                config.const_fn(attr.span())?;
+            } else if attr.path.is_ident("none") {
+                let path = &attr.path;
+                let args = &attr.tokens;
+                let name_value: syn::MetaNameValue =
+                    syn::parse2::<_>(quote! { #path #args })?;
+                let span = name_value.span();
+                match name_value.lit {
+                    syn::Lit::Int(lit_int) => {
+                        config.none(lit_int.base10_parse::<u128>()?, span)?;
+                    }
+                    _ => {
+                        return Err(format_err!(
+                            span,
+                            "encountered invalid value type for #[none = N]"
+                        ))
+                    }
+                }
             } else if attr.path.is_ident("endian") {
                 let path = &attr.path;
                 let args = &attr.tokens;
@@ -291,6 +636,13 @@ impl BitfieldStruct {
                 config.retain_attr(attr.clone());
             }
         }
+        if let (Some(reserved), Some(default)) = (&config.reserved, &config.default) {
+            return Err(format_err!(
+                default.span,
+                "encountered #[default = N] attribute on a #[reserved = M] field"
+            )
+            .into_combine(format_err!(reserved.span, "#[reserved = M] here")))
+        }
         Ok(config)
     }
 }
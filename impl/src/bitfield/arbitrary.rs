@@ -0,0 +1,288 @@
+use super::{
+    config::ArbitraryMode,
+    field_info::FieldInfo,
+    generics::FieldBounds,
+    BitfieldStruct,
+    Config,
+    Endian,
+};
+use proc_macro2::TokenStream as TokenStream2;
+use quote::{
+    format_ident,
+    quote_spanned,
+};
+use syn::spanned::Spanned as _;
+
+impl BitfieldStruct {
+    /// Returns the fields that are set by the generated `__bf_from_raw_fields` constructor.
+    ///
+    /// These are all fields that have setters.
+    fn raw_fields<'a>(&'a self, config: &'a Config) -> impl Iterator<Item = FieldInfo<'a>> {
+        self.field_infos(config)
+            .filter(|info| !info.config.skip_setters())
+    }
+
+    /// Returns `true` if the `__bf_from_raw_fields` constructor is generated.
+    ///
+    /// It is used by `Specifier::valid_bit_pattern` of non-generic `#[bitfield]` specifiers
+    /// and for generating values with `arbitrary = "fields"` or `proptest = "fields"`.
+    /// Since `valid_bit_pattern` is only used to generate values, the former requires the
+    /// `arbitrary` or `proptest` crate feature.
+    fn has_raw_fields_constructor(&self, config: &Config) -> bool {
+        let fields_mode = |mode: Option<ArbitraryMode>| mode == Some(ArbitraryMode::Fields);
+        let generates_values = cfg!(feature = "arbitrary") || cfg!(feature = "proptest");
+        (generates_values && config.derive_specifier.is_some() && !self.is_generic())
+            || fields_mode(config.arbitrary.as_ref().map(|config| config.value))
+            || fields_mode(config.proptest.as_ref().map(|config| config.value))
+    }
+
+    /// Generates the `valid_bit_pattern` method of the `Specifier` impl of the `#[bitfield]` struct.
+    ///
+    /// Returns `None` for generic `#[bitfield]` structs which keep the default.
+    pub fn generate_valid_bit_pattern(&self, config: &Config) -> Option<TokenStream2> {
+        if !self.has_raw_fields_constructor(config) || self.is_generic() {
+            return None
+        }
+        let span = self.item_struct.span();
+        Some(quote_spanned!(span=>
+            #[inline]
+            fn valid_bit_pattern(raw: ::core::primitive::u128) -> ::core::option::Option<Self::Bytes> {
+                let mut __bf_raw = raw;
+                let __bf_bitfield = Self::__bf_from_raw_fields(&mut |__bf_bits| {
+                    let __bf_field_raw = __bf_raw;
+                    __bf_raw = __bf_raw.checked_shr(__bf_bits as ::core::primitive::u32).unwrap_or(0);
+                    __bf_field_raw
+                })?;
+                <Self as ::modular_bitfield::Specifier>::into_bytes(__bf_bitfield).ok()
+            }
+        ))
+    }
+
+    /// Generates the private constructor setting every field with setters to the
+    /// valid bit pattern of its specifier for the raw bits requested from `__bf_raw`.
+    ///
+    /// All other fields keep the values of `new`.
+    fn generate_raw_fields_constructor(&self, config: &Config) -> Option<TokenStream2> {
+        if !self.has_raw_fields_constructor(config) {
+            return None
+        }
+        let span = self.item_struct.span();
+        let ident = &self.item_struct.ident;
+        let (impl_generics, ty_generics, _) = self.item_struct.generics.split_for_impl();
+        let where_clause = self.generate_where_clause(config, FieldBounds::Access);
+        let set_fields = self.raw_fields(config).map(|info| {
+            let span = info.field.span();
+            let ty = info.specifier_ty();
+            let set_checked_ident = format_ident!("set_{}_checked", info.ident_frag());
+            let value = quote_spanned!(span=>
+                <#ty as ::modular_bitfield::Specifier>::from_bytes(
+                    <#ty as ::modular_bitfield::Specifier>::valid_bit_pattern(
+                        __bf_raw(<#ty as ::modular_bitfield::Specifier>::BITS),
+                    )?,
+                )
+                .ok()?
+            );
+            match info.array_len() {
+                Some(len) => {
+                    quote_spanned!(span=>
+                        for __bf_index in 0..#len {
+                            __bf_bitfield.#set_checked_ident(__bf_index, #value).ok()?;
+                        }
+                    )
+                }
+                None => {
+                    quote_spanned!(span=>
+                        __bf_bitfield.#set_checked_ident(#value).ok()?;
+                    )
+                }
+            }
+        });
+        Some(quote_spanned!(span=>
+            impl #impl_generics #ident #ty_generics #where_clause {
+                #[allow(unused_mut, unused_variables)]
+                fn __bf_from_raw_fields(
+                    __bf_raw: &mut dyn ::core::ops::FnMut(::core::primitive::usize) -> ::core::primitive::u128,
+                ) -> ::core::option::Option<Self> {
+                    let mut __bf_bitfield = Self::new();
+                    #( #set_fields )*
+                    ::core::option::Option::Some(__bf_bitfield)
+                }
+            }
+        ))
+    }
+
+    /// Generates the amount of raw values requested by the `__bf_from_raw_fields` constructor.
+    fn generate_raw_fields_count(&self, config: &Config) -> TokenStream2 {
+        let span = self.item_struct.span();
+        let counts = self.raw_fields(config).map(|info| {
+            match info.array_len() {
+                Some(len) => quote_spanned!(span=> (#len)),
+                None => quote_spanned!(span=> 1usize),
+            }
+        });
+        quote_spanned!(span=> 0usize #( + #counts )*)
+    }
+
+    /// Generates the statements clearing the bits of `bytes` at positions that are undefined
+    /// for the `#[bitfield(filled = false)]` struct.
+    ///
+    /// Returns `None` for filled `#[bitfield]` structs.
+    fn generate_undefined_bits_mask(&self, config: &Config) -> Option<TokenStream2> {
+        if config.filled_enabled() {
+            return None
+        }
+        let span = self.item_struct.span();
+        let size = self.generate_target_or_actual_bitfield_size(config);
+        let next_divisible_by_8 = Self::next_divisible_by_8(&size);
+        let mask_le = quote_spanned!(span=>
+            let bytes = {
+                let mut bytes = bytes;
+                bytes[(#next_divisible_by_8 / 8usize) - 1] &=
+                    ((0x01_u16 << (8 - (#next_divisible_by_8 - #size))) - 1) as ::core::primitive::u8;
+                bytes
+            };
+        );
+        let mask_be = quote_spanned!(span=>
+            let bytes = {
+                let mut bytes = bytes;
+                bytes[(#next_divisible_by_8 / 8usize) - 1] &=
+                    !(((0x01_u16 << (#next_divisible_by_8 - #size)) - 1) as ::core::primitive::u8);
+                bytes
+            };
+        );
+        let endian = match &config.endian {
+            Some(value) => value.value,
+            None => Endian::Native,
+        };
+        Some(match endian {
+            Endian::Big => mask_be,
+            Endian::Little => mask_le,
+            Endian::Native => {
+                quote_spanned!(span=>
+                    #[cfg(target_endian = "big")]
+                    #mask_be
+
+                    #[cfg(target_endian = "little")]
+                    #mask_le
+                )
+            }
+        })
+    }
+
+    /// Generates the closure constructing the `#[bitfield]` struct from arbitrary `bytes`
+    /// for `arbitrary = "bytes"` and `proptest = "bytes"`.
+    ///
+    /// Only bits at undefined positions of `filled = false` structs are cleared.
+    fn generate_from_raw_bytes(&self, config: &Config) -> TokenStream2 {
+        let span = self.item_struct.span();
+        let size = self.generate_target_or_actual_bitfield_size(config);
+        let next_divisible_by_8 = Self::next_divisible_by_8(&size);
+        let marker_init = self.generate_marker_init();
+        let generic_checks = self.generate_generic_checks_usage();
+        let mask = self.generate_undefined_bits_mask(config);
+        quote_spanned!(span=>
+            |bytes: [::core::primitive::u8; #next_divisible_by_8 / 8usize]| {
+                #generic_checks
+                #mask
+                Self { bytes, #marker_init }
+            }
+        )
+    }
+
+    /// Generates the `arbitrary::Arbitrary` impl of the `#[bitfield]` struct.
+    ///
+    /// Returns `None` if the `arbitrary` parameter has not been set.
+    fn generate_arbitrary_impl(&self, config: &Config) -> Option<TokenStream2> {
+        let mode = config.arbitrary.as_ref()?.value;
+        let span = self.item_struct.span();
+        let ident = &self.item_struct.ident;
+        let mut generics = self.item_struct.generics.clone();
+        generics.params.insert(0, syn::parse_quote!('a));
+        let (impl_generics, _, _) = generics.split_for_impl();
+        let (_, ty_generics, _) = self.item_struct.generics.split_for_impl();
+        let where_clause = self.generate_where_clause(config, FieldBounds::Access);
+        let body = match mode {
+            ArbitraryMode::Fields => {
+                quote_spanned!(span=>
+                    ::modular_bitfield::private::arbitrary_fields(u, Self::__bf_from_raw_fields)
+                )
+            }
+            ArbitraryMode::Bytes => {
+                let size = self.generate_target_or_actual_bitfield_size(config);
+                let next_divisible_by_8 = Self::next_divisible_by_8(&size);
+                let from_raw_bytes = self.generate_from_raw_bytes(config);
+                quote_spanned!(span=>
+                    let mut __bf_bytes = [0x00_u8; #next_divisible_by_8 / 8usize];
+                    ::modular_bitfield::private::arbitrary::Unstructured::fill_buffer(u, &mut __bf_bytes)?;
+                    ::core::result::Result::Ok((#from_raw_bytes)(__bf_bytes))
+                )
+            }
+        };
+        Some(quote_spanned!(span=>
+            impl #impl_generics ::modular_bitfield::private::arbitrary::Arbitrary<'a> for #ident #ty_generics #where_clause {
+                #[allow(clippy::identity_op)]
+                fn arbitrary(
+                    u: &mut ::modular_bitfield::private::arbitrary::Unstructured<'a>,
+                ) -> ::modular_bitfield::private::arbitrary::Result<Self> {
+                    #body
+                }
+            }
+        ))
+    }
+
+    /// Generates the `proptest::arbitrary::Arbitrary` impl of the `#[bitfield]` struct.
+    ///
+    /// Returns `None` if the `proptest` parameter has not been set.
+    fn generate_proptest_impl(&self, config: &Config) -> Option<TokenStream2> {
+        let mode = config.proptest.as_ref()?.value;
+        let span = self.item_struct.span();
+        let ident = &self.item_struct.ident;
+        let (impl_generics, ty_generics, _) = self.item_struct.generics.split_for_impl();
+        let mut where_clause = self.generate_where_clause(config, FieldBounds::Debug);
+        for param in self.item_struct.generics.type_params() {
+            let param = &param.ident;
+            where_clause.predicates.push(syn::parse_quote_spanned!(span=>
+                #param: 'static
+            ));
+        }
+        let strategy = match mode {
+            ArbitraryMode::Fields => {
+                let count = self.generate_raw_fields_count(config);
+                quote_spanned!(span=>
+                    ::modular_bitfield::private::proptest_fields(#count, Self::__bf_from_raw_fields)
+                )
+            }
+            ArbitraryMode::Bytes => {
+                let from_raw_bytes = self.generate_from_raw_bytes(config);
+                quote_spanned!(span=>
+                    ::modular_bitfield::private::proptest_bytes(#from_raw_bytes)
+                )
+            }
+        };
+        Some(quote_spanned!(span=>
+            impl #impl_generics ::modular_bitfield::private::proptest::arbitrary::Arbitrary for #ident #ty_generics #where_clause {
+                type Parameters = ();
+                type Strategy = ::modular_bitfield::private::proptest::strategy::BoxedStrategy<Self>;
+
+                #[allow(clippy::identity_op)]
+                fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+                    #strategy
+                }
+            }
+        ))
+    }
+
+    /// Generates the `arbitrary` and `proptest` impls of the `#[bitfield]` struct
+    /// together with the constructor they share.
+    pub fn generate_arbitrary_impls(&self, config: &Config) -> TokenStream2 {
+        let span = self.item_struct.span();
+        let raw_fields_constructor = self.generate_raw_fields_constructor(config);
+        let arbitrary_impl = self.generate_arbitrary_impl(config);
+        let proptest_impl = self.generate_proptest_impl(config);
+        quote_spanned!(span=>
+            #raw_fields_constructor
+            #arbitrary_impl
+            #proptest_impl
+        )
+    }
+}
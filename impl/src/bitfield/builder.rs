@@ -0,0 +1,300 @@
+use super::{
+    field_info::FieldInfo,
+    generics::FieldBounds,
+    BitfieldStruct,
+    Config,
+};
+use proc_macro2::TokenStream as TokenStream2;
+use quote::{
+    format_ident,
+    quote_spanned,
+    ToTokens as _,
+};
+use syn::spanned::Spanned as _;
+
+impl BitfieldStruct {
+    /// Returns the fields that can be set with the builder of the `#[bitfield]` struct.
+    ///
+    /// These are all fields that have setters.
+    fn builder_fields<'a>(&'a self, config: &'a Config) -> impl Iterator<Item = FieldInfo<'a>> {
+        self.field_infos(config)
+            .filter(|info| !info.config.skip_setters())
+    }
+
+    /// Generates the `FooBuilder` of the `#[bitfield]` struct and the `Foo::builder` constructor.
+    ///
+    /// Every field without a `#[default = N]` attribute is tracked by a type parameter of the
+    /// builder that is either `Unset` or `Set`. Such fields can be set exactly once and `build`
+    /// is only implemented once all of them are `Set`.
+    ///
+    /// Returns `None` if the `builder` parameter has not been set.
+    pub fn generate_builder(&self, config: &Config) -> Option<TokenStream2> {
+        config.builder.as_ref()?;
+        let span = self.item_struct.span();
+        let ident = &self.item_struct.ident;
+        let vis = &self.item_struct.vis;
+        let builder_ident = format_ident!("{}Builder", ident);
+        let (bitfield_impl_generics, bitfield_ty_generics, struct_where_clause) =
+            self.item_struct.generics.split_for_impl();
+        let specifier_where_clause = self.generate_where_clause(config, FieldBounds::Specifier);
+        let access_where_clause = self.generate_where_clause(config, FieldBounds::Access);
+        let type_args = self
+            .item_struct
+            .generics
+            .params
+            .iter()
+            .map(|param| {
+                match param {
+                    syn::GenericParam::Type(param) => param.ident.to_token_stream(),
+                    syn::GenericParam::Const(param) => param.ident.to_token_stream(),
+                    syn::GenericParam::Lifetime(param) => param.lifetime.to_token_stream(),
+                }
+            })
+            .collect::<Vec<_>>();
+        let states = self
+            .builder_fields(config)
+            .filter(|info| info.config.default.is_none())
+            .enumerate()
+            .map(|(n, _)| format_ident!("__BfS{}", n))
+            .collect::<Vec<_>>();
+        let unset = quote_spanned!(span=> ::modular_bitfield::private::Unset);
+        let set = quote_spanned!(span=> ::modular_bitfield::private::Set);
+        // The generics of the bitfield struct followed by the given state parameters.
+        let generics_with = |states: &[&syn::Ident]| {
+            let mut generics = self.item_struct.generics.clone();
+            for state in states {
+                generics.params.push(syn::parse_quote!(#state));
+            }
+            generics
+        };
+        let builder_ty = |states: &[TokenStream2]| {
+            quote_spanned!(span=>
+                #builder_ident<#( #type_args, )* #( #states, )*>
+            )
+        };
+        let generics = generics_with(&states.iter().collect::<Vec<_>>());
+        let (impl_generics, _, _) = generics.split_for_impl();
+        let unset_builder_ty = builder_ty(&states.iter().map(|_| unset.clone()).collect::<Vec<_>>());
+        let set_builder_ty = builder_ty(&states.iter().map(|_| set.clone()).collect::<Vec<_>>());
+
+        let mut state_index = 0;
+        let setters = self.builder_fields(config).map(|info| {
+            let field_span = info.field.span();
+            let retained_attrs = &info.config.retained_attrs;
+            let field_vis = &info.field.vis;
+            let name = info.name();
+            let with_ident = format_ident!("with_{}", info.ident_frag());
+            let with_checked_ident = format_ident!("with_{}_checked", info.ident_frag());
+            let ty = info.specifier_ty();
+            let (value_ty, with, set_checked) = match info.array_len() {
+                Some(len) => {
+                    (
+                        quote_spanned!(field_span=>
+                            [<#ty as ::modular_bitfield::Specifier>::InOut; #len]
+                        ),
+                        format_ident!("with_{}_array", info.ident_frag()),
+                        format_ident!("set_{}_array_checked", info.ident_frag()),
+                    )
+                }
+                None => {
+                    (
+                        quote_spanned!(field_span=> <#ty as ::modular_bitfield::Specifier>::InOut),
+                        format_ident!("with_{}", info.ident_frag()),
+                        format_ident!("set_{}_checked", info.ident_frag()),
+                    )
+                }
+            };
+            let with_docs = format!(
+                "Sets the value of {} to the given value.\n\n\
+                 #Panics\n\n\
+                 If the given value is out of bounds for {}.",
+                name, name,
+            );
+            let checked_with_docs = format!(
+                "Sets the value of {} to the given value.\n\n\
+                 #Errors\n\n\
+                 If the given value is out of bounds for {}.",
+                name, name,
+            );
+            // Required fields turn their state from `Unset` into `Set` whereas
+            // fields with a `#[default = N]` can be set in any state.
+            let (impl_generics, self_ty, output_ty) = match info.config.default {
+                Some(_) => {
+                    let self_ty = builder_ty(
+                        &states
+                            .iter()
+                            .map(|state| quote_spanned!(span=> #state))
+                            .collect::<Vec<_>>(),
+                    );
+                    (generics.clone(), self_ty.clone(), self_ty)
+                }
+                None => {
+                    let current = state_index;
+                    state_index += 1;
+                    let generics = generics_with(
+                        &states
+                            .iter()
+                            .enumerate()
+                            .filter(|(n, _)| *n != current)
+                            .map(|(_, state)| state)
+                            .collect::<Vec<_>>(),
+                    );
+                    let with_state = |marker: &TokenStream2| {
+                        builder_ty(
+                            &states
+                                .iter()
+                                .enumerate()
+                                .map(|(n, state)| {
+                                    match n == current {
+                                        true => marker.clone(),
+                                        false => quote_spanned!(span=> #state),
+                                    }
+                                })
+                                .collect::<Vec<_>>(),
+                        )
+                    };
+                    (generics, with_state(&unset), with_state(&set))
+                }
+            };
+            let (impl_generics, _, _) = impl_generics.split_for_impl();
+            // The builder methods are `const fn` if the `with_*` methods of the bitfield are.
+            // Array fields set their elements one by one in a `while` loop which requires
+            // the elements to be `Copy`.
+            let forward_with = quote_spanned!(field_span=>
+                #builder_ident {
+                    __bf_value: self.__bf_value.#with(new_val),
+                    __bf_state: ::core::marker::PhantomData,
+                }
+            );
+            let (constness, with, with_checked) = match (info.array_len(), info.const_conversion()) {
+                (Some(len), Some(conversion)) if conversion.is_copy() => {
+                    let set_assert_msg =
+                        format!("value out of bounds for field {}.{}", ident, name);
+                    (
+                        Some(quote_spanned!(field_span=> const)),
+                        quote_spanned!(field_span=>
+                            match self.#with_checked_ident(new_val) {
+                                ::core::result::Result::Ok(__bf_builder) => __bf_builder,
+                                ::core::result::Result::Err(_) => ::core::panic!(#set_assert_msg),
+                            }
+                        ),
+                        quote_spanned!(field_span=>
+                            let mut __bf_value = self.__bf_value;
+                            let mut __bf_index: ::core::primitive::usize = 0;
+                            while __bf_index < #len {
+                                __bf_value = match __bf_value.#with_checked_ident(
+                                    __bf_index,
+                                    new_val[__bf_index],
+                                ) {
+                                    ::core::result::Result::Ok(__bf_value) => __bf_value,
+                                    ::core::result::Result::Err(__bf_err) => {
+                                        return ::core::result::Result::Err(__bf_err)
+                                    }
+                                };
+                                __bf_index += 1;
+                            }
+                            ::core::result::Result::Ok(#builder_ident {
+                                __bf_value,
+                                __bf_state: ::core::marker::PhantomData,
+                            })
+                        ),
+                    )
+                }
+                (None, Some(_)) => {
+                    (
+                        Some(quote_spanned!(field_span=> const)),
+                        forward_with,
+                        quote_spanned!(field_span=>
+                            match self.__bf_value.#with_checked_ident(new_val) {
+                                ::core::result::Result::Ok(__bf_value) => {
+                                    ::core::result::Result::Ok(#builder_ident {
+                                        __bf_value,
+                                        __bf_state: ::core::marker::PhantomData,
+                                    })
+                                }
+                                ::core::result::Result::Err(__bf_err) => {
+                                    ::core::result::Result::Err(__bf_err)
+                                }
+                            }
+                        ),
+                    )
+                }
+                _ => {
+                    (
+                        None,
+                        forward_with,
+                        quote_spanned!(field_span=>
+                            let mut __bf_value = self.__bf_value;
+                            __bf_value.#set_checked(new_val)?;
+                            ::core::result::Result::Ok(#builder_ident {
+                                __bf_value,
+                                __bf_state: ::core::marker::PhantomData,
+                            })
+                        ),
+                    )
+                }
+            };
+            quote_spanned!(field_span=>
+                impl #impl_generics #self_ty #access_where_clause {
+                    #[doc = #with_docs]
+                    #[inline]
+                    #[allow(dead_code)]
+                    #( #retained_attrs )*
+                    #field_vis #constness fn #with_ident(self, new_val: #value_ty) -> #output_ty {
+                        #with
+                    }
+
+                    #[doc = #checked_with_docs]
+                    #[inline]
+                    #[allow(dead_code)]
+                    #( #retained_attrs )*
+                    #field_vis #constness fn #with_checked_ident(
+                        self,
+                        new_val: #value_ty,
+                    ) -> ::core::result::Result<#output_ty, ::modular_bitfield::error::OutOfBounds> {
+                        #with_checked
+                    }
+                }
+            )
+        }).collect::<Vec<_>>();
+
+        let builder_docs = format!(
+            "A builder for [`{}`] that tracks which of its fields have been set.\n\n\
+             Every field without a `#[default = N]` has to be set exactly once before \
+             [`{}::build`] becomes available.",
+            ident, builder_ident,
+        );
+        let constructor_docs = format!(
+            "Returns a [`{}`] that requires every field without a `#[default = N]` to be set.",
+            builder_ident,
+        );
+        Some(quote_spanned!(span=>
+            #[doc = #builder_docs]
+            #vis struct #builder_ident #impl_generics #struct_where_clause {
+                __bf_value: #ident #bitfield_ty_generics,
+                __bf_state: ::core::marker::PhantomData<fn() -> (#( #states, )*)>,
+            }
+
+            impl #bitfield_impl_generics #ident #bitfield_ty_generics #specifier_where_clause {
+                #[doc = #constructor_docs]
+                #[inline]
+                #vis const fn builder() -> #unset_builder_ty {
+                    #builder_ident {
+                        __bf_value: Self::new(),
+                        __bf_state: ::core::marker::PhantomData,
+                    }
+                }
+            }
+
+            impl #bitfield_impl_generics #set_builder_ty #specifier_where_clause {
+                /// Returns the bitfield with the values set by the builder.
+                #[inline]
+                #vis const fn build(self) -> #ident #bitfield_ty_generics {
+                    self.__bf_value
+                }
+            }
+
+            #( #setters )*
+        ))
+    }
+}
@@ -0,0 +1,51 @@
+use super::{
+    generics::FieldBounds,
+    BitfieldStruct,
+    Config,
+};
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote_spanned;
+use syn::spanned::Spanned as _;
+
+impl BitfieldStruct {
+    /// Returns `true` if `bytemuck::Pod` is implemented for the `#[bitfield]` struct.
+    ///
+    /// This requires the `bytemuck` parameter, a bitfield that derives `Copy` and no
+    /// user provided `#[repr(..)]` since the struct is made `#[repr(transparent)]`.
+    pub fn implements_pod(&self, config: &Config) -> bool {
+        config.bytemuck.is_some()
+            && config.derive_copy.is_some()
+            && !config.retains_repr()
+    }
+
+    /// Generates the `bytemuck::Zeroable` and `bytemuck::Pod` impls for `#[bitfield]` structs.
+    ///
+    /// Returns `None` if the `bytemuck` parameter has not been set. The parameter conflicts
+    /// with `filled = false` since not all bit patterns of such bitfields are valid.
+    pub fn generate_bytemuck_impls(&self, config: &Config) -> Option<TokenStream2> {
+        config.bytemuck.as_ref()?;
+        let span = self.item_struct.span();
+        let ident = &self.item_struct.ident;
+        let (impl_generics, ty_generics, _) = self.item_struct.generics.split_for_impl();
+        let where_clause = self.generate_where_clause(config, FieldBounds::Specifier);
+        let pod_impl = self.implements_pod(config).then(|| {
+            let mut where_clause = where_clause.clone();
+            for param in self.item_struct.generics.type_params() {
+                let param = &param.ident;
+                where_clause.predicates.push(syn::parse_quote_spanned!(span=>
+                    #param: ::core::marker::Copy + 'static
+                ));
+            }
+            quote_spanned!(span=>
+                #[allow(unsafe_code)]
+                unsafe impl #impl_generics ::modular_bitfield::private::bytemuck::Pod for #ident #ty_generics #where_clause {}
+            )
+        });
+        Some(quote_spanned!(span=>
+            #[allow(unsafe_code)]
+            unsafe impl #impl_generics ::modular_bitfield::private::bytemuck::Zeroable for #ident #ty_generics #where_clause {}
+
+            #pod_impl
+        ))
+    }
+}
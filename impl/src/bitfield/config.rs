@@ -20,9 +20,22 @@ pub struct Config {
     pub bits: Option<ConfigValue<usize>>,
     pub filled: Option<ConfigValue<bool>>,
     pub endian: Option<ConfigValue<Endian>>,
+    pub bit_order: Option<ConfigValue<BitOrder>>,
+    pub views: Option<ConfigValue<()>>,
+    pub builder: Option<ConfigValue<()>>,
+    pub reflect: Option<ConfigValue<()>>,
+    pub bytemuck: Option<ConfigValue<()>>,
+    pub serde: Option<ConfigValue<SerdeMode>>,
+    pub arbitrary: Option<ConfigValue<ArbitraryMode>>,
+    pub proptest: Option<ConfigValue<ArbitraryMode>>,
     pub repr: Option<ConfigValue<ReprKind>>,
     pub derive_debug: Option<ConfigValue<()>>,
+    pub derive_default: Option<ConfigValue<()>>,
+    pub derive_defmt: Option<ConfigValue<()>>,
     pub derive_specifier: Option<ConfigValue<()>>,
+    pub derive_copy: Option<ConfigValue<()>>,
+    pub derive_zerocopy: Option<ConfigValue<()>>,
+    pub derive_zerocopy_bytes: Option<ConfigValue<()>>,
     pub retained_attributes: Vec<syn::Attribute>,
     pub field_configs: HashMap<usize, ConfigValue<FieldConfig>>,
 }
@@ -97,6 +110,86 @@ impl fmt::Display for Endian {
     }
 }
 
+/// Bit numbering of a `#[bitfield]` struct.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum BitOrder {
+    /// Bit 0 is the least significant bit of the first byte.
+    Lsb0,
+    /// Bit 0 is the most significant bit of the first byte.
+    Msb0,
+}
+
+impl BitOrder {
+    /// Returns the endianness whose layout implies this bit numbering.
+    pub fn natural_endian(self) -> Endian {
+        match self {
+            BitOrder::Lsb0 => Endian::Little,
+            BitOrder::Msb0 => Endian::Big,
+        }
+    }
+}
+
+impl TryFrom<String> for BitOrder {
+    type Error = ::syn::Error;
+
+    fn try_from(value: String) -> std::result::Result<Self, Self::Error> {
+        match value.as_str() {
+            "lsb0" => Ok(BitOrder::Lsb0),
+            "msb0" => Ok(BitOrder::Msb0),
+            invalid => {
+                Err(format_err!(
+                invalid,
+                "encountered invalid value argument for #[bitfield] `bit_order` parameter",
+                ))
+            }
+        }
+    }
+}
+
+impl fmt::Display for BitOrder {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", match self {
+            BitOrder::Lsb0 => "lsb0",
+            BitOrder::Msb0 => "msb0"
+        })
+    }
+}
+
+/// Representation of a `#[bitfield]` struct for `serde`.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum SerdeMode {
+    /// A map of the field names to their values.
+    Fields,
+    /// The underlying byte array as returned by `into_bytes`.
+    Bytes,
+}
+
+impl TryFrom<String> for SerdeMode {
+    type Error = ::syn::Error;
+
+    fn try_from(value: String) -> std::result::Result<Self, Self::Error> {
+        match value.as_str() {
+            "fields" => Ok(SerdeMode::Fields),
+            "bytes" => Ok(SerdeMode::Bytes),
+            invalid => {
+                Err(format_err!(
+                invalid,
+                "encountered invalid value argument for #[bitfield] `serde` parameter",
+                ))
+            }
+        }
+    }
+}
+
+/// How arbitrary values of a `#[bitfield]` struct are generated by `arbitrary` and `proptest`.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum ArbitraryMode {
+    /// Every field is set to a valid bit pattern of its specifier.
+    Fields,
+    /// The underlying byte array is generated as is, including invalid bit patterns.
+    Bytes,
+}
+
 /// A configuration value and its originating span.
 #[derive(Clone)]
 pub struct ConfigValue<T> {
@@ -195,11 +288,98 @@ impl Config {
         Ok(())
     }
 
+    /// Returns `true` if the internal bytes of the bitfield are the bytes of `into_bytes`.
+    ///
+    /// This is the case unless the `bit_order` parameter differs from the layout of the
+    /// `endian` parameter and `into_bytes` and `from_bytes` have to reverse the bits.
+    pub fn bit_order_matches_endian(&self) -> bool {
+        match &self.bit_order {
+            Some(bit_order) => {
+                matches!(
+                    (bit_order.value, self.endian.as_ref().map(|endian| endian.value)),
+                    (BitOrder::Lsb0, Some(Endian::Little)) | (BitOrder::Msb0, Some(Endian::Big))
+                )
+            }
+            None => true,
+        }
+    }
+
+    /// Borrowed views read and write fields in place and thus cannot reverse the bits
+    /// of a `bit_order` that differs from the layout of the `endian` parameter.
+    fn ensure_no_views_and_bit_order_conflict(&self) -> Result<()> {
+        if let (Some(views), Some(bit_order)) = (self.views.as_ref(), self.bit_order.as_ref()) {
+            if !self.bit_order_matches_endian() {
+                return Err(format_err!(
+                    Span::call_site(),
+                    "encountered conflicting `views` and `bit_order = {}` parameters: \
+                     views require the bit order to match the `endian` parameter",
+                    bit_order.value,
+                )
+                .into_combine(format_err!(views.span, "conflicting `views` here"))
+                .into_combine(format_err!(
+                    bit_order.span,
+                    "conflicting `bit_order = {}` here",
+                    bit_order.value,
+                )))
+            }
+        }
+        Ok(())
+    }
+
+    /// `bytemuck::Zeroable` and `bytemuck::Pod` require all bit patterns of the bitfield to be valid.
+    fn ensure_no_bytemuck_and_filled_conflict(&self) -> Result<()> {
+        if let (Some(bytemuck), Some(filled @ ConfigValue { value: false, .. })) =
+            (self.bytemuck.as_ref(), self.filled.as_ref())
+        {
+            return Err(format_err!(
+                Span::call_site(),
+                "encountered conflicting `bytemuck` and `filled = {}` parameters",
+                filled.value,
+            )
+            .into_combine(format_err!(bytemuck.span, "conflicting `bytemuck` here"))
+            .into_combine(format_err!(
+                filled.span,
+                "conflicting `filled = {}` here",
+                filled.value,
+            )))
+        }
+        Ok(())
+    }
+
+    /// `bytemuck::Pod` casts expose the internal bytes and thus cannot reverse the bits
+    /// of a `bit_order` that differs from the layout of the `endian` parameter.
+    fn ensure_no_bytemuck_and_bit_order_conflict(&self) -> Result<()> {
+        if let (Some(bytemuck), Some(bit_order), Some(_)) = (
+            self.bytemuck.as_ref(),
+            self.bit_order.as_ref(),
+            self.derive_copy.as_ref(),
+        ) {
+            if !self.bit_order_matches_endian() {
+                return Err(format_err!(
+                    Span::call_site(),
+                    "encountered conflicting `bytemuck` and `bit_order = {}` parameters: \
+                     `bytemuck::Pod` requires the bit order to match the `endian` parameter",
+                    bit_order.value,
+                )
+                .into_combine(format_err!(bytemuck.span, "conflicting `bytemuck` here"))
+                .into_combine(format_err!(
+                    bit_order.span,
+                    "conflicting `bit_order = {}` here",
+                    bit_order.value,
+                )))
+            }
+        }
+        Ok(())
+    }
+
     /// Ensures that there are no conflicting configuration parameters.
     pub fn ensure_no_conflicts(&self) -> Result<()> {
         self.ensure_no_bits_and_repr_conflict()?;
         self.ensure_no_bits_and_bytes_conflict()?;
         self.ensure_no_repr_and_filled_conflict()?;
+        self.ensure_no_views_and_bit_order_conflict()?;
+        self.ensure_no_bytemuck_and_filled_conflict()?;
+        self.ensure_no_bytemuck_and_bit_order_conflict()?;
         Ok(())
     }
 
@@ -289,6 +469,126 @@ impl Config {
         Ok(())
     }
 
+    /// Sets the `bit_order: str` #[bitfield] parameter to the given value.
+    ///
+    /// # Errors
+    ///
+    /// If the bit order has already been set.
+    pub fn bit_order(&mut self, value: BitOrder, span: Span) -> Result<()> {
+        match &self.bit_order {
+            Some(previous) => {
+                return Err(Self::raise_duplicate_error("bit_order", span, previous))
+            }
+            None => self.bit_order = Some(ConfigValue::new(value, span)),
+        }
+        Ok(())
+    }
+
+    /// Sets the `views` #[bitfield] parameter.
+    ///
+    /// # Errors
+    ///
+    /// If the parameter has already been set.
+    pub fn views(&mut self, span: Span) -> Result<()> {
+        match &self.views {
+            Some(previous) => {
+                return Err(Self::raise_duplicate_error("views", span, previous))
+            }
+            None => self.views = Some(ConfigValue::new((), span)),
+        }
+        Ok(())
+    }
+
+    /// Sets the `builder` #[bitfield] parameter.
+    ///
+    /// # Errors
+    ///
+    /// If the parameter has already been set.
+    pub fn builder(&mut self, span: Span) -> Result<()> {
+        match &self.builder {
+            Some(previous) => {
+                return Err(Self::raise_duplicate_error("builder", span, previous))
+            }
+            None => self.builder = Some(ConfigValue::new((), span)),
+        }
+        Ok(())
+    }
+
+    /// Sets the `bytemuck` #[bitfield] parameter.
+    ///
+    /// # Errors
+    ///
+    /// If the parameter has already been set.
+    pub fn bytemuck(&mut self, span: Span) -> Result<()> {
+        match &self.bytemuck {
+            Some(previous) => {
+                return Err(Self::raise_duplicate_error("bytemuck", span, previous))
+            }
+            None => self.bytemuck = Some(ConfigValue::new((), span)),
+        }
+        Ok(())
+    }
+
+    /// Sets the `reflect` #[bitfield] parameter.
+    ///
+    /// # Errors
+    ///
+    /// If the parameter has already been set.
+    pub fn reflect(&mut self, span: Span) -> Result<()> {
+        match &self.reflect {
+            Some(previous) => {
+                return Err(Self::raise_duplicate_error("reflect", span, previous))
+            }
+            None => self.reflect = Some(ConfigValue::new((), span)),
+        }
+        Ok(())
+    }
+
+    /// Sets the `serde: str` #[bitfield] parameter to the given value.
+    ///
+    /// # Errors
+    ///
+    /// If the parameter has already been set.
+    pub fn serde(&mut self, value: SerdeMode, span: Span) -> Result<()> {
+        match &self.serde {
+            Some(previous) => {
+                return Err(Self::raise_duplicate_error("serde", span, previous))
+            }
+            None => self.serde = Some(ConfigValue::new(value, span)),
+        }
+        Ok(())
+    }
+
+    /// Sets the `arbitrary: str` #[bitfield] parameter to the given value.
+    ///
+    /// # Errors
+    ///
+    /// If the parameter has already been set.
+    pub fn arbitrary(&mut self, value: ArbitraryMode, span: Span) -> Result<()> {
+        match &self.arbitrary {
+            Some(previous) => {
+                return Err(Self::raise_duplicate_error("arbitrary", span, previous))
+            }
+            None => self.arbitrary = Some(ConfigValue::new(value, span)),
+        }
+        Ok(())
+    }
+
+    /// Sets the `proptest: str` #[bitfield] parameter to the given value.
+    ///
+    /// # Errors
+    ///
+    /// If the parameter has already been set.
+    pub fn proptest(&mut self, value: ArbitraryMode, span: Span) -> Result<()> {
+        match &self.proptest {
+            Some(previous) => {
+                return Err(Self::raise_duplicate_error("proptest", span, previous))
+            }
+            None => self.proptest = Some(ConfigValue::new(value, span)),
+        }
+        Ok(())
+    }
+
     /// Registers the `#[repr(uN)]` attribute for the #[bitfield] macro.
     ///
     /// # Errors
@@ -323,6 +623,44 @@ impl Config {
         Ok(())
     }
 
+    /// Registers the `#[derive(Default)]` attribute for the #[bitfield] macro.
+    ///
+    /// # Errors
+    ///
+    /// If a `#[derive(Default)]` attribute has already been found.
+    pub fn derive_default(&mut self, span: Span) -> Result<()> {
+        match &self.derive_default {
+            Some(previous) => {
+                return Err(Self::raise_duplicate_error(
+                    "#[derive(Default)]",
+                    span,
+                    previous,
+                ))
+            }
+            None => self.derive_default = Some(ConfigValue::new((), span)),
+        }
+        Ok(())
+    }
+
+    /// Registers the `#[derive(defmt::Format)]` attribute for the #[bitfield] macro.
+    ///
+    /// # Errors
+    ///
+    /// If a `#[derive(defmt::Format)]` attribute has already been found.
+    pub fn derive_defmt(&mut self, span: Span) -> Result<()> {
+        match &self.derive_defmt {
+            Some(previous) => {
+                return Err(Self::raise_duplicate_error(
+                    "#[derive(defmt::Format)]",
+                    span,
+                    previous,
+                ))
+            }
+            None => self.derive_defmt = Some(ConfigValue::new((), span)),
+        }
+        Ok(())
+    }
+
     /// Registers the `#[derive(BitfieldSpecifier)]` attribute for the #[bitfield] macro.
     ///
     /// # Errors
@@ -342,6 +680,51 @@ impl Config {
         Ok(())
     }
 
+    /// Registers the `#[derive(Copy)]` attribute for the #[bitfield] macro.
+    ///
+    /// Unlike other derives it is still re-expanded for the `#[bitfield]` struct.
+    ///
+    /// # Errors
+    ///
+    /// If a `#[derive(Copy)]` attribute has already been found.
+    pub fn derive_copy(&mut self, span: Span) -> Result<()> {
+        match &self.derive_copy {
+            Some(previous) => {
+                return Err(Self::raise_duplicate_error("#[derive(Copy)]", span, previous))
+            }
+            None => self.derive_copy = Some(ConfigValue::new((), span)),
+        }
+        Ok(())
+    }
+
+    /// Registers a `#[derive(..)]` of a `zerocopy` trait such as `FromBytes` for the #[bitfield] macro.
+    ///
+    /// Unlike other derives it is still re-expanded for the `#[bitfield]` struct.
+    /// Only the first of these derives is registered since they are usually derived together.
+    pub fn derive_zerocopy(&mut self, span: Span) {
+        if self.derive_zerocopy.is_none() {
+            self.derive_zerocopy = Some(ConfigValue::new((), span));
+        }
+    }
+
+    /// Registers a `#[derive(..)]` of a `zerocopy` trait such as `IntoBytes` that gives
+    /// access to the internal bytes of the #[bitfield] struct.
+    ///
+    /// Only the first of these derives is registered.
+    pub fn derive_zerocopy_bytes(&mut self, span: Span) {
+        if self.derive_zerocopy_bytes.is_none() {
+            self.derive_zerocopy_bytes = Some(ConfigValue::new((), span));
+        }
+    }
+
+    /// Returns `true` if a `#[repr(..)]` other than `#[repr(uN)]` is re-expanded for the
+    /// `#[bitfield]` struct.
+    pub fn retains_repr(&self) -> bool {
+        self.retained_attributes
+            .iter()
+            .any(|attr| attr.path.is_ident("repr"))
+    }
+
     /// Pushes another retained attribute that the #[bitfield] macro is going to re-expand and ignore.
     pub fn push_retained_attribute(&mut self, retained_attr: syn::Attribute) {
         self.retained_attributes.push(retained_attr);
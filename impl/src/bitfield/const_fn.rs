@@ -0,0 +1,249 @@
+use super::{
+    field_info::FieldInfo,
+    Endian,
+};
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote_spanned;
+
+/// How the `const fn` accessors of a field convert between its values and raw bit patterns.
+///
+/// `Specifier::into_bytes` and `Specifier::from_bytes` cannot be called in `const fn`s.
+/// Therefore the conversion is generated directly for the built-in specifiers and
+/// forwarded to the hidden inherent `const fn`s generated by `#[derive(BitfieldSpecifier)]`
+/// for fields flagged with `#[const_fn]` as well as for the `B1`, .. `B128` and `S1`, .. `S128`
+/// specifiers named by a plain identifier.
+#[derive(Copy, Clone)]
+pub enum ConstConversion {
+    /// The `bool` specifier.
+    Bool,
+    /// The `B1`, .. `B128` specifiers and unsigned primitive integers.
+    Unsigned,
+    /// The `S1`, .. `S128` specifiers and signed primitive integers.
+    Signed,
+    /// Specifiers providing the inherent `__bf_from_bit_pattern` and `__bf_into_bit_pattern`.
+    Inherent,
+    /// The `B1`, .. `B128` and `S1`, .. `S128` specifiers named by a plain identifier.
+    ///
+    /// These use the inherent conversions as well so that a user type of the same name
+    /// without them fails to compile instead of being converted like a built-in specifier.
+    InherentBuiltin,
+}
+
+impl ConstConversion {
+    /// Returns `true` if the `InOut` type of the converted specifier is certainly `Copy`.
+    ///
+    /// This is not known for specifiers with inherent conversions such as enums.
+    pub fn is_copy(self) -> bool {
+        !matches!(self, Self::Inherent)
+    }
+
+    /// Returns the conversion of the given specifier type if it is named like a built-in specifier.
+    ///
+    /// These are `bool` and the primitive integers as well as the `B1`, .. `B128` and `S1`, .. `S128`
+    /// specifiers, either named by their full path, e.g. `modular_bitfield::specifiers::B5`, or by a
+    /// plain identifier such as `B5`.
+    fn of_builtin(ty: &syn::Type) -> Option<Self> {
+        let path = match ty {
+            syn::Type::Path(type_path) if type_path.qself.is_none() => &type_path.path,
+            _ => return None,
+        };
+        if path.segments.iter().any(|segment| !segment.arguments.is_empty()) {
+            return None
+        }
+        let segments = path
+            .segments
+            .iter()
+            .map(|segment| segment.ident.to_string())
+            .collect::<Vec<_>>();
+        let (name, prefix) = segments.split_last()?;
+        let is_primitive_path = match prefix {
+            [] => path.leading_colon.is_none(),
+            [krate, module] => (krate == "core" || krate == "std") && module == "primitive",
+            _ => false,
+        };
+        let is_crate_path = match prefix {
+            [krate, module] => {
+                krate == "modular_bitfield" && (module == "specifiers" || module == "prelude")
+            }
+            _ => false,
+        };
+        let is_plain_ident = prefix.is_empty() && path.leading_colon.is_none();
+        match name.as_str() {
+            "bool" if is_primitive_path => return Some(Self::Bool),
+            "u8" | "u16" | "u32" | "u64" | "u128" if is_primitive_path => {
+                return Some(Self::Unsigned)
+            }
+            "i8" | "i16" | "i32" | "i64" | "i128" if is_primitive_path => {
+                return Some(Self::Signed)
+            }
+            _ if !is_crate_path && !is_plain_ident => return None,
+            _ => (),
+        }
+        let is_bit_width = |digits: &str| {
+            matches!(digits.parse::<usize>(), Ok(bits) if (1..=128).contains(&bits))
+                && !digits.starts_with('0')
+        };
+        let conversion = match (name.strip_prefix('B'), name.strip_prefix('S')) {
+            (Some(digits), _) if is_bit_width(digits) => Self::Unsigned,
+            (_, Some(digits)) if is_bit_width(digits) => Self::Signed,
+            _ => return None,
+        };
+        if is_plain_ident {
+            return Some(Self::InherentBuiltin)
+        }
+        Some(conversion)
+    }
+
+    /// Returns the expression converting the raw bit pattern `__bf_raw` into an
+    /// `Option` of the `InOut` type of `ty`.
+    pub fn expand_from_bit_pattern(self, ty: &syn::Type, span: proc_macro2::Span) -> TokenStream2 {
+        match self {
+            Self::Bool => {
+                quote_spanned!(span=>
+                    ::core::option::Option::Some(__bf_raw != 0)
+                )
+            }
+            Self::Unsigned => {
+                quote_spanned!(span=>
+                    ::core::option::Option::Some(
+                        __bf_raw as <#ty as ::modular_bitfield::Specifier>::InOut
+                    )
+                )
+            }
+            Self::Signed => {
+                quote_spanned!(span=>
+                    ::core::option::Option::Some(
+                        ::modular_bitfield::private::sign_extend(
+                            __bf_raw,
+                            <#ty as ::modular_bitfield::Specifier>::BITS,
+                        ) as <#ty as ::modular_bitfield::Specifier>::InOut
+                    )
+                )
+            }
+            Self::Inherent | Self::InherentBuiltin => {
+                quote_spanned!(span=>
+                    <#ty>::__bf_from_bit_pattern(__bf_raw)
+                )
+            }
+        }
+    }
+
+    /// Returns the expression converting `new_val` into an `Option` of its raw bit pattern.
+    ///
+    /// The bit pattern is `None` if `new_val` is out of bounds for `ty`.
+    pub fn expand_into_bit_pattern(self, ty: &syn::Type, span: proc_macro2::Span) -> TokenStream2 {
+        match self {
+            Self::Bool => {
+                quote_spanned!(span=>
+                    ::core::option::Option::Some(new_val as ::core::primitive::u128)
+                )
+            }
+            Self::Unsigned => {
+                quote_spanned!(span=>
+                    ::modular_bitfield::private::unsigned_bit_pattern(
+                        new_val as ::core::primitive::u128,
+                        <#ty as ::modular_bitfield::Specifier>::BITS,
+                    )
+                )
+            }
+            Self::Signed => {
+                quote_spanned!(span=>
+                    ::modular_bitfield::private::signed_bit_pattern(
+                        new_val as ::core::primitive::i128,
+                        <#ty as ::modular_bitfield::Specifier>::BITS,
+                    )
+                )
+            }
+            Self::Inherent | Self::InherentBuiltin => {
+                quote_spanned!(span=>
+                    <#ty>::__bf_into_bit_pattern(new_val)
+                )
+            }
+        }
+    }
+
+    /// Generates the statement reading the raw bit pattern of the specifier `ty` at
+    /// `offset` into the `__bf_raw` binding.
+    pub fn expand_read(
+        ty: &syn::Type,
+        offset: &TokenStream2,
+        endian: Endian,
+        span: proc_macro2::Span,
+    ) -> TokenStream2 {
+        let read = |read_bits: TokenStream2| {
+            quote_spanned!(span=>
+                let __bf_raw: ::core::primitive::u128 = #read_bits(
+                    &self.bytes,
+                    #offset,
+                    <#ty as ::modular_bitfield::Specifier>::BITS,
+                );
+            )
+        };
+        let read_le = read(quote_spanned!(span=> ::modular_bitfield::private::read_bits_le));
+        let read_be = read(quote_spanned!(span=> ::modular_bitfield::private::read_bits_be));
+        match endian {
+            Endian::Little => read_le,
+            Endian::Big => read_be,
+            Endian::Native => {
+                quote_spanned!(span=>
+                    #[cfg(target_endian = "big")]
+                    #read_be
+
+                    #[cfg(target_endian = "little")]
+                    #read_le
+                )
+            }
+        }
+    }
+
+    /// Generates the statements writing the raw bit pattern `__bf_raw` of the specifier
+    /// `ty` at `offset` into the bytes of `self`.
+    pub fn expand_write(
+        ty: &syn::Type,
+        offset: &TokenStream2,
+        endian: Endian,
+        span: proc_macro2::Span,
+    ) -> TokenStream2 {
+        let write = |write_bits: TokenStream2| {
+            quote_spanned!(span=>
+                let __bf_bytes = #write_bits(
+                    self.bytes,
+                    #offset,
+                    <#ty as ::modular_bitfield::Specifier>::BITS,
+                    __bf_raw,
+                );
+            )
+        };
+        let write_le = write(quote_spanned!(span=> ::modular_bitfield::private::write_bits_le));
+        let write_be = write(quote_spanned!(span=> ::modular_bitfield::private::write_bits_be));
+        let write = match endian {
+            Endian::Little => write_le,
+            Endian::Big => write_be,
+            Endian::Native => {
+                quote_spanned!(span=>
+                    #[cfg(target_endian = "big")]
+                    #write_be
+
+                    #[cfg(target_endian = "little")]
+                    #write_le
+                )
+            }
+        };
+        quote_spanned!(span=>
+            #write
+            self.bytes = __bf_bytes;
+        )
+    }
+}
+
+impl FieldInfo<'_> {
+    /// Returns how the `const fn` accessors of the field convert its values.
+    ///
+    /// Returns `None` if the accessors of the field cannot be `const fn`.
+    pub fn const_conversion(&self) -> Option<ConstConversion> {
+        if self.config.const_fn.is_some() {
+            return Some(ConstConversion::Inherent)
+        }
+        ConstConversion::of_builtin(self.specifier_ty())
+    }
+}
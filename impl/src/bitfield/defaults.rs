@@ -0,0 +1,188 @@
+use super::{
+    generics::FieldBounds,
+    BitfieldStruct,
+    Config,
+    Endian,
+};
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote_spanned;
+use syn::spanned::Spanned as _;
+
+impl BitfieldStruct {
+    /// Generates the associated constant holding the bytes returned by `new`.
+    ///
+    /// Fields are set to the value of their `#[default = N]` attribute or otherwise to
+    /// the `Specifier::DEFAULT_BIT_PATTERN` and `Specifier::DEFAULT_BYTES` of their type. The bits of `#[reserved = N]`
+    /// fields are set to their mandated values.
+    pub fn generate_default_consts(&self, config: &Config) -> TokenStream2 {
+        let span = self.item_struct.span();
+        let ident = &self.item_struct.ident;
+        let (impl_generics, ty_generics, _) = self.item_struct.generics.split_for_impl();
+        let where_clause = self.generate_where_clause(config, FieldBounds::Specifier);
+        let size = self.generate_target_or_actual_bitfield_size(config);
+        let next_divisible_by_8 = Self::next_divisible_by_8(&size);
+        let init = match self.has_reserved_fields(config) {
+            true => quote_spanned!(span=> Self::__BF_RESERVED_BITS),
+            false => quote_spanned!(span=> [0u8; #next_divisible_by_8 / 8usize]),
+        };
+        let offsets = self.generate_field_offsets(config);
+        let set_defaults = self
+            .field_infos(config)
+            .zip(offsets)
+            .filter(|(info, _)| info.config.reserved.is_none())
+            .map(|(info, offset)| {
+                let span = info.field.span();
+                let ty = info.specifier_ty();
+                let (value, wide) = match &info.config.default {
+                    Some(default) => {
+                        let magnitude = default.value.magnitude;
+                        let negative = default.value.negative;
+                        // Invalid values are rejected by the default checks.
+                        (
+                            quote_spanned!(default.span=>
+                                match ::modular_bitfield::private::default_bit_pattern::<#ty>(#negative, #magnitude) {
+                                    ::core::option::Option::Some(__bf_pattern) => __bf_pattern,
+                                    ::core::option::Option::None => 0,
+                                }
+                            ),
+                            quote_spanned!(default.span=> &[]),
+                        )
+                    }
+                    None => {
+                        (
+                            quote_spanned!(span=>
+                                <#ty as ::modular_bitfield::Specifier>::DEFAULT_BIT_PATTERN
+                            ),
+                            quote_spanned!(span=>
+                                <#ty as ::modular_bitfield::Specifier>::DEFAULT_BYTES
+                            ),
+                        )
+                    }
+                };
+                let endian = info
+                    .config
+                    .endian
+                    .as_ref()
+                    .map(|endian| endian.value)
+                    .unwrap_or(Endian::Native);
+                let set = |set_bits: TokenStream2| {
+                    match info.array_len() {
+                        Some(len) => {
+                            quote_spanned!(span=>
+                                let __bf_bytes = {
+                                    let mut __bf_bytes = __bf_bytes;
+                                    let mut __bf_index = 0usize;
+                                    while __bf_index < (#len) {
+                                        __bf_bytes = #set_bits(
+                                            __bf_bytes,
+                                            #offset + __bf_index * <#ty as ::modular_bitfield::Specifier>::BITS,
+                                            <#ty as ::modular_bitfield::Specifier>::BITS,
+                                            #value,
+                                            #wide,
+                                        );
+                                        __bf_index += 1;
+                                    }
+                                    __bf_bytes
+                                };
+                            )
+                        }
+                        None => {
+                            quote_spanned!(span=>
+                                let __bf_bytes = #set_bits(
+                                    __bf_bytes,
+                                    #offset,
+                                    <#ty as ::modular_bitfield::Specifier>::BITS,
+                                    #value,
+                                    #wide,
+                                );
+                            )
+                        }
+                    }
+                };
+                let set_le = set(quote_spanned!(span=> ::modular_bitfield::private::set_wide_bits_le));
+                let set_be = set(quote_spanned!(span=> ::modular_bitfield::private::set_wide_bits_be));
+                match endian {
+                    Endian::Little => set_le,
+                    Endian::Big => set_be,
+                    Endian::Native => {
+                        quote_spanned!(span=>
+                            #[cfg(target_endian = "little")]
+                            #set_le
+                            #[cfg(target_endian = "big")]
+                            #set_be
+                        )
+                    }
+                }
+            });
+        quote_spanned!(span=>
+            #[allow(clippy::identity_op)]
+            impl #impl_generics #ident #ty_generics #where_clause {
+                /// The bytes of the instance returned by `new`.
+                #[doc(hidden)]
+                const __BF_DEFAULT_BYTES: [::core::primitive::u8; #next_divisible_by_8 / 8usize] = {
+                    let __bf_bytes = #init;
+                    #( #set_defaults )*
+                    __bf_bytes
+                };
+            }
+        )
+    }
+
+    /// Generates assertions that the values of `#[default = N]` attributes are valid values
+    /// of the specifiers of their fields or the elements of their array fields.
+    ///
+    /// See `Specifier::DEFAULT_VALUE` for how the values are interpreted.
+    pub fn generate_default_checks(&self, config: &Config) -> Vec<TokenStream2> {
+        let ident = &self.item_struct.ident;
+        self.field_infos(config)
+            .filter_map(|info| {
+                let default = info.config.default.as_ref()?;
+                let magnitude = default.value.magnitude;
+                let negative = default.value.negative;
+                let ty = info.specifier_ty();
+                let message = format!(
+                    "the #[default = {}] value of field {}.{} is not a valid value of its type",
+                    default.value,
+                    ident,
+                    info.name(),
+                );
+                Some(quote_spanned!(default.span=>
+                    ::core::assert!(
+                        ::modular_bitfield::private::default_bit_pattern::<#ty>(#negative, #magnitude)
+                            .is_some(),
+                        #message
+                    );
+                ))
+            })
+            .collect()
+    }
+
+    /// Generates the `Default` impl returning `new` if `#[derive(Default)]` is applied
+    /// to the `#[bitfield]` struct.
+    pub fn generate_default_impl(&self, config: &Config) -> Option<TokenStream2> {
+        config.derive_default.as_ref()?;
+        let span = self.item_struct.span();
+        let ident = &self.item_struct.ident;
+        let (impl_generics, ty_generics, _) = self.item_struct.generics.split_for_impl();
+        let where_clause = self.generate_where_clause(config, FieldBounds::Specifier);
+        Some(quote_spanned!(span=>
+            impl #impl_generics ::core::default::Default for #ident #ty_generics #where_clause {
+                #[inline]
+                fn default() -> Self {
+                    Self::new()
+                }
+            }
+        ))
+    }
+
+    /// Generates the `DEFAULT_BIT_PATTERN` and `DEFAULT_BYTES` of the `Specifier` impl
+    /// of the `#[bitfield]` struct.
+    pub fn generate_default_bit_pattern(&self) -> TokenStream2 {
+        let span = self.item_struct.span();
+        quote_spanned!(span=>
+            const DEFAULT_BIT_PATTERN: ::core::primitive::u128 =
+                ::modular_bitfield::private::bit_pattern_from_le_bytes(Self::__BF_DEFAULT_BYTES);
+            const DEFAULT_BYTES: &'static [::core::primitive::u8] = &Self::__BF_DEFAULT_BYTES;
+        )
+    }
+}
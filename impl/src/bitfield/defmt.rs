@@ -0,0 +1,74 @@
+use super::{
+    generics::FieldBounds,
+    BitfieldStruct,
+    Config,
+};
+use proc_macro2::TokenStream as TokenStream2;
+use quote::{
+    format_ident,
+    quote_spanned,
+};
+use syn::spanned::Spanned as _;
+
+impl BitfieldStruct {
+    /// Generates the `defmt::Format` impl for the `#[bitfield]` struct if the
+    /// `#[derive(defmt::Format)]` attribute is applied to it.
+    ///
+    /// Fields are formatted the same way as by the generated `Debug` impl,
+    /// including fields that contain invalid bit patterns.
+    pub fn generate_defmt_impl(&self, config: &Config) -> Option<TokenStream2> {
+        config.derive_defmt.as_ref()?;
+        let span = self.item_struct.span();
+        let ident = &self.item_struct.ident;
+        let (impl_generics, ty_generics, _) = self.item_struct.generics.split_for_impl();
+        let where_clause = self.generate_where_clause(config, FieldBounds::Defmt);
+        let mut names = Vec::new();
+        let mut values = Vec::new();
+        for info in self.field_infos(config) {
+            if info.config.skip_getters() {
+                continue
+            }
+            let field_span = info.field.span();
+            let field_getter = info
+                .field
+                .ident
+                .as_ref()
+                .map(|_| format_ident!("{}_or_err", info.ident_frag()))
+                .unwrap_or_else(|| format_ident!("get_{}_or_err", info.ident_frag()));
+            let value = match info.array_len() {
+                Some(len) => {
+                    quote_spanned!(field_span=>
+                        ::core::array::from_fn::<_, #len, _>(|__bf_index| {
+                            ::modular_bitfield::private::DefmtResult(self.#field_getter(__bf_index))
+                        })
+                    )
+                }
+                None => {
+                    quote_spanned!(field_span=>
+                        ::modular_bitfield::private::DefmtResult(self.#field_getter())
+                    )
+                }
+            };
+            names.push(info.name());
+            values.push(value);
+        }
+        let format_string = match names.is_empty() {
+            true => ident.to_string(),
+            false => {
+                let fields = names
+                    .iter()
+                    .map(|name| format!("{}: {{}}", name))
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                format!("{} {{{{ {} }}}}", ident, fields)
+            }
+        };
+        Some(quote_spanned!(span=>
+            impl #impl_generics ::modular_bitfield::private::defmt::Format for #ident #ty_generics #where_clause {
+                fn format(&self, __bf_f: ::modular_bitfield::private::defmt::Formatter<'_>) {
+                    ::modular_bitfield::private::defmt::write!(__bf_f, #format_string #( , #values )*)
+                }
+            }
+        ))
+    }
+}
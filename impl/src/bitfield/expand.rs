@@ -1,9 +1,12 @@
 use super::{
     config::{
+        BitOrder,
         Config,
         ReprKind,
     },
+    const_fn::ConstConversion,
     field_info::FieldInfo,
+    generics::FieldBounds,
     BitfieldStruct,
     Endian,
 };
@@ -12,6 +15,7 @@ use quote::{
     format_ident,
     quote,
     quote_spanned,
+    ToTokens as _,
 };
 use syn::{
     self,
@@ -31,20 +35,46 @@ impl BitfieldStruct {
 
         let byte_conversion_impls = self.expand_byte_conversion_impls(config);
         let getters_and_setters = self.expand_getters_and_setters(config);
+        let field_table = self.generate_field_table(config);
         let bytes_check = self.expand_optional_bytes_check(config);
         let repr_impls_and_checks = self.expand_repr_from_impls_and_checks(config);
         let debug_impl = self.generate_debug_impl(config);
+        let defmt_impl = self.generate_defmt_impl(config);
+        let reserved_consts = self.generate_reserved_consts(config);
+        let default_consts = self.generate_default_consts(config);
+        let default_impl = self.generate_default_impl(config);
+        let validate_impl = self.generate_validate_impl(config);
+        let views = self.generate_views(config);
+        let builder = self.generate_builder(config);
+        let reflect = self.generate_reflect(config);
+        let bytemuck_impls = self.generate_bytemuck_impls(config);
+        let zerocopy_checks = self.generate_zerocopy_checks(config);
+        let serde_impls = self.generate_serde_impls(config);
+        let arbitrary_impls = self.generate_arbitrary_impls(config);
 
         quote_spanned!(span=>
             #struct_definition
             #check_filled
+            #reserved_consts
+            #default_consts
             #constructor_definition
             #byte_conversion_impls
             #getters_and_setters
+            #field_table
+            #validate_impl
+            #views
+            #builder
+            #reflect
             #specifier_impl
             #bytes_check
             #repr_impls_and_checks
             #debug_impl
+            #default_impl
+            #defmt_impl
+            #bytemuck_impls
+            #zerocopy_checks
+            #serde_impls
+            #arbitrary_impls
         )
     }
 
@@ -56,23 +86,31 @@ impl BitfieldStruct {
         config.derive_specifier.as_ref()?;
         let span = self.item_struct.span();
         let ident = &self.item_struct.ident;
+        let (impl_generics, ty_generics, _) = self.item_struct.generics.split_for_impl();
+        let where_clause = self.generate_where_clause(config, FieldBounds::Specifier);
+        let marker_init = self.generate_marker_init();
+        let generic_checks = self.generate_generic_checks_usage();
         let bits = self.generate_target_or_actual_bitfield_size(config);
         let next_divisible_by_8 = Self::next_divisible_by_8(&bits);
 
         // ENDIAN
         let invalid_bit_pattern_le = quote_spanned!(span =>
-            let invalid_bit_pattern = {
-                let __bf_max_value: Self::Bytes = (0x01 as Self::Bytes)
-                    .checked_shl(Self::BITS as ::core::primitive::u32)
-                    .unwrap_or(<Self::Bytes>::MAX);
-                bytes > __bf_max_value
-            };
+            let invalid_bit_pattern = !::modular_bitfield::private::SpecifierBytesOps::fits_in_bits(
+                &bytes,
+                Self::BITS,
+            );
         );
 
+        // The bits below the most significant `Self::BITS` bits must be zero.
+        // Shifting them to the top and checking the result for zero tests this.
         let invalid_bit_pattern_be = quote_spanned!(span =>
             let invalid_bit_pattern = {
-                let __bf_reject_mask = ((0x01 << (#next_divisible_by_8 - Self::BITS)) - 1) as Self::Bytes;
-                bytes & __bf_reject_mask != 0
+                let __bf_base_bits: ::core::primitive::usize = 8usize * ::core::mem::size_of::<Self::Bytes>();
+                let __bf_rejected = ::modular_bitfield::private::SpecifierBytesOps::shl_bits(
+                    bytes,
+                    __bf_base_bits - (#next_divisible_by_8 - Self::BITS),
+                );
+                !::modular_bitfield::private::SpecifierBytesOps::fits_in_bits(&__bf_rejected, 0)
             };
         );
 
@@ -95,6 +133,29 @@ impl BitfieldStruct {
             Endian::Native => invalid_bit_pattern_native,
         };
 
+        let reserved_check = self
+            .generate_reserved_mismatch(config, quote_spanned!(span=> __bf_bytes))
+            .map(|reserved_mismatch| {
+                quote_spanned!(span=>
+                    if #reserved_mismatch {
+                        return ::core::result::Result::Err(::modular_bitfield::error::InvalidBitPattern::new(
+                            <Self::Bytes as ::modular_bitfield::private::SpecifierBytesOps>::from_le_slice(&__bf_bytes[..])
+                        ))
+                    }
+                )
+            });
+
+        // All bit patterns are valid for filled bitfields without `#[reserved = N]` fields
+        // whose fields all accept all of their bit patterns.
+        let filled_without_reserved = config.filled_enabled() && reserved_check.is_none();
+        let field_tys = self.field_infos(config).map(|info| info.specifier_ty());
+        let all_bit_patterns_valid = quote_spanned!(span=>
+            #filled_without_reserved
+                #( && <#field_tys as ::modular_bitfield::Specifier>::ALL_BIT_PATTERNS_VALID )*
+        );
+        let valid_bit_pattern = self.generate_valid_bit_pattern(config);
+        let default_bit_pattern = self.generate_default_bit_pattern();
+
         // let to_bytes_le = quote_spanned!(span =>
         //     let __bf_bytes = bytes.to_le_bytes();
         // );
@@ -118,49 +179,55 @@ impl BitfieldStruct {
 
         Some(quote_spanned!(span =>
             #[allow(clippy::identity_op)]
-            const _: () = {
-                impl modular_bitfield::private::checks::CheckSpecifierHasAtMost128Bits for #ident {
-                    type CheckType = [(); (#bits <= 128) as ::core::primitive::usize];
-                }
-            };
-
-            #[allow(clippy::identity_op)]
-            impl modular_bitfield::Specifier for #ident {
+            impl #impl_generics ::modular_bitfield::Specifier for #ident #ty_generics #where_clause {
                 const BITS: usize = #bits;
                 const STRUCT: bool = true;
 
                 #[allow(unused_braces)]
-                type Bytes = <[(); if { #bits } > 128 { 128 } else { #bits }] as modular_bitfield::private::SpecifierBytes>::Bytes;
+                type Bytes = <
+                    <[(); ({ #bits } > 128) as ::core::primitive::usize] as ::modular_bitfield::private::checks::DispatchTrueFalse>::Out
+                    as ::modular_bitfield::private::SelectSpecifierBytes<
+                        <[(); if { #bits } > 128 { 128 } else { #bits }] as ::modular_bitfield::private::SpecifierBytes>::Bytes,
+                        [::core::primitive::u8; #next_divisible_by_8 / 8usize],
+                    >
+                >::Bytes;
                 type InOut = Self;
+                const ALL_BIT_PATTERNS_VALID: bool = #all_bit_patterns_valid;
+                #default_bit_pattern
 
                 #[inline]
                 fn into_bytes(
                     value: Self::InOut,
-                ) -> ::core::result::Result<Self::Bytes, modular_bitfield::error::OutOfBounds> {
+                ) -> ::core::result::Result<Self::Bytes, ::modular_bitfield::error::OutOfBounds> {
                     ::core::result::Result::Ok(
-                        <[(); #next_divisible_by_8] as modular_bitfield::private::ArrayBytesConversion>::array_into_bytes(
-                            value.bytes
-                        )
+                        <Self::Bytes as ::modular_bitfield::private::SpecifierBytesOps>::from_le_slice(&value.bytes[..])
                     )
                 }
 
                 #[inline]
                 fn from_bytes(
                     bytes: Self::Bytes,
-                ) -> ::core::result::Result<Self::InOut, modular_bitfield::error::InvalidBitPattern<Self::Bytes>>
+                ) -> ::core::result::Result<Self::InOut, ::modular_bitfield::error::InvalidBitPattern<Self::Bytes>>
                 {
                     #invalid_bit_pattern
 
                     if invalid_bit_pattern {
-                       return ::core::result::Result::Err(modular_bitfield::error::InvalidBitPattern::new(bytes))
+                       return ::core::result::Result::Err(::modular_bitfield::error::InvalidBitPattern::new(bytes))
                     }
 
                     //#to_bytes
 
+                    #generic_checks
+                    let mut __bf_bytes = [0x00_u8; #next_divisible_by_8 / 8usize];
+                    ::modular_bitfield::private::SpecifierBytesOps::write_le_slice(bytes, &mut __bf_bytes[..]);
+                    #reserved_check
                     ::core::result::Result::Ok(Self {
-                        bytes: <[(); #next_divisible_by_8] as modular_bitfield::private::ArrayBytesConversion>::bytes_into_array(bytes)
+                        bytes: __bf_bytes,
+                        #marker_init
                     })
                 }
+
+                #valid_bit_pattern
             }
         ))
     }
@@ -170,6 +237,8 @@ impl BitfieldStruct {
         config.derive_debug.as_ref()?;
         let span = self.item_struct.span();
         let ident = &self.item_struct.ident;
+        let (impl_generics, ty_generics, _) = self.item_struct.generics.split_for_impl();
+        let where_clause = self.generate_where_clause(config, FieldBounds::Debug);
         let fields = self.field_infos(config).map(|info| {
             let FieldInfo {
                 index: _,
@@ -187,6 +256,16 @@ impl BitfieldStruct {
                 .as_ref()
                 .map(|_| format_ident!("{}_or_err", field_ident))
                 .unwrap_or_else(|| format_ident!("get_{}_or_err", field_ident));
+            if let Some(len) = info.array_len() {
+                return Some(quote_spanned!(field_span=>
+                    .field(
+                        #field_name,
+                        &::core::array::from_fn::<_, #len, _>(|__bf_index| {
+                            ::modular_bitfield::private::DebugResult(self.#field_getter(__bf_index))
+                        })
+                    )
+                ))
+            }
             Some(quote_spanned!(field_span=>
                 .field(
                     #field_name,
@@ -198,7 +277,8 @@ impl BitfieldStruct {
             ))
         });
         Some(quote_spanned!(span=>
-            impl ::core::fmt::Debug for #ident {
+            impl #impl_generics ::core::fmt::Debug for #ident #ty_generics #where_clause {
+                #[allow(unused_parens)]
                 fn fmt(&self, __bf_f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
                     __bf_f.debug_struct(::core::stringify!(#ident))
                         #( #fields )*
@@ -232,7 +312,7 @@ impl BitfieldStruct {
     /// # use modular_bitfield::prelude::*;
     /// {
     ///     0usize +
-    ///     <B8 as ::Specifier>::BITS +
+    ///     <B8 as ::modular_bitfield::Specifier>::BITS +
     ///     <B8 as ::modular_bitfield::Specifier>::BITS +
     ///     <B8 as ::modular_bitfield::Specifier>::BITS +
     ///     <bool as ::modular_bitfield::Specifier>::BITS +
@@ -242,31 +322,71 @@ impl BitfieldStruct {
     /// ```
     ///
     /// Which is a compile time evaluatable expression.
-    fn generate_bitfield_size(&self) -> TokenStream2 {
+    ///
+    /// Fields pinned with `#[at = N]` restart the sum at their bit position `N`.
+    pub fn generate_bitfield_size(&self, config: &Config) -> TokenStream2 {
         let span = self.item_struct.span();
-        let sum = self
-            .item_struct
-            .fields
-            .iter()
-            .map(|field| {
-                let span = field.span();
-                let ty = &field.ty;
-                quote_spanned!(span=>
-                    <#ty as modular_bitfield::Specifier>::BITS
-                )
-            })
-            .fold(quote_spanned!(span=> 0usize), |lhs, rhs| {
-                quote_spanned!(span =>
-                    #lhs + #rhs
-                )
-            });
+        let sum = self.field_infos(config).fold(
+            quote_spanned!(span=> 0usize),
+            |lhs, info| {
+                let bits = FieldInfo::bits_of(info.field);
+                match &info.config.at {
+                    Some(at) => {
+                        let at = at.value;
+                        quote_spanned!(span=> #at + #bits)
+                    }
+                    None => quote_spanned!(span=> #lhs + #bits),
+                }
+            },
+        );
         quote_spanned!(span=>
             { #sum }
         )
     }
 
+    /// Generates assertions that fields pinned with `#[at = N]` do not overlap the preceding fields.
+    pub fn generate_position_checks(&self, config: &Config) -> Vec<TokenStream2> {
+        let ident = &self.item_struct.ident;
+        let mut end = quote! { 0usize };
+        let mut checks = Vec::new();
+        for info in self.field_infos(config) {
+            let bits = FieldInfo::bits_of(info.field);
+            if let Some(at) = &info.config.at {
+                let position = at.value;
+                let message = format!(
+                    "field {}.{} at bit {} overlaps the preceding fields",
+                    ident,
+                    info.name(),
+                    position,
+                );
+                checks.push(quote_spanned!(at.span=>
+                    ::core::assert!(#end <= #position, #message);
+                ));
+                end = quote! { #position };
+            }
+            end = quote! { #end + #bits };
+        }
+        checks
+    }
+
+    /// Returns the expressions denoting the bit offsets of all fields in declaration order.
+    pub fn generate_field_offsets(&self, config: &Config) -> Vec<TokenStream2> {
+        let mut offset = quote! { 0usize };
+        let mut offsets = Vec::new();
+        for info in self.field_infos(config) {
+            if let Some(at) = &info.config.at {
+                let position = at.value;
+                offset = quote! { #position };
+            }
+            offsets.push(offset.clone());
+            let bits = FieldInfo::bits_of(info.field);
+            offset = quote! { #offset + #bits };
+        }
+        offsets
+    }
+
     /// Generates the expression denoting the actual configured or implied bit width.
-    fn generate_target_or_actual_bitfield_size(&self, config: &Config) -> TokenStream2 {
+    pub fn generate_target_or_actual_bitfield_size(&self, config: &Config) -> TokenStream2 {
         config
             .bits
             .as_ref()
@@ -277,7 +397,7 @@ impl BitfieldStruct {
                     #value
                 )
             })
-            .unwrap_or_else(|| self.generate_bitfield_size())
+            .unwrap_or_else(|| self.generate_bitfield_size(config))
     }
 
     /// Generates a check in case `bits = N` is unset to verify that the actual amount of bits is either
@@ -291,7 +411,7 @@ impl BitfieldStruct {
     ) -> TokenStream2 {
         let span = self.item_struct.span();
         let ident = &self.item_struct.ident;
-        let actual_bits = self.generate_bitfield_size();
+        let actual_bits = self.generate_bitfield_size(config);
         let check_ident = match config.filled_enabled() {
             true => quote_spanned!(span => CheckFillsUnalignedBits),
             false => quote_spanned!(span => CheckDoesNotFillUnalignedBits),
@@ -303,7 +423,7 @@ impl BitfieldStruct {
         quote_spanned!(span=>
             #[allow(clippy::identity_op)]
             const _: () = {
-                impl modular_bitfield::private::checks::#check_ident for #ident {
+                impl ::modular_bitfield::private::checks::#check_ident for #ident {
                     type CheckType = [(); (#required_bits #comparator #actual_bits) as usize];
                 }
             };
@@ -317,7 +437,7 @@ impl BitfieldStruct {
     fn generate_filled_check_for_aligned_bits(&self, config: &Config) -> TokenStream2 {
         let span = self.item_struct.span();
         let ident = &self.item_struct.ident;
-        let actual_bits = self.generate_bitfield_size();
+        let actual_bits = self.generate_bitfield_size(config);
         let check_ident = match config.filled_enabled() {
             true => quote_spanned!(span => CheckTotalSizeMultipleOf8),
             false => quote_spanned!(span => CheckTotalSizeIsNotMultipleOf8),
@@ -325,8 +445,8 @@ impl BitfieldStruct {
         quote_spanned!(span=>
             #[allow(clippy::identity_op)]
             const _: () = {
-                impl modular_bitfield::private::checks::#check_ident for #ident {
-                    type Size = modular_bitfield::private::checks::TotalSize<[(); #actual_bits % 8usize]>;
+                impl ::modular_bitfield::private::checks::#check_ident for #ident {
+                    type Size = ::modular_bitfield::private::checks::TotalSize<[(); #actual_bits % 8usize]>;
                 }
             };
         )
@@ -335,12 +455,15 @@ impl BitfieldStruct {
     /// Generate check for either of the following two cases:
     ///
     /// - `filled = true`: Check if the total number of required bits is
-    ///         - ... the same as `N` if `bits = N` was provided or
-    ///         - ... a multiple of 8, otherwise
+    ///   - ... the same as `N` if `bits = N` was provided or
+    ///   - ... a multiple of 8, otherwise
     /// - `filled = false`: Check if the total number of required bits is
-    ///         - ... smaller than `N` if `bits = N` was provided or
-    ///         - ... NOT a multiple of 8, otherwise
+    ///   - ... smaller than `N` if `bits = N` was provided or
+    ///   - ... NOT a multiple of 8, otherwise
     fn generate_check_for_filled(&self, config: &Config) -> TokenStream2 {
+        if self.is_generic() {
+            return self.generate_generic_checks(config)
+        }
         match config.bits.as_ref() {
             Some(bits_config) => {
                 self.generate_filled_check_for_unaligned_bits(config, bits_config.value)
@@ -350,7 +473,7 @@ impl BitfieldStruct {
     }
 
     /// Returns a token stream representing the next greater value divisible by 8.
-    fn next_divisible_by_8(value: &TokenStream2) -> TokenStream2 {
+    pub fn next_divisible_by_8(value: &TokenStream2) -> TokenStream2 {
         let span = value.span();
         quote_spanned!(span=> {
             (((#value - 1) / 8) + 1) * 8
@@ -366,32 +489,68 @@ impl BitfieldStruct {
         let attrs = &config.retained_attributes;
         let vis = &self.item_struct.vis;
         let ident = &self.item_struct.ident;
+        let generics = &self.item_struct.generics;
+        let where_clause = &generics.where_clause;
+        let marker = self.is_generic().then(|| {
+            let params = generics.type_params().map(|param| &param.ident);
+            quote_spanned!(span=>
+                __bf_marker: ::core::marker::PhantomData<fn() -> (#( #params, )*)>,
+            )
+        });
         let size = self.generate_target_or_actual_bitfield_size(config);
         let next_divisible_by_8 = Self::next_divisible_by_8(&size);
+        let transparent_repr = self.generate_transparent_repr(config);
         quote_spanned!(span=>
             #( #attrs )*
+            #transparent_repr
             #[allow(clippy::identity_op)]
-            #vis struct #ident
+            #vis struct #ident #generics #where_clause
             {
                 bytes: [::core::primitive::u8; #next_divisible_by_8 / 8usize],
+                #marker
             }
         )
     }
 
-    /// Generates the constructor for the bitfield that initializes all bytes to zero.
+    /// Generates the `#[repr(transparent)]` attribute that guarantees the layout of the
+    /// underlying byte array for `bytemuck::Pod` and the `zerocopy` derives.
+    ///
+    /// Returns `None` if neither applies to the `#[bitfield]` struct.
+    fn generate_transparent_repr(&self, config: &Config) -> Option<TokenStream2> {
+        let span = self.item_struct.span();
+        let derives_zerocopy = config.derive_zerocopy.is_some() && !config.retains_repr();
+        (self.implements_pod(config) || derives_zerocopy).then(|| {
+            quote_spanned!(span=>
+                #[repr(transparent)]
+            )
+        })
+    }
+
+    /// Generates the constructor for the bitfield that initializes all fields to their defaults.
     fn generate_constructor(&self, config: &Config) -> TokenStream2 {
         let span = self.item_struct.span();
         let ident = &self.item_struct.ident;
-        let size = self.generate_target_or_actual_bitfield_size(config);
-        let next_divisible_by_8 = Self::next_divisible_by_8(&size);
+        let (impl_generics, ty_generics, _) = self.item_struct.generics.split_for_impl();
+        let where_clause = self.generate_where_clause(config, FieldBounds::Specifier);
+        let marker_init = self.generate_marker_init();
+        let generic_checks = self.generate_generic_checks_usage();
+        let docs = match self.has_reserved_fields(config) {
+            true => {
+                "Returns an instance with all fields set to their default values \
+                 and all reserved fields set to their mandated values."
+            }
+            false => "Returns an instance with all fields set to their default values.",
+        };
         quote_spanned!(span=>
-            impl #ident
+            impl #impl_generics #ident #ty_generics #where_clause
             {
-                /// Returns an instance with zero initialized data.
-                #[allow(clippy::identity_op)]
+                #[doc = #docs]
+                #[allow(clippy::identity_op, clippy::new_without_default)]
                 pub const fn new() -> Self {
+                    #generic_checks
                     Self {
-                        bytes: [0u8; #next_divisible_by_8 / 8usize],
+                        bytes: Self::__BF_DEFAULT_BYTES,
+                        #marker_init
                     }
                 }
             }
@@ -399,7 +558,13 @@ impl BitfieldStruct {
     }
 
     /// Generates the compile-time assertion if the optional `byte` parameter has been set.
+    ///
+    /// Generic structs are skipped since they require `bits = N` which is already
+    /// checked against `bytes = N` during analysis.
     fn expand_optional_bytes_check(&self, config: &Config) -> Option<TokenStream2> {
+        if self.is_generic() {
+            return None
+        }
         let ident = &self.item_struct.ident;
         config.bytes.as_ref().map(|config| {
             let bytes = config.value;
@@ -407,7 +572,7 @@ impl BitfieldStruct {
                 const _: () = {
                     struct ExpectedBytes { __bf_unused: [::core::primitive::u8; #bytes] };
 
-                    modular_bitfield::private::static_assertions::assert_eq_size!(
+                    ::modular_bitfield::private::static_assertions::assert_eq_size!(
                         ExpectedBytes,
                         #ident
                     );
@@ -416,9 +581,50 @@ impl BitfieldStruct {
         })
     }
 
+    /// Generates the statement converting `bytes` between the layout of the bitfield struct's
+    /// endianness and its `bit_order` if they disagree on the bit numbering.
+    ///
+    /// Returns `None` if both agree since the bytes need no conversion then.
+    pub fn generate_bit_order_conversion(
+        &self,
+        config: &Config,
+        bytes: &TokenStream2,
+    ) -> Option<TokenStream2> {
+        let bit_order = config.bit_order.as_ref()?;
+        let span = bit_order.span;
+        let endian = config
+            .endian
+            .as_ref()
+            .map(|endian| endian.value)
+            .unwrap_or(Endian::Native);
+        let reverse = quote_spanned!(span=>
+            let #bytes = ::modular_bitfield::private::reverse_bits_in_bytes(#bytes);
+        );
+        match (endian, bit_order.value) {
+            (Endian::Little, BitOrder::Lsb0) | (Endian::Big, BitOrder::Msb0) => None,
+            (Endian::Little, BitOrder::Msb0) | (Endian::Big, BitOrder::Lsb0) => Some(reverse),
+            (Endian::Native, BitOrder::Lsb0) => {
+                Some(quote_spanned!(span=>
+                    #[cfg(target_endian = "big")]
+                    #reverse
+                ))
+            }
+            (Endian::Native, BitOrder::Msb0) => {
+                Some(quote_spanned!(span=>
+                    #[cfg(target_endian = "little")]
+                    #reverse
+                ))
+            }
+        }
+    }
+
     /// Generates `From` impls for a `#[repr(uN)]` annotated #[bitfield] struct.
     fn expand_repr_from_impls_and_checks(&self, config: &Config) -> Option<TokenStream2> {
         let ident = &self.item_struct.ident;
+        let (impl_generics, ty_generics, _) = self.item_struct.generics.split_for_impl();
+        let marker_init = self.generate_marker_init();
+        let generic_checks = self.generate_generic_checks_usage();
+        let bit_order_conversion = self.generate_bit_order_conversion(config, &quote! { bytes });
         config.repr.as_ref().map(|repr| {
             let kind = &repr.value;
             let span = repr.span;
@@ -437,75 +643,138 @@ impl BitfieldStruct {
                 ReprKind::U64 => quote! { IsU64Compatible },
                 ReprKind::U128 => quote! { IsU128Compatible },
             };
+            let mut where_clause = self.generate_where_clause(config, FieldBounds::Specifier);
+            where_clause.predicates.push(syn::parse_quote_spanned!(span=>
+                [(); #actual_bits]: ::modular_bitfield::private::#trait_check_ident
+            ));
             quote_spanned!(span=>
-                impl ::core::convert::From<#prim> for #ident
-                where
-                    [(); #actual_bits]: modular_bitfield::private::#trait_check_ident,
+                impl #impl_generics ::core::convert::From<#prim> for #ident #ty_generics
+                #where_clause
                 {
                     #[inline]
                     fn from(__bf_prim: #prim) -> Self {
+                        #generic_checks
 // TODO: ENDIAN
-                        Self { bytes: <#prim>::to_le_bytes(__bf_prim) }
+                        let bytes = <#prim>::to_le_bytes(__bf_prim);
+                        #bit_order_conversion
+                        Self { bytes, #marker_init }
                     }
                 }
 
-                impl ::core::convert::From<#ident> for #prim
-                where
-                    [(); #actual_bits]: modular_bitfield::private::#trait_check_ident,
+                impl #impl_generics ::core::convert::From<#ident #ty_generics> for #prim
+                #where_clause
                 {
                     #[inline]
-                    fn from(__bf_bitfield: #ident) -> Self {
+                    fn from(__bf_bitfield: #ident #ty_generics) -> Self {
 // TODO: ENDIAN
-                        <Self>::from_le_bytes(__bf_bitfield.bytes)
+                        let bytes = __bf_bitfield.bytes;
+                        #bit_order_conversion
+                        <Self>::from_le_bytes(bytes)
                     }
                 }
             )
         })
     }
 
+    /// Generates the statement binding `out_of_bounds` to `true` if `bytes` contain bits
+    /// at positions that are undefined for the `#[bitfield(filled = false)]` struct.
+    pub fn generate_bounds_check(&self, config: &Config) -> TokenStream2 {
+        let span = self.item_struct.span();
+        let size = self.generate_target_or_actual_bitfield_size(config);
+        let next_divisible_by_8 = Self::next_divisible_by_8(&size);
+        let bounds_check_le = quote_spanned!(span=>
+            let out_of_bounds = (bytes[(#next_divisible_by_8 / 8usize) - 1] as ::core::primitive::u16) >= (0x01_u16 << (8 - (#next_divisible_by_8 - #size)));
+        );
+
+        let bounds_check_be = quote_spanned!(span =>
+            let out_of_bounds = bytes[(#next_divisible_by_8 / 8usize) - 1] & ( (0x01 << (#next_divisible_by_8 - #size)) - 1) != 0;
+        );
+
+        let bounds_check_native = quote_spanned!(span =>
+            #[cfg(target_endian = "big")]
+            #bounds_check_be
+
+            #[cfg(target_endian = "little")]
+            #bounds_check_le
+        );
+
+        let endian = match &config.endian {
+            Some(value) => value.value,
+            None => Endian::Native,
+        };
+
+        match endian {
+            Endian::Big => bounds_check_be,
+            Endian::Little => bounds_check_le,
+            Endian::Native => bounds_check_native,
+        }
+    }
+
     /// Generates routines to allow conversion from and to bytes for the `#[bitfield]` struct.
     fn expand_byte_conversion_impls(&self, config: &Config) -> TokenStream2 {
         let span = self.item_struct.span();
         let ident = &self.item_struct.ident;
+        let (impl_generics, ty_generics, _) = self.item_struct.generics.split_for_impl();
+        let where_clause = self.generate_where_clause(config, FieldBounds::Specifier);
+        let marker_init = self.generate_marker_init();
+        let generic_checks = self.generate_generic_checks_usage();
         let size = self.generate_target_or_actual_bitfield_size(config);
         let next_divisible_by_8 = Self::next_divisible_by_8(&size);
+        let reserved_mismatch = self.generate_reserved_mismatch(config, quote_spanned!(span=> bytes));
+        let bit_order_conversion = self.generate_bit_order_conversion(config, &quote! { bytes });
         let from_bytes = match config.filled_enabled() {
             true => {
+                let reserved_mismatch = self.generate_reserved_mismatch(
+                    config,
+                    quote_spanned!(span=> __bf_bitfield.bytes),
+                );
+                let from_bytes_checked = reserved_mismatch.map(|reserved_mismatch| {
+                    quote_spanned!(span=>
+                        /// Converts the given bytes into the bitfield struct.
+                        ///
+                        /// # Errors
+                        ///
+                        /// If the bits of the reserved fields differ from their mandated values.
+                        #[inline]
+                        #[allow(clippy::identity_op)]
+                        pub fn from_bytes_checked(
+                            bytes: [::core::primitive::u8; #next_divisible_by_8 / 8usize]
+                        ) -> ::core::result::Result<Self, ::modular_bitfield::error::OutOfBounds> {
+                            let __bf_bitfield = Self::from_bytes(bytes);
+                            if #reserved_mismatch {
+                                return ::core::result::Result::Err(::modular_bitfield::error::OutOfBounds)
+                            }
+                            ::core::result::Result::Ok(__bf_bitfield)
+                        }
+                    )
+                });
                 quote_spanned!(span=>
                     /// Converts the given bytes directly into the bitfield struct.
                     #[inline]
                     #[allow(clippy::identity_op)]
                     pub const fn from_bytes(bytes: [::core::primitive::u8; #next_divisible_by_8 / 8usize]) -> Self {
-                        Self { bytes }
+                        #generic_checks
+                        #bit_order_conversion
+                        Self { bytes, #marker_init }
                     }
+
+                    #from_bytes_checked
                 )
             }
             false => {
-                let bounds_check_le = quote_spanned!(span=>
-                    let out_of_bounds = bytes[(#next_divisible_by_8 / 8usize) - 1] >= (0x01 << (8 - (#next_divisible_by_8 - #size)));
-                );
-
-                let bounds_check_be = quote_spanned!(span =>
-                    let out_of_bounds = bytes[(#next_divisible_by_8 / 8usize) - 1] & ( (0x01 << (#next_divisible_by_8 - #size)) - 1) != 0;
-                );
-
-                let bounds_check_native = quote_spanned!(span =>
-                    #[cfg(target_endian = "big")]
-                    #bounds_check_be
-
-                    #[cfg(target_endian = "little")]
-                    #bounds_check_le
-                );
-
-                let endian = match &config.endian {
-                    Some(value) => value.value,
-                    None => Endian::Native,
-                };
-
-                let bounds_check = match endian {
-                    Endian::Big => bounds_check_be,
-                    Endian::Little => bounds_check_le,
-                    Endian::Native => bounds_check_native,
+                let bounds_check = self.generate_bounds_check(config);
+                let (reserved_docs, reserved_check) = match reserved_mismatch {
+                    Some(reserved_mismatch) => {
+                        (
+                            Some(quote_spanned!(span=>
+                                /// Also if the bits of the reserved fields differ from their mandated values.
+                            )),
+                            Some(quote_spanned!(span=>
+                                let out_of_bounds = out_of_bounds || #reserved_mismatch;
+                            )),
+                        )
+                    }
+                    None => (None, None),
                 };
 
                 quote_spanned!(span=>
@@ -514,25 +783,28 @@ impl BitfieldStruct {
                     /// # Errors
                     ///
                     /// If the given bytes contain bits at positions that are undefined for `Self`.
+                    #reserved_docs
                     #[inline]
                     #[allow(clippy::identity_op)]
                     pub fn from_bytes(
                         bytes: [::core::primitive::u8; #next_divisible_by_8 / 8usize]
-                    ) -> ::core::result::Result<Self, modular_bitfield::error::OutOfBounds> {
-
+                    ) -> ::core::result::Result<Self, ::modular_bitfield::error::OutOfBounds> {
+                        #bit_order_conversion
                         #bounds_check
+                        #reserved_check
 
                         if out_of_bounds {
-                           return ::core::result::Result::Err(modular_bitfield::error::OutOfBounds)
+                           return ::core::result::Result::Err(::modular_bitfield::error::OutOfBounds)
                         }
 
-                        ::core::result::Result::Ok(Self { bytes })
+                        #generic_checks
+                        ::core::result::Result::Ok(Self { bytes, #marker_init })
                     }
                 )
             }
         };
         quote_spanned!(span=>
-            impl #ident {
+            impl #impl_generics #ident #ty_generics #where_clause {
                 /// Returns the underlying bits.
                 ///
                 /// # Layout
@@ -542,7 +814,9 @@ impl BitfieldStruct {
                 #[inline]
                 #[allow(clippy::identity_op)]
                 pub const fn into_bytes(self) -> [::core::primitive::u8; #next_divisible_by_8 / 8usize] {
-                    self.bytes
+                    let bytes = self.bytes;
+                    #bit_order_conversion
+                    bytes
                 }
 
                 #from_bytes
@@ -559,14 +833,16 @@ impl BitfieldStruct {
         } = field_info;
         let span = field.span();
         let bits_check = match &config.bits {
+            // Checked by the associated `__BF_CHECKS` constant after monomorphization.
+            Some(_) if self.is_generic_type(&field.ty) => None,
             Some(bits) => {
-                let ty = &field.ty;
+                let actual_bits = FieldInfo::bits_of(field);
                 let expected_bits = bits.value;
                 let span = bits.span;
                 Some(quote_spanned!(span =>
-                    let _: modular_bitfield::private::checks::BitsCheck::<[(); #expected_bits]> =
-                        modular_bitfield::private::checks::BitsCheck::<[(); #expected_bits]>{
-                            arr: [(); <#ty as modular_bitfield::Specifier>::BITS]
+                    let _: ::modular_bitfield::private::checks::BitsCheck::<[(); #expected_bits]> =
+                        ::modular_bitfield::private::checks::BitsCheck::<[(); #expected_bits]>{
+                            arr: [(); #actual_bits]
                         };
                 ))
             }
@@ -579,10 +855,165 @@ impl BitfieldStruct {
         )
     }
 
+    /// Generates the statement reading the raw bytes of the specifier `ty` at `offset`
+    /// into the `__bf_read` binding.
+    pub fn expand_read_for_field(
+        ty: &syn::Type,
+        offset: &TokenStream2,
+        endian: Endian,
+        span: proc_macro2::Span,
+    ) -> TokenStream2 {
+        let bf_read_le = quote_spanned!(span=>
+            let __bf_read: <#ty as ::modular_bitfield::Specifier>::Bytes = {
+                ::modular_bitfield::private::read_specifier_le::<#ty>(&self.bytes[..], #offset)
+            };
+        );
+
+        let bf_read_be = quote_spanned!(span=>
+            let __bf_read: <#ty as ::modular_bitfield::Specifier>::Bytes = {
+                let __bf_base_bits: ::core::primitive::usize = 8usize * ::core::mem::size_of::<<#ty as ::modular_bitfield::Specifier>::Bytes>();
+                let __bf_spec_bits: ::core::primitive::usize = <#ty as ::modular_bitfield::Specifier>::BITS;
+                ::modular_bitfield::private::SpecifierBytesOps::shl_bits(
+                    ::modular_bitfield::private::read_specifier_be::<#ty>(&self.bytes[..], #offset),
+                    if <#ty as ::modular_bitfield::Specifier>::STRUCT { __bf_base_bits - __bf_spec_bits } else { 0 },
+                )
+            };
+        );
+
+        let bf_read_native = quote_spanned!(span =>
+            #[cfg(target_endian = "big")]
+            #bf_read_be
+
+            #[cfg(target_endian = "little")]
+            #bf_read_le
+        );
+
+        match endian {
+            Endian::Big => bf_read_be,
+            Endian::Little => bf_read_le,
+            Endian::Native => bf_read_native,
+        }
+    }
+
+    /// Generates the statements writing `new_val` of the specifier `ty` at `offset`.
+    ///
+    /// Returns early with `OutOfBounds` if `new_val` is out of bounds for `ty`.
+    fn expand_write_for_field(
+        &self,
+        ty: &syn::Type,
+        offset: &TokenStream2,
+        endian: Endian,
+        span: proc_macro2::Span,
+    ) -> TokenStream2 {
+        let bf_raw_val_le = quote_spanned!(span=>
+            let __bf_raw_val: <#ty as ::modular_bitfield::Specifier>::Bytes = {
+                <#ty as ::modular_bitfield::Specifier>::into_bytes(new_val)
+            }?;
+        );
+
+        let bf_raw_val_be = quote_spanned!(span=>
+            let __bf_raw_val: <#ty as ::modular_bitfield::Specifier>::Bytes =
+                ::modular_bitfield::private::SpecifierBytesOps::shr_bits(
+                    <#ty as ::modular_bitfield::Specifier>::into_bytes(new_val)?,
+                    if <#ty as ::modular_bitfield::Specifier>::STRUCT { __bf_base_bits - __bf_spec_bits } else { 0 },
+                );
+        );
+
+        let bf_raw_val_native = quote_spanned!(span =>
+            #[cfg(target_endian = "big")]
+            #bf_raw_val_be
+
+            #[cfg(target_endian = "little")]
+            #bf_raw_val_le
+        );
+
+        let bf_raw_val = match endian {
+            Endian::Big => bf_raw_val_be,
+            Endian::Little => bf_raw_val_le,
+            Endian::Native => bf_raw_val_native,
+        };
+
+        let write_specifier_be = quote_spanned!(span=> ::modular_bitfield::private::write_specifier_be::<#ty>(&mut self.bytes[..], #offset, __bf_raw_val););
+        let write_specifier_le = quote_spanned!(span=> ::modular_bitfield::private::write_specifier_le::<#ty>(&mut self.bytes[..], #offset, __bf_raw_val););
+        let write_specifier_native = quote_spanned!(span =>
+            #[cfg(target_endian = "big")]
+            #write_specifier_be
+
+            #[cfg(target_endian = "little")]
+            #write_specifier_le
+        );
+
+        let write_specifier = match endian {
+            Endian::Big => write_specifier_be,
+            Endian::Little => write_specifier_le,
+            Endian::Native => write_specifier_native,
+        };
+
+        quote_spanned!(span=>
+            let __bf_base_bits: ::core::primitive::usize = 8usize * ::core::mem::size_of::<<#ty as ::modular_bitfield::Specifier>::Bytes>();
+            let __bf_spec_bits: ::core::primitive::usize = <#ty as ::modular_bitfield::Specifier>::BITS;
+
+            #bf_raw_val
+
+            if !::modular_bitfield::private::SpecifierBytesOps::fits_in_bits(&__bf_raw_val, __bf_spec_bits) {
+                return ::core::result::Result::Err(::modular_bitfield::error::OutOfBounds)
+            }
+
+            #write_specifier
+        )
+    }
+
+    /// Generates the body of a `const fn` checked getter reading the specifier `ty` at `offset`.
+    fn expand_const_read_for_field(
+        ty: &syn::Type,
+        offset: &TokenStream2,
+        endian: Endian,
+        conversion: ConstConversion,
+        span: proc_macro2::Span,
+    ) -> TokenStream2 {
+        let bf_read = ConstConversion::expand_read(ty, offset, endian, span);
+        let from_bit_pattern = conversion.expand_from_bit_pattern(ty, span);
+        quote_spanned!(span=>
+            #bf_read
+            match #from_bit_pattern {
+                ::core::option::Option::Some(__bf_value) => ::core::result::Result::Ok(__bf_value),
+                ::core::option::Option::None => {
+                    ::core::result::Result::Err(::modular_bitfield::error::InvalidBitPattern::new(
+                        __bf_raw as <#ty as ::modular_bitfield::Specifier>::Bytes,
+                    ))
+                }
+            }
+        )
+    }
+
+    /// Generates the body of a `const fn` checked `with_*` method writing `new_val` of the
+    /// specifier `ty` at `offset`.
+    fn expand_const_write_for_field(
+        ty: &syn::Type,
+        offset: &TokenStream2,
+        endian: Endian,
+        conversion: ConstConversion,
+        span: proc_macro2::Span,
+    ) -> TokenStream2 {
+        let into_bit_pattern = conversion.expand_into_bit_pattern(ty, span);
+        let bf_write = ConstConversion::expand_write(ty, offset, endian, span);
+        quote_spanned!(span=>
+            let __bf_raw: ::core::primitive::u128 = match #into_bit_pattern {
+                ::core::option::Option::Some(__bf_raw) => __bf_raw,
+                ::core::option::Option::None => {
+                    return ::core::result::Result::Err(::modular_bitfield::error::OutOfBounds)
+                }
+            };
+            #bf_write
+            ::core::result::Result::Ok(self)
+        )
+    }
+
     fn expand_getters_for_field(
         &self,
         offset: &Punctuated<syn::Expr, syn::Token![+]>,
         info: &FieldInfo<'_>,
+        conversion: Option<ConstConversion>,
     ) -> Option<TokenStream2> {
         let FieldInfo {
             index: _,
@@ -592,6 +1023,9 @@ impl BitfieldStruct {
         if config.skip_getters() {
             return None
         }
+        if info.array_len().is_some() {
+            return Some(self.expand_array_getters_for_field(offset, info, conversion))
+        }
         let struct_ident = &self.item_struct.ident;
         let span = field.span();
         let ident = info.ident_frag();
@@ -623,72 +1057,196 @@ impl BitfieldStruct {
             name, name,
         );
 
-        let bf_read_le = quote_spanned!(span=>
-            let __bf_read: <#ty as modular_bitfield::Specifier>::Bytes = {
-                modular_bitfield::private::read_specifier_le::<#ty>(&self.bytes[..], #offset)
-            };
-        );
+        let endian = match &config.endian {
+            Some(value) => value.value,
+            None => Endian::Native,
+        };
+        let (constness, get, get_checked) = match conversion {
+            Some(conversion) => {
+                (
+                    Some(quote_spanned!(span=> const)),
+                    quote_spanned!(span=>
+                        match self.#get_checked_ident() {
+                            ::core::result::Result::Ok(__bf_value) => __bf_value,
+                            ::core::result::Result::Err(_) => ::core::panic!(#get_assert_msg),
+                        }
+                    ),
+                    Self::expand_const_read_for_field(
+                        ty,
+                        &offset.to_token_stream(),
+                        endian,
+                        conversion,
+                        span,
+                    ),
+                )
+            }
+            None => {
+                let bf_read =
+                    Self::expand_read_for_field(ty, &offset.to_token_stream(), endian, span);
+                (
+                    None,
+                    quote_spanned!(span=>
+                        self.#get_checked_ident().expect(#get_assert_msg)
+                    ),
+                    quote_spanned!(span=>
+                        #bf_read
+
+                        <#ty as ::modular_bitfield::Specifier>::from_bytes(__bf_read)
+                    ),
+                )
+            }
+        };
 
-        let bf_read_be = quote_spanned!(span=>
-            let __bf_read: <#ty as modular_bitfield::Specifier>::Bytes = {
-                let __bf_base_bits: ::core::primitive::usize = 8usize * ::core::mem::size_of::<<#ty as modular_bitfield::Specifier>::Bytes>();
-                let __bf_spec_bits: ::core::primitive::usize = <#ty as modular_bitfield::Specifier>::BITS;
-                let __bf_read: <#ty as modular_bitfield::Specifier>::Bytes = {
-                    modular_bitfield::private::read_specifier_be::<#ty>(&self.bytes[..], #offset)
-                } << if <#ty as modular_bitfield::Specifier>::STRUCT { __bf_base_bits - __bf_spec_bits } else { 0 };
-                __bf_read
-            };
+        let getters = quote_spanned!(span=>
+            #[doc = #getter_docs]
+            #[inline]
+            #( #retained_attrs )*
+            #vis #constness fn #get_ident(&self) -> <#ty as ::modular_bitfield::Specifier>::InOut {
+                #get
+            }
+
+            #[doc = #checked_getter_docs]
+            #[inline]
+            #[allow(dead_code)]
+            #( #retained_attrs )*
+            #vis #constness fn #get_checked_ident(
+                &self,
+            ) -> ::core::result::Result<
+                <#ty as ::modular_bitfield::Specifier>::InOut,
+                ::modular_bitfield::error::InvalidBitPattern<<#ty as ::modular_bitfield::Specifier>::Bytes>
+            > {
+                #get_checked
+            }
         );
+        Some(getters)
+    }
 
-        let bf_read_native = quote_spanned!(span =>
-            #[cfg(target_endian = "big")]
-            #bf_read_be
+    /// Generates the indexed and whole-array getters for an array field such as `[B4; 8]`.
+    fn expand_array_getters_for_field(
+        &self,
+        offset: &Punctuated<syn::Expr, syn::Token![+]>,
+        info: &FieldInfo<'_>,
+        conversion: Option<ConstConversion>,
+    ) -> TokenStream2 {
+        let FieldInfo {
+            index: _,
+            field,
+            config,
+        } = &info;
+        let struct_ident = &self.item_struct.ident;
+        let span = field.span();
+        let ident = info.ident_frag();
+        let name = info.name();
+        let ty = info.specifier_ty();
+        let len = info.array_len();
+        let vis = &field.vis;
+        let retained_attrs = &config.retained_attrs;
 
-            #[cfg(target_endian = "little")]
-            #bf_read_le
+        let get_ident = field
+            .ident
+            .as_ref()
+            .cloned()
+            .unwrap_or_else(|| format_ident!("get_{}", ident));
+        let get_checked_ident = format_ident!("{}_or_err", get_ident);
+        let get_array_ident = format_ident!("{}_array", get_ident);
+
+        let get_assert_msg = format!(
+            "value contains invalid bit pattern for field {}.{}",
+            struct_ident, name
+        );
+        let index_assert_msg =
+            format!("index out of bounds for field {}.{}", struct_ident, name);
+        let getter_docs = format!(
+            "Returns the value of {} at the given index.\n\n\
+             #Panics\n\n\
+             If the index is out of bounds for {}.",
+            name, name,
         );
+        let checked_getter_docs = format!(
+            "Returns the value of {} at the given index.\n\n\
+             #Errors\n\n\
+             If the returned value contains an invalid bit pattern for {}.\n\n\
+             #Panics\n\n\
+             If the index is out of bounds for {}.",
+            name, name, name,
+        );
+        let array_getter_docs = format!("Returns the values of all elements of {}.", name);
 
         let endian = match &config.endian {
             Some(value) => value.value,
             None => Endian::Native,
         };
-        let bf_read = match endian {
-            Endian::Big => bf_read_be,
-            Endian::Little => bf_read_le,
-            Endian::Native => bf_read_native,
+        let offset_ident = quote_spanned!(span=> __bf_offset);
+        let (constness, get, get_checked) = match conversion {
+            Some(conversion) => {
+                (
+                    Some(quote_spanned!(span=> const)),
+                    quote_spanned!(span=>
+                        match self.#get_checked_ident(index) {
+                            ::core::result::Result::Ok(__bf_value) => __bf_value,
+                            ::core::result::Result::Err(_) => ::core::panic!(#get_assert_msg),
+                        }
+                    ),
+                    Self::expand_const_read_for_field(ty, &offset_ident, endian, conversion, span),
+                )
+            }
+            None => {
+                let bf_read = Self::expand_read_for_field(ty, &offset_ident, endian, span);
+                (
+                    None,
+                    quote_spanned!(span=>
+                        self.#get_checked_ident(index).expect(#get_assert_msg)
+                    ),
+                    quote_spanned!(span=>
+                        #bf_read
+
+                        <#ty as ::modular_bitfield::Specifier>::from_bytes(__bf_read)
+                    ),
+                )
+            }
         };
 
-        let getters = quote_spanned!(span=>
+        quote_spanned!(span=>
             #[doc = #getter_docs]
             #[inline]
             #( #retained_attrs )*
-            #vis fn #get_ident(&self) -> <#ty as modular_bitfield::Specifier>::InOut {
-                self.#get_checked_ident().expect(#get_assert_msg)
+            #vis #constness fn #get_ident(&self, index: ::core::primitive::usize) -> <#ty as ::modular_bitfield::Specifier>::InOut {
+                #get
             }
 
             #[doc = #checked_getter_docs]
             #[inline]
             #[allow(dead_code)]
             #( #retained_attrs )*
-            #vis fn #get_checked_ident(
+            #vis #constness fn #get_checked_ident(
                 &self,
+                index: ::core::primitive::usize,
             ) -> ::core::result::Result<
-                <#ty as modular_bitfield::Specifier>::InOut,
-                modular_bitfield::error::InvalidBitPattern<<#ty as modular_bitfield::Specifier>::Bytes>
+                <#ty as ::modular_bitfield::Specifier>::InOut,
+                ::modular_bitfield::error::InvalidBitPattern<<#ty as ::modular_bitfield::Specifier>::Bytes>
             > {
+                ::core::assert!(index < #len, #index_assert_msg);
+                let __bf_offset: ::core::primitive::usize =
+                    #offset + index * <#ty as ::modular_bitfield::Specifier>::BITS;
 
-                #bf_read
+                #get_checked
+            }
 
-                <#ty as modular_bitfield::Specifier>::from_bytes(__bf_read)
+            #[doc = #array_getter_docs]
+            #[inline]
+            #[allow(dead_code)]
+            #( #retained_attrs )*
+            #vis fn #get_array_ident(&self) -> [<#ty as ::modular_bitfield::Specifier>::InOut; #len] {
+                ::core::array::from_fn(|__bf_index| self.#get_ident(__bf_index))
             }
-        );
-        Some(getters)
+        )
     }
 
     fn expand_setters_for_field(
         &self,
         offset: &Punctuated<syn::Expr, syn::Token![+]>,
         info: &FieldInfo<'_>,
+        conversion: Option<ConstConversion>,
     ) -> Option<TokenStream2> {
         let FieldInfo {
             index: _,
@@ -698,6 +1256,9 @@ impl BitfieldStruct {
         if config.skip_setters() {
             return None
         }
+        if info.array_len().is_some() {
+            return Some(self.expand_array_setters_for_field(offset, info, conversion))
+        }
         let struct_ident = &self.item_struct.ident;
         let span = field.span();
         let retained_attrs = &config.retained_attrs;
@@ -745,80 +1306,321 @@ impl BitfieldStruct {
             Some(value) => value.value,
             None => Endian::Native,
         };
+        let bf_write =
+            self.expand_write_for_field(ty, &offset.to_token_stream(), endian, span);
+        // The `const fn` `with_*` methods forward to the checked ones and need no `mut self`.
+        let with_self = match conversion {
+            Some(_) => quote_spanned!(span=> self),
+            None => quote_spanned!(span=> mut self),
+        };
+        let (constness, with, with_checked) = match conversion {
+            Some(conversion) => {
+                (
+                    Some(quote_spanned!(span=> const)),
+                    quote_spanned!(span=>
+                        match self.#with_checked_ident(new_val) {
+                            ::core::result::Result::Ok(__bf_value) => __bf_value,
+                            ::core::result::Result::Err(_) => ::core::panic!(#set_assert_msg),
+                        }
+                    ),
+                    Self::expand_const_write_for_field(
+                        ty,
+                        &offset.to_token_stream(),
+                        endian,
+                        conversion,
+                        span,
+                    ),
+                )
+            }
+            None => {
+                (
+                    None,
+                    quote_spanned!(span=>
+                        self.#set_ident(new_val);
+                        self
+                    ),
+                    quote_spanned!(span=>
+                        self.#set_checked_ident(new_val)?;
+                        ::core::result::Result::Ok(self)
+                    ),
+                )
+            }
+        };
 
-        let bf_raw_val_le = quote_spanned!(span=>
-            let __bf_raw_val: <#ty as modular_bitfield::Specifier>::Bytes = {
-                <#ty as modular_bitfield::Specifier>::into_bytes(new_val)
-            }?;
-        );
+        let setters = quote_spanned!(span=>
+            #[doc = #with_docs]
+            #[inline]
+            #[allow(dead_code)]
+            #( #retained_attrs )*
+            #vis #constness fn #with_ident(
+                #with_self,
+                new_val: <#ty as ::modular_bitfield::Specifier>::InOut
+            ) -> Self {
+                #with
+            }
 
-        let bf_raw_val_be = quote_spanned!(span=>
-            let __bf_raw_val: <#ty as modular_bitfield::Specifier>::Bytes = {
-                <#ty as modular_bitfield::Specifier>::into_bytes(new_val)
-            }? >> if <#ty as modular_bitfield::Specifier>::STRUCT { __bf_base_bits - __bf_spec_bits } else { 0 };
-        );
+            #[doc = #checked_with_docs]
+            #[inline]
+            #[allow(dead_code)]
+            #( #retained_attrs )*
+            #vis #constness fn #with_checked_ident(
+                mut self,
+                new_val: <#ty as ::modular_bitfield::Specifier>::InOut,
+            ) -> ::core::result::Result<Self, ::modular_bitfield::error::OutOfBounds> {
+                #with_checked
+            }
 
-        let bf_raw_val_native = quote_spanned!(span =>
-            #[cfg(target_endian = "big")]
-            #bf_raw_val_be
+            #[doc = #setter_docs]
+            #[inline]
+            #[allow(dead_code)]
+            #( #retained_attrs )*
+            #vis fn #set_ident(&mut self, new_val: <#ty as ::modular_bitfield::Specifier>::InOut) {
+                self.#set_checked_ident(new_val).expect(#set_assert_msg)
+            }
 
-            #[cfg(target_endian = "little")]
-            #bf_raw_val_le
+            #[doc = #checked_setter_docs]
+            #[inline]
+            #( #retained_attrs )*
+            #vis fn #set_checked_ident(
+                &mut self,
+                new_val: <#ty as ::modular_bitfield::Specifier>::InOut
+            ) -> ::core::result::Result<(), ::modular_bitfield::error::OutOfBounds> {
+                #bf_write
+                ::core::result::Result::Ok(())
+            }
         );
+        Some(setters)
+    }
 
-        let bf_raw_val = match endian {
-            Endian::Big => bf_raw_val_be,
-            Endian::Little => bf_raw_val_le,
-            Endian::Native => bf_raw_val_native,
-        };
+    /// Generates the indexed and whole-array setters for an array field such as `[B4; 8]`.
+    fn expand_array_setters_for_field(
+        &self,
+        offset: &Punctuated<syn::Expr, syn::Token![+]>,
+        info: &FieldInfo<'_>,
+        conversion: Option<ConstConversion>,
+    ) -> TokenStream2 {
+        let FieldInfo {
+            index: _,
+            field,
+            config,
+        } = &info;
+        let struct_ident = &self.item_struct.ident;
+        let span = field.span();
+        let retained_attrs = &config.retained_attrs;
 
-        let write_specifier_be = quote_spanned!(span=> modular_bitfield::private::write_specifier_be::<#ty>(&mut self.bytes[..], #offset, __bf_raw_val););
-        let write_specifier_le = quote_spanned!(span=> modular_bitfield::private::write_specifier_le::<#ty>(&mut self.bytes[..], #offset, __bf_raw_val););
-        let write_specifier_native = quote_spanned!(span =>
-            #[cfg(target_endian = "big")]
-            #write_specifier_be
+        let ident = info.ident_frag();
+        let name = info.name();
+        let ty = info.specifier_ty();
+        let len = info.array_len();
+        let vis = &field.vis;
 
-            #[cfg(target_endian = "little")]
-            #write_specifier_le
+        let set_ident = format_ident!("set_{}", ident);
+        let set_checked_ident = format_ident!("set_{}_checked", ident);
+        let with_ident = format_ident!("with_{}", ident);
+        let with_checked_ident = format_ident!("with_{}_checked", ident);
+        let set_array_ident = format_ident!("set_{}_array", ident);
+        let set_array_checked_ident = format_ident!("set_{}_array_checked", ident);
+        let with_array_ident = format_ident!("with_{}_array", ident);
+
+        let set_assert_msg =
+            format!("value out of bounds for field {}.{}", struct_ident, name);
+        let index_assert_msg =
+            format!("index out of bounds for field {}.{}", struct_ident, name);
+        let setter_docs = format!(
+            "Sets the value of {} at the given index to the given value.\n\n\
+             #Panics\n\n\
+             If the index or the given value is out of bounds for {}.",
+            name, name,
+        );
+        let checked_setter_docs = format!(
+            "Sets the value of {} at the given index to the given value.\n\n\
+             #Errors\n\n\
+             If the given value is out of bounds for {}.\n\n\
+             #Panics\n\n\
+             If the index is out of bounds for {}.",
+            name, name, name,
+        );
+        let with_docs = format!(
+            "Returns a copy of the bitfield with the value of {} at the \
+             given index set to the given value.\n\n\
+             #Panics\n\n\
+             If the index or the given value is out of bounds for {}.",
+            name, name,
+        );
+        let checked_with_docs = format!(
+            "Returns a copy of the bitfield with the value of {} at the \
+             given index set to the given value.\n\n\
+             #Errors\n\n\
+             If the given value is out of bounds for {}.\n\n\
+             #Panics\n\n\
+             If the index is out of bounds for {}.",
+            name, name, name,
+        );
+        let array_setter_docs = format!(
+            "Sets the values of all elements of {} to the given values.\n\n\
+             #Panics\n\n\
+             If any of the given values is out of bounds for {}.",
+            name, name,
+        );
+        let checked_array_setter_docs = format!(
+            "Sets the values of all elements of {} to the given values.\n\n\
+             #Errors\n\n\
+             If any of the given values is out of bounds for {}. \
+             In this case the bitfield is left unchanged.",
+            name, name,
+        );
+        let array_with_docs = format!(
+            "Returns a copy of the bitfield with the values of all elements of {} \
+             set to the given values.\n\n\
+             #Panics\n\n\
+             If any of the given values is out of bounds for {}.",
+            name, name,
         );
 
-        let write_specifier = match endian {
-            Endian::Big => write_specifier_be,
-            Endian::Little => write_specifier_le,
-            Endian::Native => write_specifier_native,
+        let endian = match &config.endian {
+            Some(value) => value.value,
+            None => Endian::Native,
+        };
+        let bf_write =
+            self.expand_write_for_field(ty, &quote_spanned!(span=> __bf_offset), endian, span);
+        // Saves the raw bits of all elements so that a failing array setter can restore them.
+        let save_and_restore = |read: TokenStream2, write: TokenStream2| {
+            (
+                quote_spanned!(span=>
+                    let __bf_saved: [<#ty as ::modular_bitfield::Specifier>::Bytes; #len] =
+                        ::core::array::from_fn(|__bf_index| {
+                            #read::<#ty>(
+                                &self.bytes[..],
+                                #offset + __bf_index * <#ty as ::modular_bitfield::Specifier>::BITS,
+                            )
+                        });
+                ),
+                quote_spanned!(span=>
+                    for (__bf_index, __bf_raw_val) in ::core::iter::Iterator::enumerate(
+                        ::core::iter::IntoIterator::into_iter(__bf_saved),
+                    ) {
+                        #write::<#ty>(
+                            &mut self.bytes[..],
+                            #offset + __bf_index * <#ty as ::modular_bitfield::Specifier>::BITS,
+                            __bf_raw_val,
+                        );
+                    }
+                ),
+            )
+        };
+        let save_and_restore_le = save_and_restore(
+            quote_spanned!(span=> ::modular_bitfield::private::read_specifier_le),
+            quote_spanned!(span=> ::modular_bitfield::private::write_specifier_le),
+        );
+        let save_and_restore_be = save_and_restore(
+            quote_spanned!(span=> ::modular_bitfield::private::read_specifier_be),
+            quote_spanned!(span=> ::modular_bitfield::private::write_specifier_be),
+        );
+        let (bf_save, bf_restore) = match endian {
+            Endian::Little => save_and_restore_le,
+            Endian::Big => save_and_restore_be,
+            Endian::Native => {
+                let ((save_le, restore_le), (save_be, restore_be)) =
+                    (save_and_restore_le, save_and_restore_be);
+                (
+                    quote_spanned!(span=>
+                        #[cfg(target_endian = "big")]
+                        #save_be
+
+                        #[cfg(target_endian = "little")]
+                        #save_le
+                    ),
+                    quote_spanned!(span=>
+                        #[cfg(target_endian = "big")]
+                        #restore_be
+
+                        #[cfg(target_endian = "little")]
+                        #restore_le
+                    ),
+                )
+            }
         };
 
-        let setters = quote_spanned!(span=>
+        // The `const fn` `with_*` methods forward to the checked ones and need no `mut self`.
+        let with_self = match conversion {
+            Some(_) => quote_spanned!(span=> self),
+            None => quote_spanned!(span=> mut self),
+        };
+        let (constness, with, with_checked) = match conversion {
+            Some(conversion) => {
+                let bf_const_write = Self::expand_const_write_for_field(
+                    ty,
+                    &quote_spanned!(span=> __bf_offset),
+                    endian,
+                    conversion,
+                    span,
+                );
+                (
+                    Some(quote_spanned!(span=> const)),
+                    quote_spanned!(span=>
+                        match self.#with_checked_ident(index, new_val) {
+                            ::core::result::Result::Ok(__bf_value) => __bf_value,
+                            ::core::result::Result::Err(_) => ::core::panic!(#set_assert_msg),
+                        }
+                    ),
+                    quote_spanned!(span=>
+                        ::core::assert!(index < #len, #index_assert_msg);
+                        let __bf_offset: ::core::primitive::usize =
+                            #offset + index * <#ty as ::modular_bitfield::Specifier>::BITS;
+                        #bf_const_write
+                    ),
+                )
+            }
+            None => {
+                (
+                    None,
+                    quote_spanned!(span=>
+                        self.#set_ident(index, new_val);
+                        self
+                    ),
+                    quote_spanned!(span=>
+                        self.#set_checked_ident(index, new_val)?;
+                        ::core::result::Result::Ok(self)
+                    ),
+                )
+            }
+        };
+
+        quote_spanned!(span=>
             #[doc = #with_docs]
             #[inline]
             #[allow(dead_code)]
             #( #retained_attrs )*
-            #vis fn #with_ident(
-                mut self,
-                new_val: <#ty as modular_bitfield::Specifier>::InOut
+            #vis #constness fn #with_ident(
+                #with_self,
+                index: ::core::primitive::usize,
+                new_val: <#ty as ::modular_bitfield::Specifier>::InOut
             ) -> Self {
-                self.#set_ident(new_val);
-                self
+                #with
             }
 
             #[doc = #checked_with_docs]
             #[inline]
             #[allow(dead_code)]
             #( #retained_attrs )*
-            #vis fn #with_checked_ident(
+            #vis #constness fn #with_checked_ident(
                 mut self,
-                new_val: <#ty as modular_bitfield::Specifier>::InOut,
-            ) -> ::core::result::Result<Self, modular_bitfield::error::OutOfBounds> {
-                self.#set_checked_ident(new_val)?;
-                ::core::result::Result::Ok(self)
+                index: ::core::primitive::usize,
+                new_val: <#ty as ::modular_bitfield::Specifier>::InOut,
+            ) -> ::core::result::Result<Self, ::modular_bitfield::error::OutOfBounds> {
+                #with_checked
             }
 
             #[doc = #setter_docs]
             #[inline]
             #[allow(dead_code)]
             #( #retained_attrs )*
-            #vis fn #set_ident(&mut self, new_val: <#ty as modular_bitfield::Specifier>::InOut) {
-                self.#set_checked_ident(new_val).expect(#set_assert_msg)
+            #vis fn #set_ident(
+                &mut self,
+                index: ::core::primitive::usize,
+                new_val: <#ty as ::modular_bitfield::Specifier>::InOut
+            ) {
+                self.#set_checked_ident(index, new_val).expect(#set_assert_msg)
             }
 
             #[doc = #checked_setter_docs]
@@ -826,70 +1628,147 @@ impl BitfieldStruct {
             #( #retained_attrs )*
             #vis fn #set_checked_ident(
                 &mut self,
-                new_val: <#ty as modular_bitfield::Specifier>::InOut
-            ) -> ::core::result::Result<(), modular_bitfield::error::OutOfBounds> {
-                let __bf_base_bits: ::core::primitive::usize = 8usize * ::core::mem::size_of::<<#ty as modular_bitfield::Specifier>::Bytes>();
-                let __bf_max_value: <#ty as modular_bitfield::Specifier>::Bytes = {
-                    !0 >> (__bf_base_bits - <#ty as modular_bitfield::Specifier>::BITS)
-                };
-                let __bf_spec_bits: ::core::primitive::usize = <#ty as modular_bitfield::Specifier>::BITS;
-
-                #bf_raw_val
+                index: ::core::primitive::usize,
+                new_val: <#ty as ::modular_bitfield::Specifier>::InOut
+            ) -> ::core::result::Result<(), ::modular_bitfield::error::OutOfBounds> {
+                ::core::assert!(index < #len, #index_assert_msg);
+                let __bf_offset: ::core::primitive::usize =
+                    #offset + index * <#ty as ::modular_bitfield::Specifier>::BITS;
+                #bf_write
+                ::core::result::Result::Ok(())
+            }
 
-                // We compare base bits with spec bits to drop this condition
-                // if there cannot be invalid inputs.
-                if !(__bf_base_bits == __bf_spec_bits || __bf_raw_val <= __bf_max_value) {
-                    return ::core::result::Result::Err(modular_bitfield::error::OutOfBounds)
-                }
+            #[doc = #array_with_docs]
+            #[inline]
+            #[allow(dead_code)]
+            #( #retained_attrs )*
+            #vis fn #with_array_ident(
+                mut self,
+                new_vals: [<#ty as ::modular_bitfield::Specifier>::InOut; #len],
+            ) -> Self {
+                self.#set_array_ident(new_vals);
+                self
+            }
 
-                #write_specifier
+            #[doc = #array_setter_docs]
+            #[inline]
+            #[allow(dead_code)]
+            #( #retained_attrs )*
+            #vis fn #set_array_ident(
+                &mut self,
+                new_vals: [<#ty as ::modular_bitfield::Specifier>::InOut; #len],
+            ) {
+                self.#set_array_checked_ident(new_vals).expect(#set_assert_msg)
+            }
 
+            #[doc = #checked_array_setter_docs]
+            #[inline]
+            #( #retained_attrs )*
+            #vis fn #set_array_checked_ident(
+                &mut self,
+                new_vals: [<#ty as ::modular_bitfield::Specifier>::InOut; #len],
+            ) -> ::core::result::Result<(), ::modular_bitfield::error::OutOfBounds> {
+                #bf_save
+                let __bf_new_vals = ::core::iter::IntoIterator::into_iter(new_vals);
+                for (__bf_index, __bf_new_val) in ::core::iter::Iterator::enumerate(__bf_new_vals) {
+                    if let ::core::result::Result::Err(__bf_err) = self.#set_checked_ident(__bf_index, __bf_new_val) {
+                        #bf_restore
+                        return ::core::result::Result::Err(__bf_err)
+                    }
+                }
                 ::core::result::Result::Ok(())
             }
-        );
-        Some(setters)
+        )
     }
 
     fn expand_getters_and_setters_for_field(
         &self,
         offset: &mut Punctuated<syn::Expr, syn::Token![+]>,
         info: FieldInfo<'_>,
+        base: Option<&syn::Expr>,
+        with_setters: bool,
     ) -> Option<TokenStream2> {
         let FieldInfo {
             index: _, field, ..
         } = &info;
         let span = field.span();
-        let ty = &field.ty;
-        let getters = self.expand_getters_for_field(offset, &info);
-        let setters = self.expand_setters_for_field(offset, &info);
+        let bits = FieldInfo::bits_of(field);
+        if let Some(at) = &info.config.at {
+            let position = at.value;
+            offset.clear();
+            offset.extend(base.cloned());
+            offset.push(syn::parse_quote! { #position });
+        }
+        // The accessors of views read and write through borrowed bytes and are never `const fn`.
+        let conversion = base.is_none().then(|| info.const_conversion()).flatten();
+        let getters = self.expand_getters_for_field(offset, &info, conversion);
+        let setters = with_setters
+            .then(|| self.expand_setters_for_field(offset, &info, conversion))
+            .flatten();
         let getters_and_setters = quote_spanned!(span=>
             #getters
             #setters
         );
-        offset.push(syn::parse_quote! { <#ty as modular_bitfield::Specifier>::BITS });
+        offset.push(syn::parse_quote! { #bits });
         Some(getters_and_setters)
     }
 
-    fn expand_getters_and_setters(&self, config: &Config) -> TokenStream2 {
-        let span = self.item_struct.span();
-        let ident = &self.item_struct.ident;
+    /// Generates the getters and optionally the setters of all fields.
+    ///
+    /// The bit offsets of the fields are relative to `base` if given.
+    pub fn generate_getters_and_setters(
+        &self,
+        config: &Config,
+        base: Option<syn::Expr>,
+        with_setters: bool,
+    ) -> Vec<TokenStream2> {
         let mut offset = {
             let mut offset = Punctuated::<syn::Expr, Token![+]>::new();
-            offset.push(syn::parse_quote! { 0usize });
+            offset.push(base.clone().unwrap_or_else(|| syn::parse_quote! { 0usize }));
             offset
         };
+        self.field_infos(config)
+            .filter_map(|field_info| {
+                self.expand_getters_and_setters_for_field(
+                    &mut offset,
+                    field_info,
+                    base.as_ref(),
+                    with_setters,
+                )
+            })
+            .collect()
+    }
+
+    fn expand_getters_and_setters(&self, config: &Config) -> TokenStream2 {
+        let span = self.item_struct.span();
+        let ident = &self.item_struct.ident;
+        let (impl_generics, ty_generics, _) = self.item_struct.generics.split_for_impl();
+        let where_clause = self.generate_where_clause(config, FieldBounds::Access);
         let bits_checks = self
             .field_infos(config)
             .map(|field_info| self.expand_bits_checks_for_field(field_info));
-        let setters_and_getters = self.field_infos(config).map(|field_info| {
-            self.expand_getters_and_setters_for_field(&mut offset, field_info)
-        });
+        let setters_and_getters = self.generate_getters_and_setters(config, None, true);
+        // Checked by the associated `__BF_CHECKS` constant after monomorphization.
+        let position_checks = match self.is_generic() {
+            true => Vec::new(),
+            false => {
+                let mut checks = self.generate_position_checks(config);
+                checks.extend(self.generate_reserved_checks(config));
+                checks.extend(self.generate_default_checks(config));
+                checks
+            }
+        };
         quote_spanned!(span=>
             const _: () = {
                 #( #bits_checks )*
             };
 
-            impl #ident {
+            #[allow(clippy::identity_op)]
+            const _: () = {
+                #( #position_checks )*
+            };
+
+            impl #impl_generics #ident #ty_generics #where_clause {
                 #( #setters_and_getters )*
             }
         )
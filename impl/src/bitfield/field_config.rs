@@ -12,6 +12,34 @@ pub struct FieldConfig {
     pub skip: Option<ConfigValue<SkipWhich>>,
     /// An encountered `#[endian]` attribute on a field.
     pub endian: Option<ConfigValue<Endian>>,
+    /// An encountered `#[none = N]` attribute on an `Option<T>` field.
+    pub none: Option<ConfigValue<u128>>,
+    /// The bit position of a field pinned with `#[at = N]` or `#[bits(N..=M)]`.
+    pub at: Option<ConfigValue<usize>>,
+    /// An encountered `#[reserved = N]` attribute on a field.
+    pub reserved: Option<ConfigValue<u128>>,
+    /// An encountered `#[default = N]` attribute on a field.
+    pub default: Option<ConfigValue<DefaultValue>>,
+    /// An encountered `#[const_fn]` attribute on a field.
+    pub const_fn: Option<ConfigValue<()>>,
+}
+
+/// The value `N` of a `#[default = N]` attribute on a field.
+#[derive(Copy, Clone)]
+pub struct DefaultValue {
+    /// The absolute value of `N`.
+    pub magnitude: u128,
+    /// Whether `N` is negative.
+    pub negative: bool,
+}
+
+impl core::fmt::Display for DefaultValue {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        if self.negative {
+            f.write_str("-")?;
+        }
+        write!(f, "{}", self.magnitude)
+    }
 }
 
 /// Controls which parts of the code generation to skip.
@@ -79,6 +107,117 @@ impl FieldConfig {
         Ok(())
     }
 
+    /// Sets the `#[at = N]` if found for a `#[bitfield]` annotated field.
+    ///
+    /// # Errors
+    ///
+    /// If previously already registered a bit position for the field.
+    pub fn at(&mut self, position: usize, span: Span) -> Result<(), syn::Error> {
+        match self.at {
+            Some(ref previous) => {
+                return Err(format_err!(
+                    span,
+                    "encountered duplicate bit position for field"
+                )
+                .into_combine(format_err!(previous.span, "duplicate bit position here")))
+            }
+            None => {
+                self.at = Some(ConfigValue {
+                    value: position,
+                    span,
+                })
+            }
+        }
+        Ok(())
+    }
+
+    /// Sets the `#[reserved = N]` if found for a `#[bitfield]` annotated field.
+    ///
+    /// # Errors
+    ///
+    /// If previously already registered a `#[reserved = M]`.
+    pub fn reserved(&mut self, value: u128, span: Span) -> Result<(), syn::Error> {
+        match self.reserved {
+            Some(ref previous) => {
+                return Err(format_err!(
+                    span,
+                    "encountered duplicate `#[reserved = N]` attribute for field"
+                )
+                .into_combine(format_err!(previous.span, "duplicate `#[reserved = M]` here")))
+            }
+            None => {
+                self.reserved = Some(ConfigValue { value, span })
+            }
+        }
+        Ok(())
+    }
+
+    /// Sets the `#[default = N]` if found for a `#[bitfield]` annotated field.
+    ///
+    /// # Errors
+    ///
+    /// If previously already registered a `#[default = M]`.
+    pub fn default_value(&mut self, value: DefaultValue, span: Span) -> Result<(), syn::Error> {
+        match self.default {
+            Some(ref previous) => {
+                return Err(format_err!(
+                    span,
+                    "encountered duplicate `#[default = N]` attribute for field"
+                )
+                .into_combine(format_err!(previous.span, "duplicate `#[default = M]` here")))
+            }
+            None => {
+                self.default = Some(ConfigValue { value, span })
+            }
+        }
+        Ok(())
+    }
+
+    /// Sets the `#[const_fn]` if found for a `#[bitfield]` annotated field.
+    ///
+    /// # Errors
+    ///
+    /// If previously already registered a `#[const_fn]`.
+    pub fn const_fn(&mut self, span: Span) -> Result<(), syn::Error> {
+        match self.const_fn {
+            Some(ref previous) => {
+                return Err(format_err!(
+                    span,
+                    "encountered duplicate `#[const_fn]` attribute for field"
+                )
+                .into_combine(format_err!(previous.span, "duplicate `#[const_fn]` here")))
+            }
+            None => {
+                self.const_fn = Some(ConfigValue { value: (), span })
+            }
+        }
+        Ok(())
+    }
+
+    /// Sets the `#[none = N]` if found for a `#[bitfield]` annotated field.
+    ///
+    /// # Errors
+    ///
+    /// If previously already registered a `#[none = M]`.
+    pub fn none(&mut self, pattern: u128, span: Span) -> Result<(), syn::Error> {
+        match self.none {
+            Some(ref previous) => {
+                return Err(format_err!(
+                    span,
+                    "encountered duplicate `#[none = N]` attribute for field"
+                )
+                .into_combine(format_err!(previous.span, "duplicate `#[none = M]` here")))
+            }
+            None => {
+                self.none = Some(ConfigValue {
+                    value: pattern,
+                    span,
+                })
+            }
+        }
+        Ok(())
+    }
+
     /// Sets the `#[skip(which)]` if found for a `#[bitfield]` annotated field.
     ///
     /// # Syntax
@@ -141,12 +280,16 @@ impl FieldConfig {
     }
 
     /// Returns `true` if the config demands that code generation for setters should be skipped.
+    ///
+    /// Setters are never generated for `#[reserved = N]` fields.
     pub fn skip_setters(&self) -> bool {
-        self.skip
-            .as_ref()
-            .map(|config| config.value)
-            .map(SkipWhich::skip_setters)
-            .unwrap_or(false)
+        self.reserved.is_some()
+            || self
+                .skip
+                .as_ref()
+                .map(|config| config.value)
+                .map(SkipWhich::skip_setters)
+                .unwrap_or(false)
     }
 
     /// Returns `true` if the config demands that code generation for getters should be skipped.
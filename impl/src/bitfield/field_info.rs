@@ -3,6 +3,9 @@ use super::{
     BitfieldStruct,
     Config,
 };
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote_spanned;
+use syn::spanned::Spanned as _;
 
 /// Compactly stores all shared and useful information about a single `#[bitfield]` field.
 pub struct FieldInfo<'a> {
@@ -37,6 +40,50 @@ impl<'a> FieldInfo<'a> {
         Self::ident_as_string(self.field, self.index)
     }
 
+    /// Returns the `Specifier` type of the field.
+    ///
+    /// For array fields such as `[B4; 8]` this is the type of the array elements.
+    pub fn specifier_ty(&self) -> &'a syn::Type {
+        Self::specifier_ty_of(self.field)
+    }
+
+    /// Returns the length of the field if it is an array field such as `[B4; 8]`.
+    pub fn array_len(&self) -> Option<&'a syn::Expr> {
+        match &self.field.ty {
+            syn::Type::Array(array) => Some(&array.len),
+            _ => None,
+        }
+    }
+
+    /// Returns the `Specifier` type of the given field.
+    pub fn specifier_ty_of(field: &syn::Field) -> &syn::Type {
+        match &field.ty {
+            syn::Type::Array(array) => &array.elem,
+            ty => ty,
+        }
+    }
+
+    /// Returns the expression denoting the bit width of the given field.
+    ///
+    /// For array fields this is the bit width of the elements times the array length.
+    pub fn bits_of(field: &syn::Field) -> TokenStream2 {
+        let span = field.span();
+        match &field.ty {
+            syn::Type::Array(array) => {
+                let elem = &array.elem;
+                let len = &array.len;
+                quote_spanned!(span=>
+                    (<#elem as ::modular_bitfield::Specifier>::BITS * (#len))
+                )
+            }
+            ty => {
+                quote_spanned!(span=>
+                    <#ty as ::modular_bitfield::Specifier>::BITS
+                )
+            }
+        }
+    }
+
     /// Returns the field's identifier at the given index as `String`.
     pub fn ident_as_string(field: &'a syn::Field, index: usize) -> String {
         field
@@ -54,11 +101,7 @@ impl BitfieldStruct {
     pub fn fields(
         item_struct: &syn::ItemStruct,
     ) -> impl Iterator<Item = (usize, &syn::Field)> {
-        item_struct
-            .fields
-            .iter()
-            .enumerate()
-            .map(|(n, field)| (n, field))
+        item_struct.fields.iter().enumerate()
     }
 
     /// Returns an iterator over the names of the fields.
@@ -0,0 +1,239 @@
+use super::{
+    field_info::FieldInfo,
+    BitfieldStruct,
+    Config,
+};
+use proc_macro2::{
+    TokenStream as TokenStream2,
+    TokenTree,
+};
+use quote::{
+    quote_spanned,
+    ToTokens as _,
+};
+use syn::spanned::Spanned as _;
+
+/// The bounds required for the generic fields of a `#[bitfield]` struct.
+#[derive(Copy, Clone, PartialEq, Eq)]
+pub enum FieldBounds {
+    /// The generic field types must implement `Specifier`.
+    Specifier,
+    /// The generic field types must additionally be readable and writable.
+    Access,
+    /// The generic field types must additionally be printable via `Debug`.
+    Debug,
+    /// The generic field types must additionally be printable via `defmt`.
+    Defmt,
+    /// The generic field types must additionally be serializable via `serde`.
+    Serialize,
+    /// The generic field types must additionally be deserializable via `serde`
+    /// for the lifetime `'de`.
+    Deserialize,
+}
+
+impl BitfieldStruct {
+    /// Returns `true` if the `#[bitfield]` struct has generic type parameters.
+    pub fn is_generic(&self) -> bool {
+        !self.item_struct.generics.params.is_empty()
+    }
+
+    /// Returns `true` if the given field type refers to any of the generic type
+    /// parameters of the `#[bitfield]` struct.
+    pub fn is_generic_type(&self, ty: &syn::Type) -> bool {
+        fn contains_any(tokens: TokenStream2, idents: &[&syn::Ident]) -> bool {
+            tokens.into_iter().any(|tt| {
+                match tt {
+                    TokenTree::Ident(ident) => idents.contains(&&ident),
+                    TokenTree::Group(group) => contains_any(group.stream(), idents),
+                    _ => false,
+                }
+            })
+        }
+        let params = self
+            .item_struct
+            .generics
+            .type_params()
+            .map(|param| &param.ident)
+            .collect::<Vec<_>>();
+        !params.is_empty() && contains_any(ty.to_token_stream(), &params)
+    }
+
+    /// Returns the where clause of the `#[bitfield]` struct extended by the given
+    /// bounds for all of its generic field types.
+    pub fn generate_where_clause(
+        &self,
+        config: &Config,
+        bounds: FieldBounds,
+    ) -> syn::WhereClause {
+        let mut generics = self.item_struct.generics.clone();
+        let where_clause = generics.make_where_clause();
+        for info in self.field_infos(config) {
+            let ty = info.specifier_ty();
+            if !self.is_generic_type(ty) {
+                continue
+            }
+            let span = ty.span();
+            let predicates: Vec<syn::WherePredicate> = match bounds {
+                FieldBounds::Specifier => {
+                    vec![syn::parse_quote_spanned!(span=>
+                        #ty: ::modular_bitfield::Specifier
+                    )]
+                }
+                FieldBounds::Access
+                | FieldBounds::Debug
+                | FieldBounds::Defmt
+                | FieldBounds::Serialize
+                | FieldBounds::Deserialize => {
+                    vec![
+                        syn::parse_quote_spanned!(span=>
+                            #ty: ::modular_bitfield::Specifier
+                        ),
+                        syn::parse_quote_spanned!(span=>
+                            <#ty as ::modular_bitfield::Specifier>::Bytes:
+                                ::modular_bitfield::private::GenericSpecifierBytes
+                        ),
+                        syn::parse_quote_spanned!(span=>
+                            ::modular_bitfield::private::PushBuffer<<#ty as ::modular_bitfield::Specifier>::Bytes>:
+                                ::core::default::Default + ::modular_bitfield::private::PushBits
+                        ),
+                        syn::parse_quote_spanned!(span=>
+                            ::modular_bitfield::private::PopBuffer<<#ty as ::modular_bitfield::Specifier>::Bytes>:
+                                ::modular_bitfield::private::PopBits
+                        ),
+                    ]
+                }
+            };
+            where_clause.predicates.extend(predicates);
+            match bounds {
+                FieldBounds::Debug => {
+                    where_clause.predicates.push(syn::parse_quote_spanned!(span=>
+                        <#ty as ::modular_bitfield::Specifier>::InOut: ::core::fmt::Debug
+                    ));
+                }
+                FieldBounds::Defmt => {
+                    where_clause.predicates.push(syn::parse_quote_spanned!(span=>
+                        <#ty as ::modular_bitfield::Specifier>::InOut:
+                            ::modular_bitfield::private::defmt::Format
+                    ));
+                    where_clause.predicates.push(syn::parse_quote_spanned!(span=>
+                        <#ty as ::modular_bitfield::Specifier>::Bytes:
+                            ::modular_bitfield::private::defmt::Format
+                    ));
+                }
+                FieldBounds::Serialize => {
+                    where_clause.predicates.push(syn::parse_quote_spanned!(span=>
+                        <#ty as ::modular_bitfield::Specifier>::InOut:
+                            ::modular_bitfield::private::serde::Serialize
+                    ));
+                }
+                FieldBounds::Deserialize => {
+                    where_clause.predicates.push(syn::parse_quote_spanned!(span=>
+                        <#ty as ::modular_bitfield::Specifier>::InOut:
+                            ::modular_bitfield::private::serde::Deserialize<'de>
+                    ));
+                }
+                FieldBounds::Specifier | FieldBounds::Access => {}
+            }
+        }
+        where_clause.clone()
+    }
+
+    /// Generates the initializer of the marker field of generic `#[bitfield]` structs.
+    ///
+    /// Returns `None` for non-generic `#[bitfield]` structs.
+    pub fn generate_marker_init(&self) -> Option<TokenStream2> {
+        let span = self.item_struct.span();
+        self.is_generic().then(|| {
+            quote_spanned!(span=>
+                __bf_marker: ::core::marker::PhantomData,
+            )
+        })
+    }
+
+    /// Generates the statement that forces evaluation of the compile-time checks
+    /// of generic `#[bitfield]` structs.
+    ///
+    /// Returns `None` for non-generic `#[bitfield]` structs.
+    pub fn generate_generic_checks_usage(&self) -> Option<TokenStream2> {
+        let span = self.item_struct.span();
+        self.is_generic().then(|| {
+            quote_spanned!(span=>
+                let () = Self::__BF_CHECKS;
+            )
+        })
+    }
+
+    /// Generates the compile-time checks for generic `#[bitfield]` structs.
+    ///
+    /// The bit widths of generic fields are only known after monomorphization.
+    /// Therefore the checks are performed by an associated constant that is
+    /// evaluated whenever an instance of the bitfield is constructed.
+    pub fn generate_generic_checks(&self, config: &Config) -> TokenStream2 {
+        let span = self.item_struct.span();
+        let ident = &self.item_struct.ident;
+        let (impl_generics, ty_generics, _) = self.item_struct.generics.split_for_impl();
+        let where_clause = self.generate_where_clause(config, FieldBounds::Specifier);
+        let bits = config
+            .bits
+            .as_ref()
+            .expect("generic bitfield structs must have a `bits = N` parameter")
+            .value;
+        let actual_bits = self.generate_bitfield_size(config);
+        let filled_check = match config.filled_enabled() {
+            true => {
+                let message = format!(
+                    "the fields of {} must have a total bit width of {}",
+                    ident, bits,
+                );
+                quote_spanned!(span=>
+                    ::core::assert!(#bits == #actual_bits, #message);
+                )
+            }
+            false => {
+                let message = format!(
+                    "the fields of {} must have a total bit width below {} for `filled = false`",
+                    ident, bits,
+                );
+                quote_spanned!(span=>
+                    ::core::assert!(#bits > #actual_bits, #message);
+                )
+            }
+        };
+        let field_checks = self.field_infos(config).filter_map(|info| {
+            let bits = info.config.bits.as_ref()?;
+            if !self.is_generic_type(&info.field.ty) {
+                return None
+            }
+            let actual_bits = FieldInfo::bits_of(info.field);
+            let expected_bits = bits.value;
+            let message = format!(
+                "field {}.{} must have a bit width of {}",
+                ident,
+                info.name(),
+                expected_bits,
+            );
+            Some(quote_spanned!(bits.span=>
+                ::core::assert!(
+                    #actual_bits == #expected_bits,
+                    #message
+                );
+            ))
+        });
+        let position_checks = self.generate_position_checks(config);
+        let reserved_checks = self.generate_reserved_checks(config);
+        let default_checks = self.generate_default_checks(config);
+        quote_spanned!(span=>
+            impl #impl_generics #ident #ty_generics #where_clause {
+                #[doc(hidden)]
+                #[allow(clippy::identity_op)]
+                const __BF_CHECKS: () = {
+                    #filled_check
+                    #( #field_checks )*
+                    #( #position_checks )*
+                    #( #reserved_checks )*
+                    #( #default_checks )*
+                };
+            }
+        )
+    }
+}
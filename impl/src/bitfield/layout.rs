@@ -0,0 +1,143 @@
+use super::{
+    config::BitOrder,
+    field_info::FieldInfo,
+    generics::FieldBounds,
+    BitfieldStruct,
+    Config,
+    Endian,
+};
+use proc_macro2::TokenStream as TokenStream2;
+use quote::{
+    quote_spanned,
+    ToTokens as _,
+};
+use syn::spanned::Spanned as _;
+
+/// Returns the given type as written in source, e.g. `Option<Mode>` instead of `Option < Mode >`.
+///
+/// Only whitespace between two identifier characters is kept.
+fn type_name(ty: &syn::Type) -> String {
+    let tokens = ty.to_token_stream().to_string();
+    let is_ident_char = |c: char| c.is_alphanumeric() || c == '_';
+    let chars = tokens.chars().collect::<Vec<_>>();
+    chars
+        .iter()
+        .enumerate()
+        .filter(|&(n, &c)| {
+            !c.is_whitespace()
+                || (n > 0
+                    && is_ident_char(chars[n - 1])
+                    && matches!(chars.get(n + 1), Some(&next) if is_ident_char(next)))
+        })
+        .map(|(_, &c)| c)
+        .collect()
+}
+
+/// Returns `T` of the `NoneAt<T, N>` type that replaced an `Option<T>` field with `#[none = N]`.
+fn none_at_inner_type(ty: &syn::Type) -> syn::Type {
+    if let syn::Type::Path(type_path) = ty {
+        if let Some(segment) = type_path.path.segments.last() {
+            if let syn::PathArguments::AngleBracketed(args) = &segment.arguments {
+                if let Some(syn::GenericArgument::Type(inner)) = args.args.first() {
+                    return inner.clone()
+                }
+            }
+        }
+    }
+    ty.clone()
+}
+
+/// Returns the `modular_bitfield::layout::Endian` variant denoting the given byte order.
+fn endian_variant(endian: Option<Endian>, span: proc_macro2::Span) -> TokenStream2 {
+    match endian {
+        Some(Endian::Little) => quote_spanned!(span=> Little),
+        Some(Endian::Big) => quote_spanned!(span=> Big),
+        Some(Endian::Native) | None => quote_spanned!(span=> Native),
+    }
+}
+
+impl BitfieldStruct {
+    /// Generates the `FIELDS` constant and the `layout_json` function describing the fields
+    /// of the `#[bitfield]` struct.
+    ///
+    /// The offsets and bit widths are the same `Specifier::BITS` sums used by the getters and setters.
+    pub fn generate_field_table(&self, config: &Config) -> TokenStream2 {
+        let span = self.item_struct.span();
+        let ident = &self.item_struct.ident;
+        let vis = &self.item_struct.vis;
+        let (impl_generics, ty_generics, _) = self.item_struct.generics.split_for_impl();
+        let where_clause = self.generate_where_clause(config, FieldBounds::Specifier);
+        let offsets = self.generate_field_offsets(config);
+        let generic_checks = self.generate_generic_checks_usage();
+        let name = ident.to_string();
+        let size = self.generate_target_or_actual_bitfield_size(config);
+        let next_divisible_by_8 = Self::next_divisible_by_8(&size);
+        let struct_endian = endian_variant(config.endian.as_ref().map(|endian| endian.value), span);
+        let bit_order = match config.bit_order.as_ref().map(|bit_order| bit_order.value) {
+            Some(BitOrder::Msb0) => quote_spanned!(span=> Msb0),
+            Some(BitOrder::Lsb0) | None => quote_spanned!(span=> Lsb0),
+        };
+        let entries = self
+            .field_infos(config)
+            .zip(offsets)
+            .map(|(info, offset)| {
+                let span = info.field.span();
+                let name = info.name();
+                let bits = FieldInfo::bits_of(info.field);
+                let array_len = match info.array_len() {
+                    Some(len) => quote_spanned!(span=> ::core::option::Option::Some(#len)),
+                    None => quote_spanned!(span=> ::core::option::Option::None),
+                };
+                let endian =
+                    endian_variant(info.config.endian.as_ref().map(|endian| endian.value), span);
+                let skip_getters = info.config.skip_getters();
+                let skip_setters = info.config.skip_setters();
+                let ty = info.specifier_ty();
+                let specifier = match info.config.none {
+                    Some(_) => format!("Option<{}>", type_name(&none_at_inner_type(ty))),
+                    None => type_name(ty),
+                };
+                quote_spanned!(span=>
+                    ::modular_bitfield::layout::FieldInfo::new(
+                        #name,
+                        #offset,
+                        #bits,
+                        #array_len,
+                        ::modular_bitfield::layout::Endian::#endian,
+                        #skip_getters,
+                        #skip_setters,
+                        #specifier,
+                        <#ty as ::modular_bitfield::Specifier>::VARIANTS,
+                    )
+                )
+            });
+        quote_spanned!(span=>
+            #[allow(clippy::identity_op)]
+            impl #impl_generics #ident #ty_generics #where_clause {
+                /// Describes the fields of the bitfield in declaration order.
+                #[allow(dead_code)]
+                #vis const FIELDS: &'static [::modular_bitfield::layout::FieldInfo] = {
+                    #generic_checks
+                    &[
+                        #( #entries ),*
+                    ]
+                };
+
+                /// Returns the layout of the bitfield that is displayed as a JSON object.
+                #[inline]
+                #[allow(dead_code, unused_braces)]
+                #vis const fn layout_json() -> ::modular_bitfield::layout::LayoutJson {
+                    #generic_checks
+                    ::modular_bitfield::layout::LayoutJson::new(
+                        #name,
+                        #size,
+                        #next_divisible_by_8 / 8usize,
+                        ::modular_bitfield::layout::Endian::#struct_endian,
+                        ::modular_bitfield::layout::BitOrder::#bit_order,
+                        Self::FIELDS,
+                    )
+                }
+            }
+        )
+    }
+}
@@ -1,9 +1,23 @@
 mod analyse;
+mod arbitrary;
+mod builder;
+mod bytemuck;
 mod config;
+mod const_fn;
+mod defaults;
+mod defmt;
 mod expand;
 mod field_config;
 mod field_info;
+mod generics;
+mod layout;
 mod params;
+mod reflect;
+mod reserved;
+mod serde;
+mod validate;
+mod views;
+mod zerocopy;
 
 use self::{
     config::Config,
@@ -1,6 +1,10 @@
 use std::convert::TryInto;
 
-use super::config::Config;
+use super::config::{
+    ArbitraryMode,
+    Config,
+    ConfigValue,
+};
 use proc_macro2::Span;
 use syn::{
     parse::Result,
@@ -119,11 +123,7 @@ impl Config {
         assert!(name_value.path.is_ident("endian"));
         match &name_value.lit {
             syn::Lit::Str(lit_str) => {
-                let endian = match lit_str.value().try_into() {
-                    Ok(endian) => endian,
-                    Err(err) => return Err(err),
-                };
-
+                let endian = lit_str.value().try_into()?;
                 self.endian(endian, name_value.span())?;
             }
             invalid => {
@@ -136,6 +136,106 @@ impl Config {
         Ok(())
     }
 
+    /// Feeds a `bit_order: string` parameter to the `#[bitfield]` configuration.
+    fn feed_bit_order_param(&mut self, name_value: syn::MetaNameValue) -> Result<()> {
+        assert!(name_value.path.is_ident("bit_order"));
+        match &name_value.lit {
+            syn::Lit::Str(lit_str) => {
+                let bit_order = lit_str.value().try_into()?;
+                self.bit_order(bit_order, name_value.span())?;
+            }
+            invalid => {
+                return Err(format_err!(
+                invalid,
+                "encountered invalid value argument for #[bitfield] `bit_order` parameter",
+            ))
+            }
+        }
+        Ok(())
+    }
+
+    /// Feeds a `bytemuck` parameter to the `#[bitfield]` configuration.
+    fn feed_bytemuck_param(&mut self, path: syn::Path) -> Result<()> {
+        if !cfg!(feature = "bytemuck") {
+            return Err(format_err!(
+                path,
+                "the #[bitfield] `bytemuck` parameter requires the `bytemuck` crate feature",
+            ))
+        }
+        self.bytemuck(path.span())
+    }
+
+    /// Feeds a `serde: string` parameter to the `#[bitfield]` configuration.
+    fn feed_serde_param(&mut self, name_value: syn::MetaNameValue) -> Result<()> {
+        assert!(name_value.path.is_ident("serde"));
+        if !cfg!(feature = "serde") {
+            return Err(format_err!(
+                name_value,
+                "the #[bitfield] `serde` parameter requires the `serde` crate feature",
+            ))
+        }
+        match &name_value.lit {
+            syn::Lit::Str(lit_str) => {
+                let mode = lit_str.value().try_into()?;
+                self.serde(mode, name_value.span())?;
+            }
+            invalid => {
+                return Err(format_err!(
+                invalid,
+                "encountered invalid value argument for #[bitfield] `serde` parameter",
+            ))
+            }
+        }
+        Ok(())
+    }
+
+    /// Parses the value of an `arbitrary: string` or `proptest: string` parameter.
+    ///
+    /// # Errors
+    ///
+    /// If the crate feature of the same name is disabled or the value is invalid.
+    fn parse_arbitrary_mode(
+        name_value: &syn::MetaNameValue,
+        param: &str,
+        enabled: bool,
+    ) -> Result<ArbitraryMode> {
+        if !enabled {
+            return Err(format_err!(
+                name_value,
+                "the #[bitfield] `{}` parameter requires the `{}` crate feature",
+                param,
+                param,
+            ))
+        }
+        match &name_value.lit {
+            syn::Lit::Str(lit_str) if lit_str.value() == "fields" => Ok(ArbitraryMode::Fields),
+            syn::Lit::Str(lit_str) if lit_str.value() == "bytes" => Ok(ArbitraryMode::Bytes),
+            invalid => {
+                Err(format_err!(
+                    invalid,
+                    "encountered invalid value argument for #[bitfield] `{}` parameter, expected \"fields\" or \"bytes\"",
+                    param,
+                ))
+            }
+        }
+    }
+
+    /// Feeds an `arbitrary: string` parameter to the `#[bitfield]` configuration.
+    fn feed_arbitrary_param(&mut self, name_value: syn::MetaNameValue) -> Result<()> {
+        assert!(name_value.path.is_ident("arbitrary"));
+        let mode =
+            Self::parse_arbitrary_mode(&name_value, "arbitrary", cfg!(feature = "arbitrary"))?;
+        self.arbitrary(mode, name_value.span())
+    }
+
+    /// Feeds a `proptest: string` parameter to the `#[bitfield]` configuration.
+    fn feed_proptest_param(&mut self, name_value: syn::MetaNameValue) -> Result<()> {
+        assert!(name_value.path.is_ident("proptest"));
+        let mode =
+            Self::parse_arbitrary_mode(&name_value, "proptest", cfg!(feature = "proptest"))?;
+        self.proptest(mode, name_value.span())
+    }
+
     /// Feeds the given parameters to the `#[bitfield]` configuration.
     ///
     /// # Errors
@@ -158,16 +258,40 @@ impl Config {
                                 self.feed_filled_param(name_value)?;
                             } else if name_value.path.is_ident("endian") {
                                 self.feed_endian_param(name_value)?;
+                            } else if name_value.path.is_ident("bit_order") {
+                                self.feed_bit_order_param(name_value)?;
+                            } else if name_value.path.is_ident("serde") {
+                                self.feed_serde_param(name_value)?;
+                            } else if name_value.path.is_ident("arbitrary") {
+                                self.feed_arbitrary_param(name_value)?;
+                            } else if name_value.path.is_ident("proptest") {
+                                self.feed_proptest_param(name_value)?;
                             } else {
                                 return Err(unsupported_argument(name_value))
                             }
                         }
+                        syn::Meta::Path(path) if path.is_ident("views") => {
+                            self.views(path.span())?;
+                        }
+                        syn::Meta::Path(path) if path.is_ident("builder") => {
+                            self.builder(path.span())?;
+                        }
+                        syn::Meta::Path(path) if path.is_ident("bytemuck") => {
+                            self.feed_bytemuck_param(path)?;
+                        }
+                        syn::Meta::Path(path) if path.is_ident("reflect") => {
+                            self.reflect(path.span())?;
+                        }
                         unsupported => return Err(unsupported_argument(unsupported)),
                     }
                 }
                 unsupported => return Err(unsupported_argument(unsupported)),
             }
         }
+        // Without an explicit `endian` the bit order implies the endianness sharing its layout.
+        if let (Some(bit_order), None) = (&self.bit_order, &self.endian) {
+            self.endian = Some(ConfigValue::new(bit_order.value.natural_endian(), bit_order.span));
+        }
         Ok(())
     }
 }
@@ -0,0 +1,318 @@
+use super::{
+    field_info::FieldInfo,
+    generics::FieldBounds,
+    BitfieldStruct,
+    Config,
+    Endian,
+};
+use crate::errors::CombineError;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::{
+    format_ident,
+    quote_spanned,
+};
+use std::collections::HashMap;
+use syn::spanned::Spanned as _;
+
+impl BitfieldStruct {
+    /// Returns the fields that can be addressed by the field enum of the `#[bitfield]` struct.
+    ///
+    /// These are all fields that have getters.
+    fn reflect_fields<'a>(
+        &'a self,
+        config: &'a Config,
+    ) -> impl Iterator<Item = (FieldInfo<'a>, TokenStream2)> {
+        self.field_infos(config)
+            .zip(self.generate_field_offsets(config))
+            .filter(|(info, _)| !info.config.skip_getters())
+    }
+
+    /// Returns the variant of the field enum that denotes the given field.
+    ///
+    /// Named fields are converted to upper camel case. Tuple fields and fields such as `__`
+    /// that have no upper camel case name are named `FieldN` after their field number.
+    fn reflect_variant(info: &FieldInfo) -> syn::Ident {
+        let span = info.field.span();
+        let name = info
+            .field
+            .ident
+            .as_ref()
+            .map(|ident| {
+                ident
+                    .to_string()
+                    .trim_start_matches("r#")
+                    .split('_')
+                    .filter(|part| !part.is_empty())
+                    .map(|part| {
+                        let mut chars = part.chars();
+                        chars
+                            .next()
+                            .map(|first| first.to_uppercase().chain(chars).collect::<String>())
+                            .unwrap_or_default()
+                    })
+                    .collect::<String>()
+            })
+            .filter(|name| name.starts_with(char::is_alphabetic))
+            .unwrap_or_else(|| format!("Field{}", info.index));
+        format_ident!("{}", name, span = span)
+    }
+
+    /// Returns an error if two fields map onto the same variant or name of the field enum.
+    ///
+    /// This happens for field names that only differ in their underscores, e.g. `lane_0`
+    /// and `lane0`, or for a field such as `field6` next to a `__` field with number 6.
+    pub fn ensure_unique_reflect_variants(&self, config: &Config) -> syn::Result<()> {
+        if config.reflect.is_none() {
+            return Ok(())
+        }
+        let field_ident = format_ident!("{}Field", self.item_struct.ident);
+        let mut variants = HashMap::new();
+        let mut names = HashMap::new();
+        for (info, _) in self.reflect_fields(config) {
+            let span = info.field.span();
+            let name = info.name();
+            let variant = Self::reflect_variant(&info);
+            if let Some((previous_span, previous_name)) =
+                variants.insert(variant.to_string(), (span, name.clone()))
+            {
+                return Err(format_err!(
+                    span,
+                    "field `{}` maps onto the variant `{}::{}` of field `{}`",
+                    name,
+                    field_ident,
+                    variant,
+                    previous_name,
+                )
+                .into_combine(format_err!(previous_span, "field `{}` here", previous_name)))
+            }
+            if let Some(previous_span) = names.insert(name.clone(), span) {
+                return Err(format_err!(
+                    span,
+                    "field `{}` cannot be told apart from a previous field of the same name by `{}`",
+                    name,
+                    field_ident,
+                )
+                .into_combine(format_err!(previous_span, "previous field `{}` here", name)))
+            }
+        }
+        Ok(())
+    }
+
+    /// Generates the `FooField` enum and the `get_raw` and `set_raw` methods of the
+    /// `#[bitfield]` struct.
+    ///
+    /// The field enum has one variant per field with getters where the variants of array
+    /// fields carry the index of the element. It implements `FromStr` and `Display` using
+    /// the field names, so that `lanes[2]` denotes the third element of the array field `lanes`.
+    ///
+    /// Returns `None` if the `reflect` parameter has not been set.
+    pub fn generate_reflect(&self, config: &Config) -> Option<TokenStream2> {
+        config.reflect.as_ref()?;
+        let span = self.item_struct.span();
+        let ident = &self.item_struct.ident;
+        let vis = &self.item_struct.vis;
+        let field_ident = format_ident!("{}Field", ident);
+        let (impl_generics, ty_generics, _) = self.item_struct.generics.split_for_impl();
+        let where_clause = self.generate_where_clause(config, FieldBounds::Access);
+
+        let mut variants = Vec::new();
+        let mut from_str_arms = Vec::new();
+        let mut from_str_indexed = Vec::new();
+        let mut display_arms = Vec::new();
+        let mut get_arms = Vec::new();
+        let mut set_arms = Vec::new();
+        for (info, offset) in self.reflect_fields(config) {
+            let span = info.field.span();
+            let variant = Self::reflect_variant(&info);
+            let name = info.name();
+            let ty = info.specifier_ty();
+            let endian = info
+                .config
+                .endian
+                .as_ref()
+                .map(|endian| endian.value)
+                .unwrap_or(Endian::Native);
+            let set_checked_ident = format_ident!("set_{}_checked", info.ident_frag());
+            match info.array_len() {
+                Some(len) => {
+                    let docs =
+                        format!("The element of the array field `{}` at the given index.", name);
+                    let index_msg = format!("index out of bounds for field {}.{}", ident, name);
+                    variants.push(quote_spanned!(span=>
+                        #[doc = #docs]
+                        #variant(::core::primitive::usize)
+                    ));
+                    from_str_indexed.push(quote_spanned!(span=>
+                        if let ::core::option::Option::Some(__bf_index) =
+                            ::modular_bitfield::private::parse_field_index(s, #name)
+                        {
+                            if __bf_index < #len {
+                                return ::core::result::Result::Ok(Self::#variant(__bf_index))
+                            }
+                        }
+                    ));
+                    display_arms.push(quote_spanned!(span=>
+                        Self::#variant(__bf_index) => ::core::write!(f, "{}[{}]", #name, __bf_index)
+                    ));
+                    let bf_read = Self::expand_read_for_field(
+                        ty,
+                        &quote_spanned!(span=> __bf_offset),
+                        endian,
+                        span,
+                    );
+                    get_arms.push(quote_spanned!(span=>
+                        #field_ident::#variant(__bf_index) => {
+                            ::core::assert!(__bf_index < #len, #index_msg);
+                            let __bf_offset: ::core::primitive::usize =
+                                #offset + __bf_index * <#ty as ::modular_bitfield::Specifier>::BITS;
+                            #bf_read
+                            ::modular_bitfield::private::bytes_to_u128(__bf_read)
+                        }
+                    ));
+                    if !info.config.skip_setters() {
+                        set_arms.push(quote_spanned!(span=>
+                            #field_ident::#variant(__bf_index) => {
+                                ::core::assert!(__bf_index < #len, #index_msg);
+                                let __bf_value = Self::__bf_raw_to_value::<#ty>(raw)?;
+                                self.#set_checked_ident(__bf_index, __bf_value)
+                            }
+                        ));
+                    }
+                }
+                None => {
+                    let docs = format!("The field `{}`.", name);
+                    variants.push(quote_spanned!(span=>
+                        #[doc = #docs]
+                        #variant
+                    ));
+                    from_str_arms.push(quote_spanned!(span=>
+                        #name => return ::core::result::Result::Ok(Self::#variant)
+                    ));
+                    display_arms.push(quote_spanned!(span=>
+                        Self::#variant => f.write_str(#name)
+                    ));
+                    let bf_read = Self::expand_read_for_field(ty, &offset, endian, span);
+                    get_arms.push(quote_spanned!(span=>
+                        #field_ident::#variant => {
+                            #bf_read
+                            ::modular_bitfield::private::bytes_to_u128(__bf_read)
+                        }
+                    ));
+                    if !info.config.skip_setters() {
+                        set_arms.push(quote_spanned!(span=>
+                            #field_ident::#variant => {
+                                let __bf_value = Self::__bf_raw_to_value::<#ty>(raw)?;
+                                self.#set_checked_ident(__bf_value)
+                            }
+                        ));
+                    }
+                }
+            }
+        }
+        let enum_docs = format!(
+            "Denotes a field of [`{0}`] for [`{0}::get_raw`] and [`{0}::set_raw`].",
+            ident,
+        );
+        let set_fallback = match set_arms.len() < variants.len() {
+            true => {
+                Some(quote_spanned!(span=>
+                    _ => ::core::result::Result::Err(::modular_bitfield::error::OutOfBounds),
+                ))
+            }
+            false => None,
+        };
+        Some(quote_spanned!(span=>
+            #[doc = #enum_docs]
+            #[derive(
+                ::core::fmt::Debug,
+                ::core::marker::Copy,
+                ::core::clone::Clone,
+                ::core::cmp::PartialEq,
+                ::core::cmp::Eq,
+                ::core::hash::Hash,
+            )]
+            #vis enum #field_ident {
+                #( #variants ),*
+            }
+
+            impl ::core::str::FromStr for #field_ident {
+                type Err = ::modular_bitfield::error::UnknownField;
+
+                fn from_str(s: &::core::primitive::str) -> ::core::result::Result<Self, Self::Err> {
+                    #[allow(clippy::match_single_binding, clippy::needless_return)]
+                    match s {
+                        #( #from_str_arms, )*
+                        _ => {}
+                    }
+                    #( #from_str_indexed )*
+                    ::core::result::Result::Err(::modular_bitfield::error::UnknownField)
+                }
+            }
+
+            impl ::core::fmt::Display for #field_ident {
+                fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+                    match *self {
+                        #( #display_arms, )*
+                    }
+                }
+            }
+
+            #[allow(clippy::identity_op)]
+            impl #impl_generics #ident #ty_generics #where_clause {
+                /// Converts the given raw bits into a value of the specifier `T`.
+                #[doc(hidden)]
+                #[inline]
+                fn __bf_raw_to_value<T>(
+                    raw: ::core::primitive::u128,
+                ) -> ::core::result::Result<<T as ::modular_bitfield::Specifier>::InOut, ::modular_bitfield::error::OutOfBounds>
+                where
+                    T: ::modular_bitfield::Specifier,
+                    <T as ::modular_bitfield::Specifier>::Bytes: ::modular_bitfield::private::SpecifierBytesOps,
+                {
+                    let __bf_bytes = ::modular_bitfield::private::bytes_from_u128::<T>(raw)
+                        .ok_or(::modular_bitfield::error::OutOfBounds)?;
+                    <T as ::modular_bitfield::Specifier>::from_bytes(__bf_bytes)
+                        .map_err(|_| ::modular_bitfield::error::OutOfBounds)
+                }
+
+                /// Returns the raw bits of the given field.
+                ///
+                /// Bits of fields wider than 128 bits are truncated.
+                ///
+                /// # Panics
+                ///
+                /// If the index of an array field element is out of bounds.
+                #[inline]
+                #[allow(dead_code)]
+                #vis fn get_raw(&self, field: #field_ident) -> ::core::primitive::u128 {
+                    match field {
+                        #( #get_arms )*
+                    }
+                }
+
+                /// Sets the given field to the given raw bits.
+                ///
+                /// # Errors
+                ///
+                /// If the raw bits are not a valid bit pattern of the field or if the field
+                /// has no setters.
+                ///
+                /// # Panics
+                ///
+                /// If the index of an array field element is out of bounds.
+                #[inline]
+                #[allow(dead_code)]
+                #vis fn set_raw(
+                    &mut self,
+                    field: #field_ident,
+                    raw: ::core::primitive::u128,
+                ) -> ::core::result::Result<(), ::modular_bitfield::error::OutOfBounds> {
+                    match field {
+                        #( #set_arms )*
+                        #set_fallback
+                    }
+                }
+            }
+        ))
+    }
+}
@@ -0,0 +1,139 @@
+use super::{
+    field_info::FieldInfo,
+    generics::FieldBounds,
+    BitfieldStruct,
+    Config,
+    Endian,
+};
+use proc_macro2::TokenStream as TokenStream2;
+use quote::{
+    quote,
+    quote_spanned,
+};
+use syn::spanned::Spanned as _;
+
+impl BitfieldStruct {
+    /// Returns `true` if any field of the `#[bitfield]` struct has a `#[reserved = N]` attribute.
+    pub fn has_reserved_fields(&self, config: &Config) -> bool {
+        self.field_infos(config)
+            .any(|info| info.config.reserved.is_some())
+    }
+
+    /// Generates the associated constants holding the mask and the mandated values of
+    /// all `#[reserved = N]` fields.
+    ///
+    /// Returns `None` if the `#[bitfield]` struct has no reserved fields.
+    pub fn generate_reserved_consts(&self, config: &Config) -> Option<TokenStream2> {
+        if !self.has_reserved_fields(config) {
+            return None
+        }
+        let span = self.item_struct.span();
+        let ident = &self.item_struct.ident;
+        let (impl_generics, ty_generics, _) = self.item_struct.generics.split_for_impl();
+        let where_clause = self.generate_where_clause(config, FieldBounds::Specifier);
+        let size = self.generate_target_or_actual_bitfield_size(config);
+        let next_divisible_by_8 = Self::next_divisible_by_8(&size);
+        let offsets = self.generate_field_offsets(config);
+        let (set_mask, set_bits): (Vec<_>, Vec<_>) = self
+            .field_infos(config)
+            .zip(offsets)
+            .filter_map(|(info, offset)| {
+                let value = info.config.reserved.as_ref()?.value;
+                let bits = FieldInfo::bits_of(info.field);
+                let endian = info
+                    .config
+                    .endian
+                    .as_ref()
+                    .map(|endian| endian.value)
+                    .unwrap_or(Endian::Native);
+                let set = |binding: TokenStream2, value: TokenStream2| {
+                    let set_le = quote_spanned!(span=>
+                        let #binding = ::modular_bitfield::private::set_bits_le(#binding, #offset, #bits, #value);
+                    );
+                    let set_be = quote_spanned!(span=>
+                        let #binding = ::modular_bitfield::private::set_bits_be(#binding, #offset, #bits, #value);
+                    );
+                    match endian {
+                        Endian::Little => set_le,
+                        Endian::Big => set_be,
+                        Endian::Native => {
+                            quote_spanned!(span=>
+                                #[cfg(target_endian = "little")]
+                                #set_le
+                                #[cfg(target_endian = "big")]
+                                #set_be
+                            )
+                        }
+                    }
+                };
+                Some((
+                    set(quote! { __bf_mask }, quote! { !0_u128 }),
+                    set(quote! { __bf_bits }, quote! { #value }),
+                ))
+            })
+            .unzip();
+        Some(quote_spanned!(span=>
+            #[allow(clippy::identity_op)]
+            impl #impl_generics #ident #ty_generics #where_clause {
+                /// The bits occupied by the `#[reserved = N]` fields.
+                #[doc(hidden)]
+                const __BF_RESERVED_MASK: [::core::primitive::u8; #next_divisible_by_8 / 8usize] = {
+                    let __bf_mask = [0u8; #next_divisible_by_8 / 8usize];
+                    #( #set_mask )*
+                    __bf_mask
+                };
+
+                /// The mandated values of the `#[reserved = N]` fields.
+                #[doc(hidden)]
+                const __BF_RESERVED_BITS: [::core::primitive::u8; #next_divisible_by_8 / 8usize] = {
+                    let __bf_bits = [0u8; #next_divisible_by_8 / 8usize];
+                    #( #set_bits )*
+                    __bf_bits
+                };
+            }
+        ))
+    }
+
+    /// Generates assertions that the mandated values of `#[reserved = N]` fields fit into their bit widths.
+    pub fn generate_reserved_checks(&self, config: &Config) -> Vec<TokenStream2> {
+        let ident = &self.item_struct.ident;
+        self.field_infos(config)
+            .filter_map(|info| {
+                let reserved = info.config.reserved.as_ref()?;
+                let value = reserved.value;
+                let bits = FieldInfo::bits_of(info.field);
+                let message = format!(
+                    "the #[reserved = {}] value of field {}.{} does not fit into its bit width",
+                    value,
+                    ident,
+                    info.name(),
+                );
+                Some(quote_spanned!(reserved.span=>
+                    ::core::assert!(#bits >= 128 || #value >> #bits == 0, #message);
+                ))
+            })
+            .collect()
+    }
+
+    /// Generates the expression evaluating to `true` if the reserved bits of `bytes`
+    /// differ from the values mandated by the `#[reserved = N]` fields.
+    ///
+    /// Returns `None` if the `#[bitfield]` struct has no reserved fields.
+    pub fn generate_reserved_mismatch(
+        &self,
+        config: &Config,
+        bytes: TokenStream2,
+    ) -> Option<TokenStream2> {
+        if !self.has_reserved_fields(config) {
+            return None
+        }
+        let span = self.item_struct.span();
+        Some(quote_spanned!(span=>
+            !::modular_bitfield::private::matches_masked(
+                &#bytes[..],
+                &Self::__BF_RESERVED_MASK[..],
+                &Self::__BF_RESERVED_BITS[..],
+            )
+        ))
+    }
+}
@@ -0,0 +1,309 @@
+use super::{
+    config::SerdeMode,
+    field_info::FieldInfo,
+    generics::FieldBounds,
+    BitfieldStruct,
+    Config,
+};
+use proc_macro2::TokenStream as TokenStream2;
+use quote::{
+    format_ident,
+    quote,
+    quote_spanned,
+};
+use syn::spanned::Spanned as _;
+
+impl BitfieldStruct {
+    /// Generates the `serde::Serialize` and `serde::Deserialize` impls of the `#[bitfield]` struct.
+    ///
+    /// Returns `None` if the `serde` parameter has not been set.
+    pub fn generate_serde_impls(&self, config: &Config) -> Option<TokenStream2> {
+        let impls = match config.serde.as_ref()?.value {
+            SerdeMode::Fields => self.generate_serde_fields_impls(config),
+            SerdeMode::Bytes => self.generate_serde_bytes_impls(config),
+        };
+        Some(impls)
+    }
+
+    /// Returns the fields that are (de)serialized with `serde = "fields"`.
+    ///
+    /// These are all fields that have both getters and setters.
+    fn serde_fields<'a>(&'a self, config: &'a Config) -> impl Iterator<Item = FieldInfo<'a>> {
+        self.field_infos(config)
+            .filter(|info| !info.config.skip_getters() && !info.config.skip_setters())
+    }
+
+    /// Generates the `serde` impls that represent the `#[bitfield]` struct as a map of
+    /// its field names to their values.
+    ///
+    /// Deserialization uses the checked setters so that out of bounds values yield errors.
+    fn generate_serde_fields_impls(&self, config: &Config) -> TokenStream2 {
+        let span = self.item_struct.span();
+        let ident = &self.item_struct.ident;
+        let generics = &self.item_struct.generics;
+        let (impl_generics, ty_generics, _) = generics.split_for_impl();
+        let serialize_where_clause = self.generate_where_clause(config, FieldBounds::Serialize);
+        let deserialize_where_clause = self.generate_where_clause(config, FieldBounds::Deserialize);
+        let mut de_generics = generics.clone();
+        de_generics.params.insert(0, syn::parse_quote!('de));
+        let (de_impl_generics, _, _) = de_generics.split_for_impl();
+        let params = generics.type_params().map(|param| &param.ident);
+        let names = self.serde_fields(config).map(|info| info.name()).collect::<Vec<_>>();
+        let count = names.len();
+        let expecting = format!("struct {}", ident);
+
+        let serialize_fields = self.serde_fields(config).map(|info| {
+            let span = info.field.span();
+            let name = info.name();
+            let get_ident = info
+                .field
+                .ident
+                .as_ref()
+                .cloned()
+                .unwrap_or_else(|| format_ident!("get_{}", info.ident_frag()));
+            let get_checked_ident = format_ident!("{}_or_err", get_ident);
+            let invalid_msg = format!(
+                "value contains invalid bit pattern for field {}.{}",
+                ident, name,
+            );
+            let invalid_err = quote_spanned!(span=>
+                |_| <__BfS::Error as ::modular_bitfield::private::serde::ser::Error>::custom(#invalid_msg)
+            );
+            match info.array_len() {
+                Some(len) => {
+                    let get_array_ident = format_ident!("{}_array", get_ident);
+                    quote_spanned!(span=>
+                        for __bf_index in 0..#len {
+                            self.#get_checked_ident(__bf_index).map_err(#invalid_err)?;
+                        }
+                        ::modular_bitfield::private::serde::ser::SerializeStruct::serialize_field(
+                            &mut __bf_state,
+                            #name,
+                            &::modular_bitfield::private::SerdeArray(self.#get_array_ident()),
+                        )?;
+                    )
+                }
+                None => {
+                    quote_spanned!(span=>
+                        ::modular_bitfield::private::serde::ser::SerializeStruct::serialize_field(
+                            &mut __bf_state,
+                            #name,
+                            &self.#get_checked_ident().map_err(#invalid_err)?,
+                        )?;
+                    )
+                }
+            }
+        });
+
+        let set_fields = self
+            .serde_fields(config)
+            .map(|info| {
+                let span = info.field.span();
+                let out_of_bounds_msg = format!(
+                    "value out of bounds for field {}.{}",
+                    ident,
+                    info.name(),
+                );
+                let out_of_bounds_err = quote_spanned!(span=>
+                    |_| <__BfA::Error as ::modular_bitfield::private::serde::de::Error>::custom(#out_of_bounds_msg)
+                );
+                match info.array_len() {
+                    Some(len) => {
+                        let set_array_checked_ident =
+                            format_ident!("set_{}_array_checked", info.ident_frag());
+                        quote_spanned!(span=>
+                            let __bf_value: ::modular_bitfield::private::SerdeArray<_, #len> = __bf_value;
+                            __bf_bitfield
+                                .#set_array_checked_ident(__bf_value.0)
+                                .map_err(#out_of_bounds_err)?;
+                        )
+                    }
+                    None => {
+                        let set_checked_ident = format_ident!("set_{}_checked", info.ident_frag());
+                        quote_spanned!(span=>
+                            __bf_bitfield
+                                .#set_checked_ident(__bf_value)
+                                .map_err(#out_of_bounds_err)?;
+                        )
+                    }
+                }
+            })
+            .collect::<Vec<_>>();
+        let indices = 0..count;
+        let positions = 0..count;
+
+        quote_spanned!(span=>
+            impl #impl_generics ::modular_bitfield::private::serde::Serialize for #ident #ty_generics #serialize_where_clause {
+                fn serialize<__BfS>(&self, serializer: __BfS) -> ::core::result::Result<__BfS::Ok, __BfS::Error>
+                where
+                    __BfS: ::modular_bitfield::private::serde::Serializer,
+                {
+                    let mut __bf_state = ::modular_bitfield::private::serde::Serializer::serialize_struct(
+                        serializer,
+                        ::core::stringify!(#ident),
+                        #count,
+                    )?;
+                    #( #serialize_fields )*
+                    ::modular_bitfield::private::serde::ser::SerializeStruct::end(__bf_state)
+                }
+            }
+
+            impl #de_impl_generics ::modular_bitfield::private::serde::Deserialize<'de> for #ident #ty_generics #deserialize_where_clause {
+                fn deserialize<__BfD>(deserializer: __BfD) -> ::core::result::Result<Self, __BfD::Error>
+                where
+                    __BfD: ::modular_bitfield::private::serde::Deserializer<'de>,
+                {
+                    const FIELDS: &[&::core::primitive::str] = &[#( #names ),*];
+
+                    struct __BfVisitor #generics {
+                        __bf_marker: ::core::marker::PhantomData<fn() -> (#( #params, )*)>,
+                    }
+
+                    impl #de_impl_generics ::modular_bitfield::private::serde::de::Visitor<'de> for __BfVisitor #ty_generics #deserialize_where_clause {
+                        type Value = #ident #ty_generics;
+
+                        fn expecting(&self, __bf_f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                            __bf_f.write_str(#expecting)
+                        }
+
+                        fn visit_map<__BfA>(self, mut __bf_map: __BfA) -> ::core::result::Result<Self::Value, __BfA::Error>
+                        where
+                            __BfA: ::modular_bitfield::private::serde::de::MapAccess<'de>,
+                        {
+                            let mut __bf_bitfield = <#ident #ty_generics>::new();
+                            let mut __bf_seen = [false; #count];
+                            while let ::core::option::Option::Some(__bf_index) =
+                                ::modular_bitfield::private::serde::de::MapAccess::next_key_seed(
+                                    &mut __bf_map,
+                                    ::modular_bitfield::private::FieldIndex(FIELDS),
+                                )?
+                            {
+                                if __bf_seen[__bf_index] {
+                                    return ::core::result::Result::Err(
+                                        <__BfA::Error as ::modular_bitfield::private::serde::de::Error>::duplicate_field(FIELDS[__bf_index])
+                                    )
+                                }
+                                __bf_seen[__bf_index] = true;
+                                match __bf_index {
+                                    #(
+                                        #indices => {
+                                            let __bf_value =
+                                                ::modular_bitfield::private::serde::de::MapAccess::next_value(&mut __bf_map)?;
+                                            #set_fields
+                                        }
+                                    )*
+                                    _ => ::core::unreachable!(),
+                                }
+                            }
+                            if let ::core::option::Option::Some(__bf_index) = __bf_seen.iter().position(|__bf_seen| !__bf_seen) {
+                                return ::core::result::Result::Err(
+                                    <__BfA::Error as ::modular_bitfield::private::serde::de::Error>::missing_field(FIELDS[__bf_index])
+                                )
+                            }
+                            ::core::result::Result::Ok(__bf_bitfield)
+                        }
+
+                        fn visit_seq<__BfA>(self, mut __bf_seq: __BfA) -> ::core::result::Result<Self::Value, __BfA::Error>
+                        where
+                            __BfA: ::modular_bitfield::private::serde::de::SeqAccess<'de>,
+                        {
+                            let mut __bf_bitfield = <#ident #ty_generics>::new();
+                            #(
+                                {
+                                    let __bf_value =
+                                        ::modular_bitfield::private::serde::de::SeqAccess::next_element(&mut __bf_seq)?
+                                            .ok_or_else(|| {
+                                                <__BfA::Error as ::modular_bitfield::private::serde::de::Error>::invalid_length(#positions, &self)
+                                            })?;
+                                    #set_fields
+                                }
+                            )*
+                            ::core::result::Result::Ok(__bf_bitfield)
+                        }
+                    }
+
+                    ::modular_bitfield::private::serde::Deserializer::deserialize_struct(
+                        deserializer,
+                        ::core::stringify!(#ident),
+                        FIELDS,
+                        __BfVisitor { __bf_marker: ::core::marker::PhantomData },
+                    )
+                }
+            }
+        )
+    }
+
+    /// Generates the `serde` impls that represent the `#[bitfield]` struct as the byte array
+    /// returned by its `into_bytes` method.
+    fn generate_serde_bytes_impls(&self, config: &Config) -> TokenStream2 {
+        let span = self.item_struct.span();
+        let ident = &self.item_struct.ident;
+        let (impl_generics, ty_generics, _) = self.item_struct.generics.split_for_impl();
+        let where_clause = self.generate_where_clause(config, FieldBounds::Specifier);
+        let mut de_generics = self.item_struct.generics.clone();
+        de_generics.params.insert(0, syn::parse_quote!('de));
+        let (de_impl_generics, _, _) = de_generics.split_for_impl();
+        let bit_order_conversion = self.generate_bit_order_conversion(config, &quote! { bytes });
+        let has_reserved = self
+            .field_infos(config)
+            .any(|info| info.config.reserved.is_some());
+        let from_bytes = match (config.filled_enabled(), has_reserved) {
+            (true, false) => {
+                quote_spanned!(span=>
+                    ::core::result::Result::Ok(Self::from_bytes(bytes))
+                )
+            }
+            (filled, has_reserved) => {
+                let from_bytes_ident = match filled {
+                    true => format_ident!("from_bytes_checked"),
+                    false => format_ident!("from_bytes"),
+                };
+                let invalid_msg = match (filled, has_reserved) {
+                    (true, _) => {
+                        format!("reserved bits differ from their mandated values for {}", ident)
+                    }
+                    (false, false) => format!("bits at undefined positions are set for {}", ident),
+                    (false, true) => {
+                        format!(
+                            "bits at undefined positions are set or reserved bits differ \
+                             from their mandated values for {}",
+                            ident,
+                        )
+                    }
+                };
+                quote_spanned!(span=>
+                    Self::#from_bytes_ident(bytes).map_err(|_| {
+                        <__BfD::Error as ::modular_bitfield::private::serde::de::Error>::custom(#invalid_msg)
+                    })
+                )
+            }
+        };
+        quote_spanned!(span=>
+            impl #impl_generics ::modular_bitfield::private::serde::Serialize for #ident #ty_generics #where_clause {
+                #[allow(clippy::identity_op)]
+                fn serialize<__BfS>(&self, serializer: __BfS) -> ::core::result::Result<__BfS::Ok, __BfS::Error>
+                where
+                    __BfS: ::modular_bitfield::private::serde::Serializer,
+                {
+                    let bytes = self.bytes;
+                    #bit_order_conversion
+                    ::modular_bitfield::private::serde::Serialize::serialize(
+                        &::modular_bitfield::private::SerdeBytes(bytes),
+                        serializer,
+                    )
+                }
+            }
+
+            impl #de_impl_generics ::modular_bitfield::private::serde::Deserialize<'de> for #ident #ty_generics #where_clause {
+                fn deserialize<__BfD>(deserializer: __BfD) -> ::core::result::Result<Self, __BfD::Error>
+                where
+                    __BfD: ::modular_bitfield::private::serde::Deserializer<'de>,
+                {
+                    let ::modular_bitfield::private::SerdeBytes(bytes) =
+                        ::modular_bitfield::private::serde::Deserialize::deserialize(deserializer)?;
+                    #from_bytes
+                }
+            }
+        )
+    }
+}
@@ -0,0 +1,190 @@
+use super::{
+    generics::FieldBounds,
+    BitfieldStruct,
+    Config,
+    Endian,
+};
+use proc_macro2::TokenStream as TokenStream2;
+use quote::{
+    quote,
+    quote_spanned,
+};
+use syn::spanned::Spanned as _;
+
+impl BitfieldStruct {
+    /// Generates the number of entries that are validated by the generated `validate` method.
+    ///
+    /// Array fields count as a single entry no matter their length.
+    fn generate_validated_entries(&self, config: &Config) -> TokenStream2 {
+        let entries = self.field_infos(config).count();
+        quote! { #entries }
+    }
+
+    /// Generates the `validate` and `from_bytes_validated` methods of the `#[bitfield]` struct.
+    ///
+    /// Validation checks the bits of every field against its `Specifier` and the bits of
+    /// every `#[reserved = N]` field against its mandated value.
+    pub fn generate_validate_impl(&self, config: &Config) -> TokenStream2 {
+        let span = self.item_struct.span();
+        let ident = &self.item_struct.ident;
+        let (impl_generics, ty_generics, _) = self.item_struct.generics.split_for_impl();
+        let where_clause = self.generate_where_clause(config, FieldBounds::Access);
+        let size = self.generate_target_or_actual_bitfield_size(config);
+        let next_divisible_by_8 = Self::next_divisible_by_8(&size);
+        let entries = self.generate_validated_entries(config);
+        let offsets = self.generate_field_offsets(config);
+        let field_checks = self.field_infos(config).zip(offsets).map(|(info, offset)| {
+            let span = info.field.span();
+            let name = info.name();
+            let ty = info.specifier_ty();
+            let endian = info
+                .config
+                .endian
+                .as_ref()
+                .map(|endian| endian.value)
+                .unwrap_or(Endian::Native);
+            // Evaluates to the invalid raw bits of the read field, if any.
+            let invalid_raw = match &info.config.reserved {
+                Some(reserved) => {
+                    let value = reserved.value;
+                    quote_spanned!(span=>
+                        match ::modular_bitfield::private::bytes_to_u128(__bf_read) {
+                            #value => ::core::option::Option::None,
+                            __bf_raw => ::core::option::Option::Some(__bf_raw),
+                        }
+                    )
+                }
+                None => {
+                    quote_spanned!(span=>
+                        match <#ty as ::modular_bitfield::Specifier>::from_bytes(__bf_read) {
+                            ::core::result::Result::Ok(_) => ::core::option::Option::None,
+                            ::core::result::Result::Err(__bf_err) => ::core::option::Option::Some(
+                                ::modular_bitfield::private::bytes_to_u128(__bf_err.invalid_bytes),
+                            ),
+                        }
+                    )
+                }
+            };
+            match info.array_len() {
+                Some(len) => {
+                    let bf_read = Self::expand_read_for_field(
+                        ty,
+                        &quote_spanned!(span=> __bf_offset),
+                        endian,
+                        span,
+                    );
+                    // Only the first invalid element is recorded next to the number of
+                    // invalid elements so that large arrays do not bloat the error.
+                    quote_spanned!(span=>
+                        {
+                            let mut __bf_first: ::core::option::Option<(::core::primitive::usize, ::core::primitive::u128)> =
+                                ::core::option::Option::None;
+                            let mut __bf_invalid: ::core::primitive::usize = 0;
+                            for __bf_index in 0..#len {
+                                let __bf_offset: ::core::primitive::usize =
+                                    #offset + __bf_index * <#ty as ::modular_bitfield::Specifier>::BITS;
+                                #bf_read
+                                if let ::core::option::Option::Some(__bf_raw) = #invalid_raw {
+                                    __bf_first.get_or_insert((__bf_index, __bf_raw));
+                                    __bf_invalid += 1;
+                                }
+                            }
+                            if let ::core::option::Option::Some((__bf_index, __bf_raw)) = __bf_first {
+                                __bf_errors.push(
+                                    #name,
+                                    ::core::option::Option::Some(__bf_index),
+                                    __bf_raw,
+                                    __bf_invalid,
+                                );
+                            }
+                        }
+                    )
+                }
+                None => {
+                    let bf_read = Self::expand_read_for_field(ty, &offset, endian, span);
+                    quote_spanned!(span=>
+                        {
+                            #bf_read
+                            if let ::core::option::Option::Some(__bf_raw) = #invalid_raw {
+                                __bf_errors.push(#name, ::core::option::Option::None, __bf_raw, 1);
+                            }
+                        }
+                    )
+                }
+            }
+        });
+        let from_bytes_validated = match config.filled_enabled() {
+            true => {
+                quote_spanned!(span=>
+                    /// Converts the given bytes into the bitfield struct and validates all of its fields.
+                    ///
+                    /// # Errors
+                    ///
+                    /// If any field contains an invalid bit pattern. The error lists all invalid fields.
+                    #[inline]
+                    pub fn from_bytes_validated(
+                        bytes: [::core::primitive::u8; #next_divisible_by_8 / 8usize]
+                    ) -> ::core::result::Result<Self, ::modular_bitfield::error::InvalidFields<#entries>> {
+                        let __bf_bitfield = Self::from_bytes(bytes);
+                        __bf_bitfield.validate()?;
+                        ::core::result::Result::Ok(__bf_bitfield)
+                    }
+                )
+            }
+            false => {
+                let bounds_check = self.generate_bounds_check(config);
+                let bit_order_conversion =
+                    self.generate_bit_order_conversion(config, &quote! { bytes });
+                let marker_init = self.generate_marker_init();
+                let generic_checks = self.generate_generic_checks_usage();
+                quote_spanned!(span=>
+                    /// Converts the given bytes into the bitfield struct and validates all of its fields.
+                    ///
+                    /// # Errors
+                    ///
+                    /// If any field contains an invalid bit pattern or if the given bytes contain bits
+                    /// at positions that are undefined for `Self`. The error lists all invalid fields.
+                    #[inline]
+                    pub fn from_bytes_validated(
+                        bytes: [::core::primitive::u8; #next_divisible_by_8 / 8usize]
+                    ) -> ::core::result::Result<Self, ::modular_bitfield::error::InvalidFields<#entries>> {
+                        #bit_order_conversion
+                        #bounds_check
+                        #generic_checks
+                        let __bf_bitfield = Self { bytes, #marker_init };
+                        let mut __bf_errors = __bf_bitfield.__bf_invalid_fields();
+                        if out_of_bounds {
+                            __bf_errors.set_undefined_bits();
+                        }
+                        __bf_errors.into_result()?;
+                        ::core::result::Result::Ok(__bf_bitfield)
+                    }
+                )
+            }
+        };
+        quote_spanned!(span=>
+            #[allow(clippy::identity_op, clippy::result_large_err)]
+            impl #impl_generics #ident #ty_generics #where_clause {
+                /// Returns all fields of the bitfield containing invalid bit patterns.
+                #[doc(hidden)]
+                fn __bf_invalid_fields(&self) -> ::modular_bitfield::error::InvalidFields<#entries> {
+                    let mut __bf_errors = ::modular_bitfield::error::InvalidFields::new();
+                    #( #field_checks )*
+                    __bf_errors
+                }
+
+                /// Validates the bit patterns of all fields of the bitfield.
+                ///
+                /// # Errors
+                ///
+                /// If any field contains an invalid bit pattern. The error lists all invalid fields.
+                #[inline]
+                pub fn validate(&self) -> ::core::result::Result<(), ::modular_bitfield::error::InvalidFields<#entries>> {
+                    self.__bf_invalid_fields().into_result()
+                }
+
+                #from_bytes_validated
+            }
+        )
+    }
+}
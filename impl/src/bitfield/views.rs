@@ -0,0 +1,143 @@
+use super::{
+    generics::FieldBounds,
+    BitfieldStruct,
+    Config,
+};
+use proc_macro2::TokenStream as TokenStream2;
+use quote::{
+    format_ident,
+    quote_spanned,
+    ToTokens as _,
+};
+use syn::spanned::Spanned as _;
+
+impl BitfieldStruct {
+    /// Generates the borrowed `FooRef<'a>` and `FooMut<'a>` views of the `#[bitfield]` struct
+    /// that read and write the fields of a `Foo` in place within a byte slice.
+    ///
+    /// Returns `None` if the `views` parameter has not been set.
+    pub fn generate_views(&self, config: &Config) -> Option<TokenStream2> {
+        config.views.as_ref()?;
+        let span = self.item_struct.span();
+        let ident = &self.item_struct.ident;
+        let vis = &self.item_struct.vis;
+        let ref_ident = format_ident!("{}Ref", ident);
+        let mut_ident = format_ident!("{}Mut", ident);
+        let (_, bitfield_ty_generics, _) = self.item_struct.generics.split_for_impl();
+        let mut generics = self.item_struct.generics.clone();
+        generics.params.insert(0, syn::parse_quote!('a));
+        let (impl_generics, ty_generics, struct_where_clause) = generics.split_for_impl();
+        let where_clause = self.generate_where_clause(config, FieldBounds::Access);
+        let marker = self.is_generic().then(|| {
+            let params = generics.type_params().map(|param| &param.ident);
+            quote_spanned!(span=>
+                __bf_marker: ::core::marker::PhantomData<fn() -> (#( #params, )*)>,
+            )
+        });
+        let marker_init = self.generate_marker_init();
+        let type_args = self.item_struct.generics.params.iter().map(|param| {
+            match param {
+                syn::GenericParam::Type(param) => param.ident.to_token_stream(),
+                syn::GenericParam::Const(param) => param.ident.to_token_stream(),
+                syn::GenericParam::Lifetime(param) => param.lifetime.to_token_stream(),
+            }
+        });
+        let generic_checks = self.is_generic().then(|| {
+            quote_spanned!(span=>
+                let () = <#ident #bitfield_ty_generics>::__BF_CHECKS;
+            )
+        });
+        let size = self.generate_target_or_actual_bitfield_size(config);
+        let base: syn::Expr = syn::parse_quote!(self.offset);
+        let getters = self.generate_getters_and_setters(config, Some(base.clone()), false);
+        let getters_and_setters = self.generate_getters_and_setters(config, Some(base), true);
+        let ref_docs = format!(
+            "A borrowed view of a [`{}`] within a byte slice.\n\n\
+             Reads the fields of the bitfield in place without copying its bytes.",
+            ident,
+        );
+        let mut_docs = format!(
+            "A mutable borrowed view of a [`{}`] within a byte slice.\n\n\
+             Reads and writes the fields of the bitfield in place without copying its bytes.",
+            ident,
+        );
+        let constructors = |bytes_ty: TokenStream2| {
+            quote_spanned!(span=>
+                /// Creates a view of the bitfield at the start of the given bytes.
+                ///
+                /// # Errors
+                ///
+                /// If the given bytes are too short to hold the bitfield.
+                #[inline]
+                pub fn new(bytes: #bytes_ty) -> ::core::result::Result<Self, ::modular_bitfield::error::OutOfBounds> {
+                    Self::new_at(bytes, 0)
+                }
+
+                /// Creates a view of the bitfield starting at the given bit offset into the given bytes.
+                ///
+                /// The bit offset follows the bit numbering of the bitfield.
+                ///
+                /// # Errors
+                ///
+                /// If the given bytes are too short to hold the bitfield at the bit offset.
+                #[inline]
+                #[allow(clippy::identity_op)]
+                pub fn new_at(
+                    bytes: #bytes_ty,
+                    offset: ::core::primitive::usize,
+                ) -> ::core::result::Result<Self, ::modular_bitfield::error::OutOfBounds> {
+                    #generic_checks
+                    match offset.checked_add(#size) {
+                        ::core::option::Option::Some(end) if end <= bytes.len().saturating_mul(8) => {
+                            ::core::result::Result::Ok(Self { bytes, offset, #marker_init })
+                        }
+                        _ => ::core::result::Result::Err(::modular_bitfield::error::OutOfBounds),
+                    }
+                }
+            )
+        };
+        let ref_constructors = constructors(quote_spanned!(span=> &'a [::core::primitive::u8]));
+        let mut_constructors = constructors(quote_spanned!(span=> &'a mut [::core::primitive::u8]));
+        Some(quote_spanned!(span=>
+            #[doc = #ref_docs]
+            #vis struct #ref_ident #impl_generics #struct_where_clause {
+                bytes: &'a [::core::primitive::u8],
+                offset: ::core::primitive::usize,
+                #marker
+            }
+
+            impl #impl_generics ::core::clone::Clone for #ref_ident #ty_generics #struct_where_clause {
+                #[inline]
+                fn clone(&self) -> Self {
+                    *self
+                }
+            }
+
+            impl #impl_generics ::core::marker::Copy for #ref_ident #ty_generics #struct_where_clause {}
+
+            impl #impl_generics #ref_ident #ty_generics #where_clause {
+                #ref_constructors
+                #( #getters )*
+            }
+
+            #[doc = #mut_docs]
+            #vis struct #mut_ident #impl_generics #struct_where_clause {
+                bytes: &'a mut [::core::primitive::u8],
+                offset: ::core::primitive::usize,
+                #marker
+            }
+
+            impl #impl_generics #mut_ident #ty_generics #where_clause {
+                #mut_constructors
+
+                /// Returns a shared view of the bitfield.
+                #[inline]
+                pub fn to_ref(&self) -> #ref_ident<'_, #( #type_args, )*> {
+                    #ref_ident { bytes: &*self.bytes, offset: self.offset, #marker_init }
+                }
+
+                #( #getters_and_setters )*
+            }
+        ))
+    }
+}
@@ -0,0 +1,33 @@
+use super::{
+    BitfieldStruct,
+    Config,
+};
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote_spanned;
+use syn::spanned::Spanned as _;
+
+impl BitfieldStruct {
+    /// Generates the compile-time checks that all fields of a `#[bitfield]` struct deriving
+    /// `zerocopy` traits accept all of their bit patterns.
+    ///
+    /// Returns `None` if the `#[bitfield]` struct does not derive any `zerocopy` trait.
+    pub fn generate_zerocopy_checks(&self, config: &Config) -> Option<TokenStream2> {
+        config.derive_zerocopy.as_ref()?;
+        let ident = &self.item_struct.ident;
+        let checks = self.field_infos(config).map(|info| {
+            let ty = info.specifier_ty();
+            let span = ty.span();
+            let index = info.index;
+            quote_spanned!(span=>
+                impl ::modular_bitfield::private::checks::CheckHasNoInvalidBitPatterns<[(); #index]> for #ident {
+                    type CheckType = [(); <#ty as ::modular_bitfield::Specifier>::ALL_BIT_PATTERNS_VALID as ::core::primitive::usize];
+                }
+            )
+        });
+        Some(quote_spanned!(self.item_struct.span()=>
+            const _: () = {
+                #( #checks )*
+            };
+        ))
+    }
+}
@@ -1,8 +1,12 @@
 use std::convert::TryInto;
 
-use crate::bitfield::Endian;
+use crate::{
+    bitfield::Endian,
+    errors::CombineError,
+};
 use proc_macro2::TokenStream as TokenStream2;
 use quote::{
+    format_ident,
     quote,
     quote_spanned,
 };
@@ -29,32 +33,238 @@ fn generate_or_error(input: TokenStream2) -> syn::Result<TokenStream2> {
                 variants: data_enum.variants,
             })
         }
-        syn::Data::Struct(_) => {
+        syn::Data::Struct(ref data_struct) => generate_newtype(&input, data_struct),
+        syn::Data::Union(_) => {
             Err(format_err!(
                 input,
-                "structs are not supported as bitfield specifiers",
+                "unions are not supported as bitfield specifiers",
             ))
         }
-        syn::Data::Union(_) => {
-            Err(format_err!(
+    }
+}
+
+/// Generates the `Specifier` impl for newtype tuple structs such as `struct Id(u16)`.
+///
+/// The newtype forwards to the `Specifier` impl of its field. If the field is its own
+/// in-out type the newtype is its own in-out type as well. Newtypes over specifiers with
+/// another in-out type, such as the uninhabited `B1`, .. `B128` and `S1`, .. `S128`
+/// specifiers, are named specifiers with the in-out type of their field instead.
+/// This is decided by the `NewtypeInOut` impls of the in-out type of the field.
+///
+/// A `#[bits = N]` attribute on the field narrows the bit width of the newtype.
+fn generate_newtype(
+    input: &syn::DeriveInput,
+    data_struct: &syn::DataStruct,
+) -> syn::Result<TokenStream2> {
+    let span = input.span();
+    let ident = &input.ident;
+    let field = match &data_struct.fields {
+        syn::Fields::Unnamed(fields) if fields.unnamed.len() == 1 => &fields.unnamed[0],
+        _ => {
+            return Err(format_err!(
                 input,
-                "unions are not supported as bitfield specifiers",
+                "only newtype tuple structs with exactly one field are supported as bitfield specifiers",
             ))
         }
+    };
+    let field_span = field.span();
+    let ty = &field.ty;
+    let field_bits = parse_attrs(&field.attrs)?.bits;
+    if field_bits == Some(0) {
+        return Err(format_err_spanned!(
+            field,
+            "encountered invalid #[bits = 0] for a newtype field",
+        ))
     }
+    let bits = match field_bits {
+        Some(bits) => quote_spanned!(field_span=> #bits),
+        None => quote_spanned!(field_span=> <#ty as ::modular_bitfield::Specifier>::BITS),
+    };
+    let check_bits = field_bits.map(|bits| {
+        quote_spanned!(field_span=>
+            const _: () = ::core::assert!(
+                #bits <= <#ty as ::modular_bitfield::Specifier>::BITS,
+                "the #[bits = N] of a newtype field must not exceed the bit width of its type",
+            );
+        )
+    });
+    let valid_bit_pattern = match field_bits {
+        Some(_) => {
+            quote_spanned!(field_span=>
+                ::modular_bitfield::private::narrow_valid_bit_pattern::<#ty>(
+                    raw,
+                    <Self as ::modular_bitfield::Specifier>::BITS,
+                )
+            )
+        }
+        None => quote_spanned!(field_span=> <#ty as ::modular_bitfield::Specifier>::valid_bit_pattern(raw)),
+    };
+    // Two's complement values of the field do not survive narrowing its bit width.
+    let default_value = match field_bits {
+        Some(_) => {
+            quote_spanned!(field_span=>
+                match <#ty as ::modular_bitfield::Specifier>::DEFAULT_VALUE {
+                    ::modular_bitfield::private::DefaultValue::Signed => {
+                        ::modular_bitfield::private::DefaultValue::BitPattern
+                    }
+                    __bf_default_value => __bf_default_value,
+                }
+            )
+        }
+        None => quote_spanned!(field_span=> <#ty as ::modular_bitfield::Specifier>::DEFAULT_VALUE),
+    };
+    let attributes = parse_attrs(&input.attrs)?;
+    let arbitrary_impls = generate_arbitrary_impls(ident, &attributes, span);
+    let newtype_in_out = quote_spanned!(span=>
+        <<#ty as ::modular_bitfield::Specifier>::InOut as ::modular_bitfield::private::NewtypeInOut<#ty, Self>>
+    );
+    Ok(quote_spanned!(span=>
+        #check_bits
+
+        impl ::modular_bitfield::private::Newtype<#ty> for #ident {
+            #[inline]
+            fn from_field(field: #ty) -> Self {
+                Self(field)
+            }
+
+            #[inline]
+            fn into_field(self) -> #ty {
+                self.0
+            }
+        }
+
+        impl ::modular_bitfield::Specifier for #ident {
+            const BITS: usize = #bits;
+            const STRUCT: bool = <#ty as ::modular_bitfield::Specifier>::STRUCT;
+            type Bytes = <#ty as ::modular_bitfield::Specifier>::Bytes;
+            type InOut = #newtype_in_out::InOut;
+            const ALL_BIT_PATTERNS_VALID: bool = <#ty as ::modular_bitfield::Specifier>::ALL_BIT_PATTERNS_VALID;
+            const DEFAULT_BIT_PATTERN: ::core::primitive::u128 = <#ty as ::modular_bitfield::Specifier>::DEFAULT_BIT_PATTERN;
+            const DEFAULT_BYTES: &'static [::core::primitive::u8] = <#ty as ::modular_bitfield::Specifier>::DEFAULT_BYTES;
+            const VARIANTS: &'static [(&'static ::core::primitive::str, ::core::primitive::u128)] = <#ty as ::modular_bitfield::Specifier>::VARIANTS;
+            const DEFAULT_VALUE: ::modular_bitfield::private::DefaultValue = #default_value;
+
+            #[inline]
+            fn into_bytes(input: Self::InOut) -> ::core::result::Result<Self::Bytes, ::modular_bitfield::error::OutOfBounds> {
+                let __bf_bytes = <#ty as ::modular_bitfield::Specifier>::into_bytes(#newtype_in_out::unwrap(input))?;
+                if !::modular_bitfield::private::SpecifierBytesOps::fits_in_bits(&__bf_bytes, <Self as ::modular_bitfield::Specifier>::BITS) {
+                    return ::core::result::Result::Err(::modular_bitfield::error::OutOfBounds)
+                }
+                ::core::result::Result::Ok(__bf_bytes)
+            }
+
+            #[inline]
+            fn from_bytes(bytes: Self::Bytes) -> ::core::result::Result<Self::InOut, ::modular_bitfield::error::InvalidBitPattern<Self::Bytes>> {
+                if !::modular_bitfield::private::SpecifierBytesOps::fits_in_bits(&bytes, <Self as ::modular_bitfield::Specifier>::BITS) {
+                    return ::core::result::Result::Err(::modular_bitfield::error::InvalidBitPattern::new(bytes))
+                }
+                let __bf_value = <#ty as ::modular_bitfield::Specifier>::from_bytes(bytes)?;
+                ::core::result::Result::Ok(#newtype_in_out::wrap(__bf_value))
+            }
+
+            #[inline]
+            fn valid_bit_pattern(raw: ::core::primitive::u128) -> ::core::option::Option<Self::Bytes> {
+                #valid_bit_pattern
+            }
+        }
+
+        #arbitrary_impls
+    ))
+}
+
+/// Generates the `arbitrary::Arbitrary` and `proptest::arbitrary::Arbitrary` impls
+/// requested by the `#[arbitrary]` and `#[proptest]` attributes.
+///
+/// Both generate values from the valid bit patterns of the `Specifier` impl so
+/// that every generated value round-trips through a `#[bitfield]` struct.
+fn generate_arbitrary_impls(
+    ident: &syn::Ident,
+    attributes: &Attributes,
+    span: proc_macro2::Span,
+) -> TokenStream2 {
+    let arbitrary = attributes.arbitrary.then(|| {
+        quote_spanned!(span=>
+            impl<'a> ::modular_bitfield::private::arbitrary::Arbitrary<'a> for #ident
+            where
+                Self: ::modular_bitfield::Specifier<InOut = Self>,
+            {
+                fn arbitrary(
+                    u: &mut ::modular_bitfield::private::arbitrary::Unstructured<'a>,
+                ) -> ::modular_bitfield::private::arbitrary::Result<Self> {
+                    ::modular_bitfield::private::arbitrary_specifier::<Self>(u)
+                }
+            }
+        )
+    });
+    let proptest = attributes.proptest.then(|| {
+        quote_spanned!(span=>
+            impl ::modular_bitfield::private::proptest::arbitrary::Arbitrary for #ident
+            where
+                Self: ::modular_bitfield::Specifier<InOut = Self>,
+            {
+                type Parameters = ();
+                type Strategy = ::modular_bitfield::private::proptest::strategy::BoxedStrategy<Self>;
+
+                fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+                    ::modular_bitfield::private::proptest_specifier::<Self>()
+                }
+            }
+        )
+    });
+    quote_spanned!(span=>
+        #arbitrary
+        #proptest
+    )
 }
+
 struct Attributes {
     bits: Option<usize>,
     endian: Option<Endian>,
+    arbitrary: bool,
+    proptest: bool,
 }
 
 fn parse_attrs(attrs: &[syn::Attribute]) -> syn::Result<Attributes> {
     let mut attributes = Attributes {
         bits: None,
         endian: None,
+        arbitrary: false,
+        proptest: false,
     };
 
     for attr in attrs {
+        for (name, enabled, flag) in [
+            ("arbitrary", cfg!(feature = "arbitrary"), &mut attributes.arbitrary),
+            ("proptest", cfg!(feature = "proptest"), &mut attributes.proptest),
+        ] {
+            if !attr.path.is_ident(name) {
+                continue
+            }
+            if !attr.tokens.is_empty() {
+                return Err(format_err_spanned!(
+                    attr,
+                    "encountered invalid #[{}] attribute, expected no arguments",
+                    name,
+                ))
+            }
+            if !enabled {
+                return Err(format_err_spanned!(
+                    attr,
+                    "the #[{}] attribute requires the `{}` crate feature",
+                    name,
+                    name,
+                ))
+            }
+            if *flag {
+                return Err(format_err_spanned!(
+                    attr,
+                    "More than one '{}' attributes is not permitted",
+                    name,
+                ))
+            }
+            *flag = true;
+        }
+
         if attr.path.is_ident("bits") {
             if attributes.bits.is_some() {
                 return Err(format_err_spanned!(
@@ -110,6 +320,18 @@ fn generate_enum(input: syn::ItemEnum) -> syn::Result<TokenStream2> {
     let attributes = parse_attrs(&input.attrs)?;
     let enum_ident = &input.ident;
 
+    let default = find_default_variant(&input)?;
+    if let Some(fallback) = find_fallback_variant(&input)? {
+        return generate_fallback_enum(&input, attributes, fallback)
+    }
+    if input
+        .variants
+        .iter()
+        .any(|variant| !matches!(variant.fields, syn::Fields::Unit))
+    {
+        return generate_data_enum(&input, attributes)
+    }
+
     let bits = match attributes.bits {
         Some(bits) => bits,
         None => {
@@ -152,21 +374,59 @@ fn generate_enum(input: syn::ItemEnum) -> syn::Result<TokenStream2> {
 
     let check_discriminants = variants.iter().map(|ident| {
         let span = ident.span();
+        // Every discriminant fits into 128 bits.
+        let in_range = if bits < 128 {
+            quote_spanned!(span=> (Self::#ident as ::core::primitive::u128) < (0x01_u128 << #bits))
+        } else {
+            quote_spanned!(span=> true)
+        };
         quote_spanned!(span =>
-            impl modular_bitfield::private::checks::CheckDiscriminantInRange<[(); Self::#ident as usize]> for #enum_ident {
-                type CheckType = [(); ((Self::#ident as usize) < (0x01_usize << #bits)) as usize ];
+            impl ::modular_bitfield::private::checks::CheckDiscriminantInRange<[(); Self::#ident as usize]> for #enum_ident {
+                type CheckType = [(); (#in_range) as usize ];
             }
         )
     });
     let from_bytes_arms = variants.iter().map(|ident| {
         let span = ident.span();
         quote_spanned!(span=>
-            __bitfield_binding if __bitfield_binding == Self::#ident as <Self as modular_bitfield::Specifier>::Bytes => {
+            __bitfield_binding if __bitfield_binding == Self::#ident as <Self as ::modular_bitfield::Specifier>::Bytes => {
                 ::core::result::Result::Ok(Self::#ident)
             }
         )
     });
 
+    // Enums that leave bit patterns unused may encode `None` of an `Option`
+    // with the smallest of these patterns.
+    let niche = (bits >= 64 || (variants.len() as u64) < (0x01_u64 << bits)).then(|| {
+        quote_spanned!(span=>
+            impl ::modular_bitfield::NicheSpecifier for #enum_ident {
+                const NICHE: <Self as ::modular_bitfield::Specifier>::Bytes = {
+                    let mut __bf_niche: <Self as ::modular_bitfield::Specifier>::Bytes = 0;
+                    while #( __bf_niche == Self::#variants as <Self as ::modular_bitfield::Specifier>::Bytes )||* {
+                        __bf_niche += 1;
+                    }
+                    __bf_niche
+                };
+            }
+        )
+    });
+
+    // Enums with a variant for every bit pattern accept all of them.
+    let all_bit_patterns_valid = bits < 128 && (variants.len() as u128) == (0x01_u128 << bits);
+
+    let default_bit_pattern = default.map(|variant| {
+        let ident = &variant.ident;
+        quote_spanned!(variant.span()=>
+            const DEFAULT_BIT_PATTERN: ::core::primitive::u128 = Self::#ident as ::core::primitive::u128;
+        )
+    });
+
+    let variant_entries = variants.iter().map(|ident| {
+        let span = ident.span();
+        let name = ident.to_string();
+        quote_spanned!(span=> (#name, Self::#ident as ::core::primitive::u128))
+    });
+
     let _endian_to = match endian {
         Endian::Big => quote! { (input as Self::Bytes).to_be() },
         Endian::Little => quote! { (input as Self::Bytes).to_le() },
@@ -179,17 +439,63 @@ fn generate_enum(input: syn::ItemEnum) -> syn::Result<TokenStream2> {
         _ => quote! { bytes },
     };
 
+    let arbitrary_impls = generate_arbitrary_impls(enum_ident, &attributes, span);
+
+    let from_bit_pattern_arms = variants.iter().map(|ident| {
+        let span = ident.span();
+        quote_spanned!(span=>
+            __bf_raw if __bf_raw == Self::#ident as ::core::primitive::u128 => {
+                ::core::option::Option::Some(Self::#ident)
+            }
+        )
+    });
+
     Ok(quote_spanned!(span=>
         #( #check_discriminants )*
+        #niche
+        #arbitrary_impls
+
+        impl #enum_ident {
+            /// Converts the raw bit pattern into the variant it represents if any.
+            ///
+            /// Used by the `const fn` accessors of `#[const_fn]` bitfield fields.
+            #[doc(hidden)]
+            #[inline]
+            #[allow(dead_code)]
+            pub const fn __bf_from_bit_pattern(
+                raw: ::core::primitive::u128,
+            ) -> ::core::option::Option<Self> {
+                match raw {
+                    #( #from_bit_pattern_arms )*
+                    _ => ::core::option::Option::None,
+                }
+            }
 
-        impl modular_bitfield::Specifier for #enum_ident {
+            /// Converts the variant into its raw bit pattern.
+            ///
+            /// Used by the `const fn` accessors of `#[const_fn]` bitfield fields.
+            #[doc(hidden)]
+            #[inline]
+            #[allow(dead_code)]
+            pub const fn __bf_into_bit_pattern(self) -> ::core::option::Option<::core::primitive::u128> {
+                ::core::option::Option::Some(self as ::core::primitive::u128)
+            }
+        }
+
+        impl ::modular_bitfield::Specifier for #enum_ident {
             const BITS: usize = #bits;
             const STRUCT: bool = false;
-            type Bytes = <[(); #bits] as modular_bitfield::private::SpecifierBytes>::Bytes;
+            type Bytes = <[(); #bits] as ::modular_bitfield::private::SpecifierBytes>::Bytes;
             type InOut = Self;
+            const ALL_BIT_PATTERNS_VALID: bool = #all_bit_patterns_valid;
+            #default_bit_pattern
+            const VARIANTS: &'static [(&'static ::core::primitive::str, ::core::primitive::u128)] = &[
+                #( #variant_entries ),*
+            ];
+            const DEFAULT_VALUE: ::modular_bitfield::private::DefaultValue = ::modular_bitfield::private::DefaultValue::Variant;
 
             #[inline]
-            fn into_bytes(input: Self::InOut) -> ::core::result::Result<Self::Bytes, modular_bitfield::error::OutOfBounds> {
+            fn into_bytes(input: Self::InOut) -> ::core::result::Result<Self::Bytes, ::modular_bitfield::error::OutOfBounds> {
                 ::core::result::Result::Ok(input as Self::Bytes)
 
                 // MSB: might not be needed to do conversion here
@@ -198,7 +504,7 @@ fn generate_enum(input: syn::ItemEnum) -> syn::Result<TokenStream2> {
             }
 
             #[inline]
-            fn from_bytes(bytes: Self::Bytes) -> ::core::result::Result<Self::InOut, modular_bitfield::error::InvalidBitPattern<Self::Bytes>> {
+            fn from_bytes(bytes: Self::Bytes) -> ::core::result::Result<Self::InOut, ::modular_bitfield::error::InvalidBitPattern<Self::Bytes>> {
                 // MSB: might not be needed to do conversion here
                 //let bytes = #endian_from;
 
@@ -206,11 +512,580 @@ fn generate_enum(input: syn::ItemEnum) -> syn::Result<TokenStream2> {
                     #( #from_bytes_arms ),*
                     invalid_bytes => {
                         ::core::result::Result::Err(
-                            <modular_bitfield::error::InvalidBitPattern<Self::Bytes>>::new(invalid_bytes)
+                            <::modular_bitfield::error::InvalidBitPattern<Self::Bytes>>::new(invalid_bytes)
                         )
                     }
                 }
             }
+
+            #[inline]
+            fn valid_bit_pattern(raw: ::core::primitive::u128) -> ::core::option::Option<Self::Bytes> {
+                const __BF_VARIANTS: &[<#enum_ident as ::modular_bitfield::Specifier>::Bytes] = &[
+                    #( #enum_ident::#variants as <#enum_ident as ::modular_bitfield::Specifier>::Bytes ),*
+                ];
+                let __bf_count = __BF_VARIANTS.len() as ::core::primitive::u128;
+                __BF_VARIANTS.get(raw.checked_rem(__bf_count)? as ::core::primitive::usize).copied()
+            }
+        }
+    ))
+}
+
+/// Returns the variant flagged with `#[default]` if any.
+///
+/// Its bit pattern is stored for fields of the enum by the constructors of `#[bitfield]` structs.
+///
+/// # Errors
+///
+/// - If more than one variant is flagged with `#[default]`.
+/// - If the default variant is not a unit variant.
+fn find_default_variant(input: &syn::ItemEnum) -> syn::Result<Option<&syn::Variant>> {
+    let mut default: Option<&syn::Variant> = None;
+    for variant in &input.variants {
+        for attr in &variant.attrs {
+            if !attr.path.is_ident("default") {
+                continue
+            }
+            if !attr.tokens.is_empty() {
+                return Err(format_err_spanned!(
+                    attr,
+                    "encountered invalid #[default] attribute, expected no arguments",
+                ))
+            }
+            if let Some(previous) = default {
+                return Err(format_err_spanned!(
+                    variant,
+                    "encountered duplicate #[default] variant",
+                )
+                .into_combine(format_err_spanned!(previous, "previous #[default] variant here")))
+            }
+            if !matches!(variant.fields, syn::Fields::Unit) {
+                return Err(format_err_spanned!(
+                    variant,
+                    "a #[default] variant must be a unit variant",
+                ))
+            }
+            default = Some(variant);
+        }
+    }
+    Ok(default)
+}
+
+/// Returns the variant flagged with `#[fallback]` if any.
+///
+/// # Errors
+///
+/// - If more than one variant is flagged with `#[fallback]`.
+/// - If the fallback variant does not have exactly one unnamed field.
+/// - If any other variant of an enum with a fallback variant carries data.
+fn find_fallback_variant(input: &syn::ItemEnum) -> syn::Result<Option<&syn::Variant>> {
+    let mut fallback: Option<&syn::Variant> = None;
+    for variant in &input.variants {
+        for attr in &variant.attrs {
+            if !attr.path.is_ident("fallback") {
+                continue
+            }
+            if !attr.tokens.is_empty() {
+                return Err(format_err_spanned!(
+                    attr,
+                    "encountered invalid #[fallback] attribute, expected no arguments",
+                ))
+            }
+            if let Some(previous) = fallback {
+                return Err(format_err_spanned!(
+                    variant,
+                    "encountered duplicate #[fallback] variant",
+                )
+                .into_combine(format_err_spanned!(previous, "previous found here")))
+            }
+            fallback = Some(variant);
         }
+    }
+    let fallback = match fallback {
+        Some(fallback) => fallback,
+        None => return Ok(None),
+    };
+    if !matches!(&fallback.fields, syn::Fields::Unnamed(fields) if fields.unnamed.len() == 1)
+    {
+        return Err(format_err_spanned!(
+            fallback,
+            "a #[fallback] variant must have exactly one unnamed field for the raw bits",
+        ))
+    }
+    if let Some(variant) = input.variants.iter().find(|variant| {
+        variant.ident != fallback.ident && !matches!(variant.fields, syn::Fields::Unit)
+    }) {
+        return Err(format_err_spanned!(
+            variant,
+            "enums with a #[fallback] variant must not have other data-carrying variants",
+        ))
+    }
+    Ok(Some(fallback))
+}
+
+/// Generates the `Specifier` impl for an enum with a `#[fallback]` variant.
+///
+/// All bit patterns that do not match the discriminant of any other variant are
+/// decoded into the fallback variant which carries the raw bits.
+fn generate_fallback_enum(
+    input: &syn::ItemEnum,
+    attributes: Attributes,
+    fallback: &syn::Variant,
+) -> syn::Result<TokenStream2> {
+    let span = input.span();
+    let enum_ident = &input.ident;
+    let fallback_ident = &fallback.ident;
+    let fallback_ty = match &fallback.fields {
+        syn::Fields::Unnamed(fields) => &fields.unnamed[0].ty,
+        _ => unreachable!("checked by `find_fallback_variant`"),
+    };
+    let bits = match attributes.bits {
+        Some(bits) => bits,
+        None => {
+            return Err(format_err!(
+                span,
+                "enums with a #[fallback] variant require a #[bits = N] attribute",
+            ))
+        }
+    };
+
+    // Enums with a data-carrying variant cannot be casted into their discriminant
+    // so we compute the discriminants of all variants the same way Rust does.
+    let mut discriminant = quote_spanned!(span=> 0);
+    let mut discriminants = Vec::new();
+    for variant in &input.variants {
+        if let Some((_, expr)) = &variant.discriminant {
+            discriminant = quote_spanned!(expr.span()=> (#expr));
+        }
+        if variant.ident != fallback.ident {
+            discriminants.push((&variant.ident, discriminant.clone()));
+        }
+        discriminant = quote_spanned!(variant.span()=> (#discriminant + 1));
+    }
+
+    let check_discriminants = discriminants.iter().map(|(ident, discriminant)| {
+        let span = ident.span();
+        quote_spanned!(span=>
+            impl ::modular_bitfield::private::checks::CheckDiscriminantInRange<[(); #discriminant as usize]> for #enum_ident {
+                type CheckType = [(); ((#discriminant as usize) < (0x01_usize << #bits)) as usize ];
+            }
+        )
+    });
+    // The raw bits of the fallback value converted through the unsigned integer of the
+    // same width so that signed fallback types such as `i8` do not sign extend.
+    let fallback_bits = quote_spanned!(fallback_ty.span()=>
+        (__bf_raw as ::core::primitive::u128
+            & (!0_u128 >> (128 - ::core::mem::size_of::<#fallback_ty>() * 8)))
+    );
+    // The fallback variant must not carry the discriminant of another variant since
+    // reading it back would yield the other variant.
+    let declared_discriminants = discriminants.iter().map(|(ident, discriminant)| {
+        let span = ident.span();
+        quote_spanned!(span=>
+            || #fallback_bits == #discriminant as ::core::primitive::u128
+        )
+    });
+    let is_invalid_fallback = quote_spanned!(fallback.span()=>
+        #fallback_bits > __bf_max_value #( #declared_discriminants )*
+    );
+    let check_fallback_width = quote_spanned!(fallback_ty.span()=>
+        const _: () = ::core::assert!(
+            ::core::mem::size_of::<#fallback_ty>() * 8 >= #bits,
+            "the type of the #[fallback] variant must be at least as wide as the #[bits = N] of the enum",
+        );
+    );
+    let into_bytes_arms = discriminants.iter().map(|(ident, discriminant)| {
+        let span = ident.span();
+        quote_spanned!(span=>
+            Self::#ident => #discriminant as Self::Bytes,
+        )
+    });
+    let from_bytes_arms = discriminants.iter().map(|(ident, discriminant)| {
+        let span = ident.span();
+        quote_spanned!(span=>
+            __bitfield_binding if __bitfield_binding == #discriminant as Self::Bytes => {
+                ::core::result::Result::Ok(Self::#ident)
+            }
+        )
+    });
+
+    let default_bit_pattern = find_default_variant(input)?.map(|variant| {
+        let discriminant = discriminants
+            .iter()
+            .find(|(ident, _)| *ident == &variant.ident)
+            .map(|(_, discriminant)| discriminant)
+            .expect("the #[default] variant is a unit variant");
+        quote_spanned!(variant.span()=>
+            const DEFAULT_BIT_PATTERN: ::core::primitive::u128 = #discriminant as ::core::primitive::u128;
+        )
+    });
+    let variant_entries = discriminants.iter().map(|(ident, discriminant)| {
+        let span = ident.span();
+        let name = ident.to_string();
+        quote_spanned!(span=> (#name, #discriminant as ::core::primitive::u128))
+    });
+    let arbitrary_impls = generate_arbitrary_impls(enum_ident, &attributes, span);
+
+    let into_bit_pattern_arms = discriminants.iter().map(|(ident, discriminant)| {
+        let span = ident.span();
+        quote_spanned!(span=>
+            Self::#ident => ::core::option::Option::Some(#discriminant as ::core::primitive::u128),
+        )
+    });
+    let from_bit_pattern_arms = discriminants.iter().map(|(ident, discriminant)| {
+        let span = ident.span();
+        quote_spanned!(span=>
+            __bf_raw if __bf_raw == #discriminant as ::core::primitive::u128 => {
+                ::core::option::Option::Some(Self::#ident)
+            }
+        )
+    });
+
+    Ok(quote_spanned!(span=>
+        #( #check_discriminants )*
+        #arbitrary_impls
+
+        #check_fallback_width
+
+        impl #enum_ident {
+            /// Converts the raw bit pattern into the variant it represents if any.
+            ///
+            /// Used by the `const fn` accessors of `#[const_fn]` bitfield fields.
+            #[doc(hidden)]
+            #[inline]
+            #[allow(dead_code)]
+            pub const fn __bf_from_bit_pattern(
+                raw: ::core::primitive::u128,
+            ) -> ::core::option::Option<Self> {
+                let __bf_max_value: ::core::primitive::u128 = !0_u128 >> (128 - #bits);
+                match raw {
+                    #( #from_bit_pattern_arms )*
+                    __bf_raw if __bf_raw > __bf_max_value => ::core::option::Option::None,
+                    __bf_raw => ::core::option::Option::Some(Self::#fallback_ident(__bf_raw as #fallback_ty)),
+                }
+            }
+
+            /// Converts the variant into its raw bit pattern.
+            ///
+            /// Returns `None` if the value of the fallback variant is out of bounds or equals
+            /// the discriminant of another variant.
+            /// Used by the `const fn` accessors of `#[const_fn]` bitfield fields.
+            #[doc(hidden)]
+            #[inline]
+            #[allow(dead_code)]
+            pub const fn __bf_into_bit_pattern(self) -> ::core::option::Option<::core::primitive::u128> {
+                let __bf_max_value: ::core::primitive::u128 = !0_u128 >> (128 - #bits);
+                match self {
+                    #( #into_bit_pattern_arms )*
+                    Self::#fallback_ident(__bf_raw) => {
+                        if #is_invalid_fallback {
+                            return ::core::option::Option::None
+                        }
+                        ::core::option::Option::Some(#fallback_bits)
+                    }
+                }
+            }
+        }
+
+        impl ::modular_bitfield::Specifier for #enum_ident {
+            const BITS: usize = #bits;
+            const STRUCT: bool = false;
+            type Bytes = <[(); #bits] as ::modular_bitfield::private::SpecifierBytes>::Bytes;
+            type InOut = Self;
+            const ALL_BIT_PATTERNS_VALID: bool = true;
+            #default_bit_pattern
+            const VARIANTS: &'static [(&'static ::core::primitive::str, ::core::primitive::u128)] = &[
+                #( #variant_entries ),*
+            ];
+
+            #[inline]
+            fn into_bytes(input: Self::InOut) -> ::core::result::Result<Self::Bytes, ::modular_bitfield::error::OutOfBounds> {
+                let __bf_max_value: ::core::primitive::u128 = !0_u128 >> (128 - #bits);
+                ::core::result::Result::Ok(match input {
+                    #( #into_bytes_arms )*
+                    Self::#fallback_ident(__bf_raw) => {
+                        if #is_invalid_fallback {
+                            return ::core::result::Result::Err(::modular_bitfield::error::OutOfBounds)
+                        }
+                        #fallback_bits as Self::Bytes
+                    }
+                })
+            }
+
+            #[inline]
+            fn from_bytes(bytes: Self::Bytes) -> ::core::result::Result<Self::InOut, ::modular_bitfield::error::InvalidBitPattern<Self::Bytes>> {
+                let __bf_max_value: ::core::primitive::u128 = !0_u128 >> (128 - #bits);
+                match bytes {
+                    #( #from_bytes_arms )*
+                    __bitfield_binding if __bitfield_binding as ::core::primitive::u128 > __bf_max_value => {
+                        ::core::result::Result::Err(
+                            <::modular_bitfield::error::InvalidBitPattern<Self::Bytes>>::new(__bitfield_binding)
+                        )
+                    }
+                    __bitfield_binding => {
+                        ::core::result::Result::Ok(Self::#fallback_ident(__bitfield_binding as #fallback_ty))
+                    }
+                }
+            }
+
+            #[inline]
+            fn valid_bit_pattern(raw: ::core::primitive::u128) -> ::core::option::Option<Self::Bytes> {
+                let __bf_max_value: ::core::primitive::u128 = !0_u128 >> (128 - #bits);
+                ::core::option::Option::Some((raw & __bf_max_value) as Self::Bytes)
+            }
+        }
+    ))
+}
+
+/// Generates the `Specifier` impl for an enum with at least one data-carrying variant.
+///
+/// The variant index is stored as tag in the least significant bits followed by the
+/// fields of the variant's payload in declaration order. The tag uses just as many
+/// bits as required to represent all variants and the payload uses as many bits as
+/// the biggest payload of all variants.
+fn generate_data_enum(
+    input: &syn::ItemEnum,
+    attributes: Attributes,
+) -> syn::Result<TokenStream2> {
+    let span = input.span();
+    let enum_ident = &input.ident;
+
+    if let Some(variant) = input
+        .variants
+        .iter()
+        .find(|variant| variant.discriminant.is_some())
+    {
+        return Err(format_err_spanned!(
+            variant,
+            "explicit discriminants are not supported for enums with data-carrying variants",
+        ))
+    }
+    let count_variants = input.variants.len();
+    let tag_bits = match count_variants.checked_next_power_of_two() {
+        Some(power_of_two) => power_of_two.trailing_zeros() as usize,
+        None => {
+            return Err(format_err!(
+                span,
+                "BitfieldSpecifier has too many variants to pack into a bitfield",
+            ))
+        }
+    };
+    let tag_mask = (0x01_u128 << tag_bits) - 1;
+
+    let mut payload_bits = Vec::with_capacity(count_variants);
+    let mut into_bytes_arms = Vec::with_capacity(count_variants);
+    let mut from_bytes_arms = Vec::with_capacity(count_variants);
+    let mut valid_bit_pattern_arms = Vec::with_capacity(count_variants);
+    for (tag, variant) in input.variants.iter().enumerate() {
+        let tag = tag as u128;
+        let variant_ident = &variant.ident;
+        let mut widths = Vec::new();
+        let mut bindings = Vec::new();
+        let mut push_fields = Vec::new();
+        let mut pop_fields = Vec::new();
+        let mut valid_fields = Vec::new();
+        for (n, field) in variant.fields.iter().enumerate() {
+            let field_span = field.span();
+            let ty = &field.ty;
+            let binding = format_ident!("__bf_field_{}", n);
+            let field_bits = parse_attrs(&field.attrs)?.bits;
+            if field_bits == Some(0) {
+                return Err(format_err_spanned!(
+                    field,
+                    "encountered invalid #[bits = 0] for a payload field",
+                ))
+            }
+            let width = match field_bits {
+                Some(bits) => quote_spanned!(field_span=> #bits),
+                None => {
+                    quote_spanned!(field_span=> <#ty as ::modular_bitfield::Specifier>::BITS)
+                }
+            };
+            // Fields narrowed by `#[bits = N]` have to be checked for overflowing bits
+            // whereas the `Specifier` impl of all other fields already guarantees it.
+            let narrow = match field_bits {
+                Some(_) => {
+                    quote_spanned!(field_span=>
+                        if __bf_raw & !__bf_mask != 0 {
+                            return ::core::result::Result::Err(::modular_bitfield::error::OutOfBounds)
+                        }
+                    )
+                }
+                None => quote_spanned!(field_span=> let __bf_raw = __bf_raw & __bf_mask;),
+            };
+            push_fields.push(quote_spanned!(field_span=>
+                let __bf_width: ::core::primitive::usize = #width;
+                let __bf_mask: ::core::primitive::u128 = !0_u128 >> (128 - __bf_width);
+                let __bf_raw = <#ty as ::modular_bitfield::Specifier>::into_bytes(#binding)? as ::core::primitive::u128;
+                #narrow
+                __bf_bits |= __bf_raw << __bf_offset;
+                __bf_offset += __bf_width;
+            ));
+            pop_fields.push(quote_spanned!(field_span=>
+                let #binding = {
+                    let __bf_width: ::core::primitive::usize = #width;
+                    let __bf_mask: ::core::primitive::u128 = !0_u128 >> (128 - __bf_width);
+                    let __bf_raw = ((__bf_bits >> __bf_offset) & __bf_mask)
+                        as <#ty as ::modular_bitfield::Specifier>::Bytes;
+                    __bf_offset += __bf_width;
+                    <#ty as ::modular_bitfield::Specifier>::from_bytes(__bf_raw)
+                        .map_err(|_| ::modular_bitfield::error::InvalidBitPattern::new(bytes))?
+                };
+            ));
+            let valid_bit_pattern = match field_bits {
+                Some(_) => {
+                    quote_spanned!(field_span=>
+                        ::modular_bitfield::private::narrow_valid_bit_pattern::<#ty>(__bf_raw, __bf_width)?
+                    )
+                }
+                None => {
+                    quote_spanned!(field_span=>
+                        <#ty as ::modular_bitfield::Specifier>::valid_bit_pattern(__bf_raw)?
+                    )
+                }
+            };
+            valid_fields.push(quote_spanned!(field_span=>
+                let __bf_width: ::core::primitive::usize = #width;
+                __bf_bits |= (#valid_bit_pattern as ::core::primitive::u128) << __bf_offset;
+                __bf_offset += __bf_width;
+                __bf_raw = __bf_raw.checked_shr(__bf_width as ::core::primitive::u32).unwrap_or(0);
+            ));
+            widths.push(width);
+            bindings.push(binding);
+        }
+        let pattern = match &variant.fields {
+            syn::Fields::Named(fields) => {
+                let names = fields.named.iter().map(|field| &field.ident);
+                quote! { Self::#variant_ident { #( #names: #bindings ),* } }
+            }
+            syn::Fields::Unnamed(_) => {
+                quote! { Self::#variant_ident ( #( #bindings ),* ) }
+            }
+            syn::Fields::Unit => quote! { Self::#variant_ident },
+        };
+        payload_bits.push(quote_spanned!(variant.span()=>
+            0usize #( + #widths )*
+        ));
+        into_bytes_arms.push(quote_spanned!(variant.span()=>
+            #pattern => {
+                let mut __bf_bits: ::core::primitive::u128 = #tag;
+                let mut __bf_offset: ::core::primitive::usize = #tag_bits;
+                #( #push_fields )*
+                let _ = __bf_offset;
+                __bf_bits
+            }
+        ));
+        from_bytes_arms.push(quote_spanned!(variant.span()=>
+            #tag => {
+                let mut __bf_offset: ::core::primitive::usize = #tag_bits;
+                #( #pop_fields )*
+                // Bits not covered by the payload of the variant must be zero.
+                if __bf_bits.checked_shr(__bf_offset as ::core::primitive::u32).unwrap_or(0) != 0 {
+                    return ::core::result::Result::Err(::modular_bitfield::error::InvalidBitPattern::new(bytes))
+                }
+                ::core::result::Result::Ok(#pattern)
+            }
+        ));
+        valid_bit_pattern_arms.push(quote_spanned!(variant.span()=>
+            #tag => {
+                let mut __bf_bits: ::core::primitive::u128 = #tag;
+                let mut __bf_offset: ::core::primitive::usize = #tag_bits;
+                #( #valid_fields )*
+                let _ = (__bf_offset, __bf_raw);
+                __bf_bits
+            }
+        ));
+    }
+    let count_variants = count_variants as u128;
+    let default_bit_pattern = find_default_variant(input)?.map(|variant| {
+        let tag = input
+            .variants
+            .iter()
+            .position(|candidate| candidate.ident == variant.ident)
+            .expect("the #[default] variant is a variant of the enum") as u128;
+        quote_spanned!(variant.span()=>
+            const DEFAULT_BIT_PATTERN: ::core::primitive::u128 = #tag;
+        )
+    });
+
+    // Data-carrying variants are described by their tag.
+    let variant_entries = input.variants.iter().enumerate().map(|(tag, variant)| {
+        let name = variant.ident.to_string();
+        let tag = tag as u128;
+        quote_spanned!(variant.span()=> (#name, #tag))
+    });
+
+    let required_bits = quote_spanned!(span=> {
+        let mut __bf_max_payload_bits = 0usize;
+        #(
+            let __bf_payload_bits = #payload_bits;
+            if __bf_payload_bits > __bf_max_payload_bits {
+                __bf_max_payload_bits = __bf_payload_bits;
+            }
+        )*
+        #tag_bits + __bf_max_payload_bits
+    });
+    let bits = match attributes.bits {
+        Some(bits) => quote_spanned!(span=> #bits),
+        None => required_bits.clone(),
+    };
+    let arbitrary_impls = generate_arbitrary_impls(enum_ident, &attributes, span);
+
+    Ok(quote_spanned!(span=>
+        #[allow(clippy::identity_op)]
+        const _: () = {
+            impl ::modular_bitfield::private::checks::CheckSpecifierHasAtMost128Bits for #enum_ident {
+                type CheckType = [(); (#bits <= 128) as ::core::primitive::usize];
+            }
+
+            impl ::modular_bitfield::private::checks::CheckVariantsFitIntoBits for #enum_ident {
+                type CheckType = [(); (#required_bits <= #bits) as ::core::primitive::usize];
+            }
+        };
+
+        #[allow(clippy::identity_op)]
+        impl ::modular_bitfield::Specifier for #enum_ident {
+            const BITS: usize = #bits;
+            const STRUCT: bool = false;
+
+            #[allow(unused_braces)]
+            type Bytes = <[(); if #bits > 128 { 128 } else { #bits }] as ::modular_bitfield::private::SpecifierBytes>::Bytes;
+            type InOut = Self;
+            #default_bit_pattern
+            const VARIANTS: &'static [(&'static ::core::primitive::str, ::core::primitive::u128)] = &[
+                #( #variant_entries ),*
+            ];
+
+            #[inline]
+            #[allow(unused_mut)]
+            fn into_bytes(input: Self::InOut) -> ::core::result::Result<Self::Bytes, ::modular_bitfield::error::OutOfBounds> {
+                let __bf_bits: ::core::primitive::u128 = match input {
+                    #( #into_bytes_arms )*
+                };
+                ::core::result::Result::Ok(__bf_bits as Self::Bytes)
+            }
+
+            #[inline]
+            #[allow(unused_mut)]
+            fn from_bytes(bytes: Self::Bytes) -> ::core::result::Result<Self::InOut, ::modular_bitfield::error::InvalidBitPattern<Self::Bytes>> {
+                let __bf_bits = bytes as ::core::primitive::u128;
+                match __bf_bits & #tag_mask {
+                    #( #from_bytes_arms )*
+                    _ => ::core::result::Result::Err(::modular_bitfield::error::InvalidBitPattern::new(bytes)),
+                }
+            }
+
+            #[inline]
+            #[allow(unused_mut, unused_assignments)]
+            fn valid_bit_pattern(raw: ::core::primitive::u128) -> ::core::option::Option<Self::Bytes> {
+                let mut __bf_raw = raw / #count_variants;
+                let __bf_bits: ::core::primitive::u128 = match raw % #count_variants {
+                    #( #valid_bit_pattern_arms )*
+                    _ => return ::core::option::Option::None,
+                };
+                ::core::option::Option::Some(__bf_bits as Self::Bytes)
+            }
+        }
+
+        #arbitrary_impls
     ))
 }
@@ -6,8 +6,10 @@ use quote::{
 
 pub fn generate(_input: TokenStream2) -> TokenStream2 {
     let specifiers = (1usize..=128).map(generate_specifier_for);
+    let signed_specifiers = (1usize..=128).map(generate_signed_specifier_for);
     quote! {
         #( #specifiers )*
+        #( #signed_specifiers )*
     }
 }
 
@@ -38,11 +40,36 @@ fn generate_specifier_for(bits: usize) -> TokenStream2 {
         #[derive(Copy, Clone)]
         pub enum #ident {}
 
+        impl #ident {
+            /// Converts the raw bit pattern into the value it represents.
+            ///
+            /// Used by the `const fn` accessors of `#[const_fn]` bitfield fields.
+            #[doc(hidden)]
+            #[inline]
+            pub const fn __bf_from_bit_pattern(raw: u128) -> Option<#in_out> {
+                if raw > #max_value as u128 {
+                    return None
+                }
+                Some(raw as #in_out)
+            }
+
+            /// Converts the value into its raw bit pattern.
+            ///
+            /// Returns `None` if the value is out of bounds.
+            /// Used by the `const fn` accessors of `#[const_fn]` bitfield fields.
+            #[doc(hidden)]
+            #[inline]
+            pub const fn __bf_into_bit_pattern(value: #in_out) -> Option<u128> {
+                crate::private::unsigned_bit_pattern(value as u128, #bits)
+            }
+        }
+
         impl crate::Specifier for #ident {
             const BITS: usize = #bits;
             const STRUCT: bool = false;
             type Bytes = #in_out;
             type InOut = #in_out;
+            const ALL_BIT_PATTERNS_VALID: bool = true;
 
             #[inline]
             fn into_bytes(input: Self::InOut) -> Result<Self::Bytes, crate::OutOfBounds> {
@@ -59,6 +86,25 @@ fn generate_specifier_for(bits: usize) -> TokenStream2 {
                 }
                 Ok(bytes)
             }
+
+            #[inline]
+            fn valid_bit_pattern(raw: u128) -> Option<Self::Bytes> {
+                Some((raw as #in_out) & #max_value)
+            }
+        }
+
+        impl<N> crate::private::NewtypeInOut<#ident, N> for #in_out {
+            type InOut = #in_out;
+
+            #[inline]
+            fn wrap(value: Self) -> Self::InOut {
+                value
+            }
+
+            #[inline]
+            fn unwrap(value: Self::InOut) -> Self {
+                value
+            }
         }
 
         impl crate::private::SpecifierBytes for [(); #bits] {
@@ -68,3 +114,108 @@ fn generate_specifier_for(bits: usize) -> TokenStream2 {
         impl crate::private::checks::private::Sealed for [(); #bits] {}
     }
 }
+
+fn generate_signed_specifier_for(bits: usize) -> TokenStream2 {
+    let (bytes, in_out, base_bits) = match bits {
+        1..=8 => (quote! { ::core::primitive::u8 }, quote! { ::core::primitive::i8 }, 8),
+        9..=16 => (quote! { ::core::primitive::u16 }, quote! { ::core::primitive::i16 }, 16),
+        17..=32 => (quote! { ::core::primitive::u32 }, quote! { ::core::primitive::i32 }, 32),
+        33..=64 => (quote! { ::core::primitive::u64 }, quote! { ::core::primitive::i64 }, 64),
+        65..=128 => (quote! { ::core::primitive::u128 }, quote! { ::core::primitive::i128 }, 128),
+        _ => unreachable!(),
+    };
+    let ident = format_ident!("S{}", bits);
+    let doc_comment = if bits == 1 {
+        "Specifier for a single bit two's complement signed integer.".to_string()
+    } else {
+        format!("Specifier for {} bits two's complement signed integers.", bits)
+    };
+    let (min_value, max_value, mask) = if bits == base_bits {
+        (
+            quote! {{ <#in_out>::MIN }},
+            quote! {{ <#in_out>::MAX }},
+            quote! {{ <#bytes>::MAX }},
+        )
+    } else {
+        (
+            quote! {{ -((0x01 as #in_out) << (#bits - 1)) }},
+            quote! {{ ((0x01 as #in_out) << (#bits - 1)) - 1 }},
+            quote! {{ ((0x01 as #bytes) << #bits) - 1 }},
+        )
+    };
+    // Amount of bits to shift left and right again in order to sign extend.
+    let sign_shift = base_bits - bits;
+    quote! {
+        #[doc = #doc_comment]
+        #[derive(Copy, Clone)]
+        pub enum #ident {}
+
+        impl #ident {
+            /// Converts the raw bit pattern into the value it represents.
+            ///
+            /// Used by the `const fn` accessors of `#[const_fn]` bitfield fields.
+            #[doc(hidden)]
+            #[inline]
+            pub const fn __bf_from_bit_pattern(raw: u128) -> Option<#in_out> {
+                if raw > #mask as u128 {
+                    return None
+                }
+                Some(crate::private::sign_extend(raw, #bits) as #in_out)
+            }
+
+            /// Converts the value into its raw bit pattern.
+            ///
+            /// Returns `None` if the value is out of bounds.
+            /// Used by the `const fn` accessors of `#[const_fn]` bitfield fields.
+            #[doc(hidden)]
+            #[inline]
+            pub const fn __bf_into_bit_pattern(value: #in_out) -> Option<u128> {
+                crate::private::signed_bit_pattern(value as i128, #bits)
+            }
+        }
+
+        impl crate::Specifier for #ident {
+            const BITS: usize = #bits;
+            const STRUCT: bool = false;
+            type Bytes = #bytes;
+            type InOut = #in_out;
+            const ALL_BIT_PATTERNS_VALID: bool = true;
+            const DEFAULT_VALUE: crate::private::DefaultValue = crate::private::DefaultValue::Signed;
+
+            #[inline]
+            fn into_bytes(input: Self::InOut) -> Result<Self::Bytes, crate::OutOfBounds> {
+                if !(#min_value..=#max_value).contains(&input) {
+                    return Err(crate::OutOfBounds)
+                }
+                Ok((input as #bytes) & #mask)
+            }
+
+            #[inline]
+            fn from_bytes(bytes: Self::Bytes) -> Result<Self::InOut, crate::InvalidBitPattern<Self::Bytes>> {
+                if bytes > #mask {
+                    return Err(crate::InvalidBitPattern { invalid_bytes: bytes })
+                }
+                Ok(((bytes << #sign_shift) as #in_out) >> #sign_shift)
+            }
+
+            #[inline]
+            fn valid_bit_pattern(raw: u128) -> Option<Self::Bytes> {
+                Some((raw as #bytes) & #mask)
+            }
+        }
+
+        impl<N> crate::private::NewtypeInOut<#ident, N> for #in_out {
+            type InOut = #in_out;
+
+            #[inline]
+            fn wrap(value: Self) -> Self::InOut {
+                value
+            }
+
+            #[inline]
+            fn unwrap(value: Self::InOut) -> Self {
+                value
+            }
+        }
+    }
+}
@@ -11,7 +11,7 @@ mod define_specifiers;
 
 use proc_macro::TokenStream;
 
-/// Generates the `B1`, `B2`, ..., `B128` bitfield specifiers.
+/// Generates the `B1`, `B2`, ..., `B128` and `S1`, `S2`, ..., `S128` bitfield specifiers.
 ///
 /// Only of use witihn the `modular_bitfield` crate itself.
 #[proc_macro]
@@ -27,7 +27,8 @@ pub fn define_specifiers(input: TokenStream) -> TokenStream {
 ///
 /// - **Constructors:**
 ///
-///     1. `new()`: Initializes all bits to 0 even if 0 bits may be invalid.
+///     1. `new()`: Initializes all fields to their `#[default = N]` or the default of their type,
+///        which is 0 unless an enum has a `#[default]` variant, even if 0 bits may be invalid.
 ///        Note that invalid bit patterns are supported in that getters and setters will
 ///        be protecting accesses.
 ///
@@ -53,11 +54,18 @@ pub fn define_specifiers(input: TokenStream) -> TokenStream {
 ///     4. `with_f_checked(new_value)`: Similar to `set_f_checked` but consumes and returns `Self`.
 ///        Primarily useful for method chaining.
 ///
+///     Getters and setters of array fields additionally take the index of the element.
+///
 /// - **Conversions:**
 ///
 ///     - `from_bytes(bytes)`: Allows to constructor the bitfield type from a fixed array of bytes.
 ///     - `into_bytes()`: Allows to convert the bitfield into its underlying byte representation.
 ///
+/// - **Metadata:**
+///
+///     - `FIELDS`: Describes the name, bit offset and bit width of every field.
+///     - `layout_json()`: Returns the layout of the bitfield which is displayed as a JSON object.
+///
 /// # Parameters
 ///
 /// The following parameters for the `#[bitfield]` macro are supported:
@@ -132,6 +140,222 @@ pub fn define_specifiers(input: TokenStream) -> TokenStream {
 /// }
 /// ```
 ///
+/// ## Parameter: `bit_order = "lsb0" | "msb0"`
+///
+/// Controls the numbering of the bits that the fields are allocated from. With `"lsb0"`
+/// the first field starts at the least significant bit of the first byte whereas with
+/// `"msb0"` it starts at the most significant bit of the first byte, as done by network
+/// RFCs and CAN "Motorola" signals.
+///
+/// The `endian` parameter independently controls whether the value of a field is stored
+/// starting with its least (`"little"`) or most (`"big"`) significant bit. Without it
+/// `"msb0"` implies `endian = "big"` and `"lsb0"` implies `endian = "little"`.
+///
+/// ### Example
+///
+/// ```
+/// # use modular_bitfield::prelude::*;
+/// #[bitfield(bit_order = "msb0")]
+/// pub struct Ipv4Start {
+///     version: B4,
+///     ihl: B4,
+///     dscp: B6,
+///     ecn: B2,
+///     total_length: u16,
+/// }
+///
+/// let header = Ipv4Start::new().with_version(4).with_ihl(5).with_total_length(20);
+/// assert_eq!(header.into_bytes(), [0x45, 0x00, 0x00, 0x14]);
+/// ```
+///
+/// ## Parameter: `views`
+///
+/// Additionally generates the borrowed views `FooRef<'a>` and `FooMut<'a>` for a `#[bitfield]`
+/// struct `Foo`. They wrap a `&[u8]` or `&mut [u8]` and provide the same getters and setters
+/// as `Foo`, reading and writing the fields in place instead of copying the bytes first.
+///
+/// Views are created with `new(bytes)` or with `new_at(bytes, offset)` starting at the given
+/// bit offset. Both fail with `OutOfBounds` if the bytes are too short to hold the bitfield.
+/// Views cannot be combined with a `bit_order` that differs from the `endian` parameter.
+///
+/// ### Example
+///
+/// ```
+/// # use modular_bitfield::prelude::*;
+/// #[bitfield(views)]
+/// pub struct Descriptor {
+///     ready: bool,
+///     owner: B7,
+///     len: u8,
+/// }
+///
+/// let mut ring = [0x00_u8; 64];
+/// let mut descriptor = DescriptorMut::new(&mut ring[4..]).unwrap();
+/// descriptor.set_len(42);
+/// descriptor.set_ready(true);
+/// assert_eq!(&ring[4..6], &[0x01, 42]);
+/// assert_eq!(DescriptorRef::new(&ring[4..]).unwrap().len(), 42);
+/// ```
+///
+/// ## Parameter: `builder`
+///
+/// Additionally generates a typestate builder `FooBuilder` for a `#[bitfield]` struct `Foo`
+/// which is returned by `Foo::builder()`. For every field `f` with setters the builder provides
+/// `with_f(value)` and `with_f_checked(value)`, taking all elements at once for array fields.
+///
+/// Every field without a `#[default = N]` is tracked by a type parameter of the builder. Such
+/// fields must be set exactly once and `build()` is only available after all of them have been
+/// set, so forgetting a field is a compile error. Fields with a `#[default = N]` may be set any
+/// number of times. Both `builder()` and `build()` are `const fn` and so are `with_f(value)` and
+/// `with_f_checked(value)` for fields with `const fn` accessors, see `#[const_fn]`. For array
+/// fields this requires elements of `bool`, primitive integers or the `B1`, .. `B128` and `S1`,
+/// .. `S128` specifiers.
+///
+/// ### Example
+///
+/// ```
+/// # use modular_bitfield::prelude::*;
+/// #[bitfield(builder)]
+/// pub struct Header {
+///     #[default = 1]
+///     version: B4,
+///     flag: bool,
+///     len: B3,
+/// }
+///
+/// let header = Header::builder().with_len(5).with_flag(true).build();
+/// assert_eq!(header.version(), 1);
+/// assert_eq!(header.len(), 5);
+/// ```
+///
+/// ## Parameter: `reflect`
+///
+/// Additionally generates an enum `FooField` for a `#[bitfield]` struct `Foo` with a variant for
+/// every field with getters, allowing fields to be accessed by a runtime value:
+///
+/// - `get_raw(field)`: Returns the raw bits of the field, panicking if the index is out of bounds.
+/// - `set_raw(field, raw)`: Sets the field to the raw bits or returns `OutOfBounds` if they are no
+///   valid bit pattern of the field or if the field has no setters.
+///
+/// Variants are the field names in upper camel case. Tuple fields and fields like `__` are named
+/// `FieldN` after their field number. Fields mapping onto the same variant, such as `lane_0` and
+/// `lane0`, are a compile error. Variants of array fields carry the index of the element.
+/// `FooField` implements `FromStr` and `Display` using the field names, e.g. `"lanes[2]"` for the
+/// third element of the array field `lanes`. Note that `get_raw` and `set_raw` share the visibility
+/// of the struct and so may access fields whose own accessors are private.
+///
+/// ### Example
+///
+/// ```
+/// # use modular_bitfield::prelude::*;
+/// #[bitfield(reflect)]
+/// pub struct Ctrl {
+///     enable: bool,
+///     mode: B3,
+///     lanes: [B2; 2],
+/// }
+///
+/// let mut ctrl = Ctrl::new();
+/// let field: CtrlField = "mode".parse().unwrap();
+/// assert_eq!(field, CtrlField::Mode);
+/// assert_eq!(ctrl.set_raw(field, 3), Ok(()));
+/// assert_eq!(ctrl.mode(), 3);
+/// assert!(ctrl.set_raw(field, 8).is_err());
+/// assert_eq!(ctrl.set_raw("lanes[1]".parse().unwrap(), 2), Ok(()));
+/// assert_eq!(ctrl.get_raw(CtrlField::Lanes(1)), 2);
+/// ```
+///
+/// ## Parameter: `bytemuck`
+///
+/// Requires the `bytemuck` crate feature and implements `bytemuck::Zeroable` for the filled
+/// `#[bitfield]` struct. If the struct also derives `Copy` and has no `#[repr(..)]` of its own
+/// it implements `bytemuck::Pod` and is made `#[repr(transparent)]` so that slices of it can be
+/// cast from and to byte slices. The parameter conflicts with `filled = false` and, for structs
+/// deriving `Copy`, with a `bit_order` that differs from the layout of the `endian` parameter
+/// since casts expose the internal bytes instead of the bytes of `into_bytes`.
+///
+/// ### Example
+///
+/// ```ignore
+/// # use modular_bitfield::prelude::*;
+/// #[bitfield(bytemuck)]
+/// #[derive(Clone, Copy)]
+/// pub struct Descriptor {
+///     ready: bool,
+///     owner: B7,
+/// }
+///
+/// let ring = [Descriptor::new().with_ready(true), Descriptor::new().with_owner(1)];
+/// assert_eq!(bytemuck::cast_slice::<_, u8>(&ring), &[0x01, 0x02]);
+/// ```
+///
+/// ## Parameter: `serde = "fields" | "bytes"`
+///
+/// Requires the `serde` crate feature and implements `serde::Serialize` and `serde::Deserialize`
+/// for the `#[bitfield]` struct.
+///
+/// - `fields`: Represents the bitfield as a map of its field names to their values. Enum fields
+///   are represented by their own `serde` impls, e.g. their variant names. Fields without getters
+///   or setters, including `#[reserved = N]` fields, are not represented. Deserialization uses the
+///   checked setters so that out of bounds values yield errors instead of panics.
+/// - `bytes`: Represents the bitfield as the byte array returned by `into_bytes`. Deserialization
+///   rejects the same bytes as `from_bytes` for `filled = false` bitfields and as
+///   `from_bytes_checked` for bitfields with `#[reserved = N]` fields.
+///
+/// ### Example
+///
+/// ```ignore
+/// # use modular_bitfield::prelude::*;
+/// #[derive(BitfieldSpecifier, serde::Serialize, serde::Deserialize)]
+/// pub enum Mode { Off, Sleep, On, Boost }
+///
+/// #[bitfield(serde = "fields")]
+/// pub struct Config {
+///     enabled: bool,
+///     mode: Mode,
+///     level: B6,
+/// }
+///
+/// let config = Config::new().with_enabled(true).with_mode(Mode::On).with_level(42);
+/// let json = serde_json::to_string(&config).unwrap();
+/// assert_eq!(json, r#"{"enabled":true,"mode":"On","level":42}"#);
+/// assert!(serde_json::from_str::<Config>(r#"{"enabled":true,"mode":"On","level":64}"#).is_err());
+/// ```
+///
+/// ## Parameter: `arbitrary = "fields" | "bytes"` and `proptest = "fields" | "bytes"`
+///
+/// Require the crate feature of the same name and implement `arbitrary::Arbitrary` or
+/// `proptest::arbitrary::Arbitrary` respectively for the `#[bitfield]` struct.
+///
+/// - `fields`: Sets every field with setters to a valid bit pattern of its specifier so that
+///   all getters of generated values succeed. Fields without setters keep the values of `new`.
+/// - `bytes`: Generates the underlying bytes as is and therefore also invalid bit patterns.
+///   Only bits at undefined positions of `filled = false` bitfields are cleared.
+///
+/// Enums deriving `BitfieldSpecifier` opt into the same impls with the `#[arbitrary]` and
+/// `#[proptest]` attributes. The `proptest` impls require a `Debug` impl.
+///
+/// ### Example
+///
+/// ```ignore
+/// # use modular_bitfield::prelude::*;
+/// use arbitrary::{Arbitrary, Unstructured};
+///
+/// #[derive(BitfieldSpecifier, Debug)]
+/// #[arbitrary]
+/// #[bits = 2]
+/// pub enum Mode { Off, On, Boost }
+///
+/// #[bitfield(arbitrary = "fields")]
+/// pub struct Config {
+///     mode: Mode,
+///     level: B6,
+/// }
+///
+/// let config = Config::arbitrary(&mut Unstructured::new(&[0x03, 0x2A])).unwrap();
+/// assert!(config.mode_or_err().is_ok());
+/// ```
+///
 /// ## Field Parameter: `#[bits = N]`
 ///
 /// To ensure at compile time that a field of a `#[bitfield]` struct has a bit width of exactly
@@ -199,6 +423,140 @@ pub fn define_specifiers(input: TokenStream) -> TokenStream {
 /// }
 /// ```
 ///
+/// ## Field Parameter: `#[reserved = N]`
+///
+/// Declares a field whose bits must always hold the mandated value `N`. The constructor
+/// `new()` initializes such fields to `N` and no setters are generated for them.
+///
+/// The fallible `from_bytes` of bitfields with `filled = false` as well as the `Specifier`
+/// implementation of bitfields that derive `BitfieldSpecifier` reject bytes with reserved bits
+/// that differ from their mandated values. For filled bitfields `from_bytes` stays infallible
+/// and `from_bytes_checked` performs this check instead.
+///
+/// ### Example
+///
+/// ```
+/// # use modular_bitfield::prelude::*;
+/// #[bitfield]
+/// pub struct Register {
+///     enable: bool,
+///     #[reserved = 0b10]
+///     reserved: B2,
+///     mode: B5,
+/// }
+///
+/// let register = Register::new().with_enable(true);
+/// assert_eq!(register.reserved(), 0b10);
+/// assert_eq!(register.into_bytes(), [0b0000_0101]);
+/// assert!(Register::from_bytes_checked([0b0000_0001]).is_err());
+/// ```
+///
+/// ## Field Parameter: `#[default = N]`
+///
+/// Declares the value `N` that the `const fn new()` and the `Default` implementation
+/// generated for `#[derive(Default)]` store for the field, e.g. the reset value of a hardware
+/// register. For array fields every element is initialized to `N`.
+///
+/// `N` is a possibly negative value for `S1`, .. `S128`, signed primitive integers and
+/// `Range<T, MIN, MAX>` fields, the discriminant of a unit variant for fields of enums deriving
+/// `BitfieldSpecifier` and the bit pattern of the field otherwise. Boolean fields also accept
+/// `true` and `false`. A value that is not valid for the type of the field is a compile error.
+///
+/// Fields without `#[default = N]` use the `Specifier::DEFAULT_BIT_PATTERN` of their type which
+/// is the `#[default]` variant of enums deriving `BitfieldSpecifier`, the `new()` of nested
+/// `#[bitfield]` structs and 0 otherwise.
+///
+/// ### Example
+///
+/// ```
+/// # use modular_bitfield::prelude::*;
+/// #[bitfield]
+/// #[derive(Default)]
+/// pub struct Header {
+///     #[default = 3]
+///     version: B4,
+///     #[default = true]
+///     enable: bool,
+///     #[default = -2]
+///     offset: S3,
+/// }
+///
+/// const HEADER: Header = Header::new();
+/// assert_eq!(HEADER.version(), 3);
+/// assert!(HEADER.enable());
+/// assert_eq!(HEADER.offset(), -2);
+/// assert_eq!(Header::default().into_bytes(), [0b1101_0011]);
+/// ```
+///
+/// ## Field Parameter: `#[const_fn]`
+///
+/// The getters as well as the `with_f` and `with_f_checked` methods of `bool`, primitive integer,
+/// `B1`, .. `B128` and `S1`, .. `S128` fields are `const fn`, which allows to build register values
+/// and lookup tables as `const` items. The setters taking `&mut self` are not. Fields of user types
+/// shadowing one of these specifiers by a plain identifier such as `B5` are therefore compile
+/// errors and must name the user type by a path instead.
+///
+/// `#[bitfield]` cannot see the definition of other field types. Fields of enums deriving
+/// `BitfieldSpecifier` opt into `const fn` accessors with `#[const_fn]`. This is not supported
+/// for enums with data-carrying variants other than a `#[fallback]` variant.
+///
+/// ### Example
+///
+/// ```
+/// # use modular_bitfield::prelude::*;
+/// #[derive(BitfieldSpecifier, Debug, PartialEq)]
+/// pub enum Mode {
+///     Off,
+///     Idle,
+///     Run,
+///     Sleep,
+/// }
+///
+/// #[bitfield]
+/// pub struct Control {
+///     enable: bool,
+///     #[const_fn]
+///     mode: Mode,
+///     divider: B5,
+/// }
+///
+/// const fn control(divider: u8) -> Control {
+///     Control::new().with_enable(true).with_mode(Mode::Run).with_divider(divider)
+/// }
+///
+/// const CONTROLS: [Control; 2] = [control(1), control(8)];
+/// const DIVIDER: u8 = CONTROLS[1].divider();
+/// assert_eq!(DIVIDER, 8);
+/// assert_eq!(CONTROLS[0].mode(), Mode::Run);
+/// ```
+///
+/// ## Field Parameter: `#[at = N]` and `#[bits(N..=M)]`
+///
+/// Pins a field to the absolute bit position `N` instead of placing it right after the
+/// preceding field. The bits between the preceding field and `N` are left as an implicit
+/// gap. `#[bits(N..=M)]` additionally asserts that the field spans exactly the bits `N`
+/// through `M`, which allows to transcribe register tables of datasheets directly.
+///
+/// Fields must be declared in ascending order. A field overlapping any of the preceding
+/// fields as well as positions beyond the `bits = N` of the bitfield are compile errors.
+///
+/// ### Example
+///
+/// ```
+/// # use modular_bitfield::prelude::*;
+/// #[bitfield]
+/// pub struct Status {
+///     ready: bool,      // bit 0
+///     #[at = 4]
+///     mode: B2,         // bits 4..=5
+///     #[bits(12..=15)]
+///     channel: B4,      // bits 12..=15
+/// }
+///
+/// let status = Status::new().with_ready(true).with_mode(0b11).with_channel(0b1010);
+/// assert_eq!(status.into_bytes(), [0b0011_0001, 0b1010_0000]);
+/// ```
+///
 /// # Features
 ///
 /// ## Support: `#[derive(BitfieldSpecifier)]`
@@ -207,8 +565,8 @@ pub fn define_specifiers(input: TokenStream) -> TokenStream {
 /// an implementation of the `Specifier` trait will be generated for it. This has the effect
 /// that the bitfield struct itself can be used as the type of a field of another bitfield type.
 ///
-/// This feature is limited to bitfield types that have a total bit width of 128 bit or fewer.
-/// This restriction is ensured at compile time.
+/// Bitfield types with a total bit width of more than 128 bits are supported as well.
+/// Their `Specifier::Bytes` is a little endian byte array instead of a primitive integer.
 ///
 /// ### Example
 ///
@@ -271,6 +629,30 @@ pub fn define_specifiers(input: TokenStream) -> TokenStream {
 /// );
 /// ```
 ///
+/// ## Support: `#[derive(defmt::Format)]`
+///
+/// With the `defmt` crate feature a `#[derive(defmt::Format)]` or `#[derive(Format)]` found by the
+/// `#[bitfield]` generates a field-wise `defmt::Format` implementation instead of relying on the
+/// much heavier `Debug` implementation. Fields and invalid bit patterns are displayed the same
+/// way as by `#[derive(Debug)]`. Enums used as fields simply derive `defmt::Format` themselves.
+///
+/// The crate using it must depend on `defmt` itself.
+///
+/// ### Example
+///
+/// ```ignore
+/// # use modular_bitfield::prelude::*;
+/// #[bitfield]
+/// #[derive(defmt::Format)]
+/// pub struct Package {
+///     is_received: bool,
+///     is_alive: bool,
+///     status: B6,
+/// }
+///
+/// defmt::info!("{}", Package::new().with_status(3));
+/// ```
+///
 /// ## Support: `#[repr(uN)]`
 ///
 /// It is possible to additionally annotate a `#[bitfield]` annotated struct with `#[repr(uN)]`
@@ -297,16 +679,186 @@ pub fn define_specifiers(input: TokenStream) -> TokenStream {
 /// assert_eq!(sint.abs_value(), 0b0011_1000);
 /// assert_eq!(u16::from(sint), 0b0111_0001_u16);
 /// ```
+///
+/// ## Support: Generic Structs
+///
+/// A `#[bitfield]` struct may be generic over the `Specifier` types of its fields.
+/// Since the size of the underlying byte array cannot depend on generic parameters
+/// the bit width of a generic bitfield must be given via the `bits = N` parameter.
+///
+/// The total bit width of the fields is checked against `N` for every instantiation.
+/// An instantiation with a mismatching bit width fails to compile as soon as it is
+/// constructed.
+///
+/// ### Example
+///
+/// ```
+/// # use modular_bitfield::prelude::*;
+/// #[derive(BitfieldSpecifier, Debug, PartialEq)]
+/// pub enum Kind {
+///     Data, Ack, Ping, Pong,
+/// }
+///
+/// #[bitfield(bits = 16)]
+/// pub struct Header<K: Specifier> {
+///     kind: K,  //  2 bits
+///     len: B14, // 14 bits
+/// }
+///
+/// let header = Header::<Kind>::new().with_kind(Kind::Ack).with_len(1000);
+/// assert_eq!(header.kind(), Kind::Ack);
+/// let header = Header::<B2>::new().with_kind(0b11).with_len(1000);
+/// assert_eq!(header.kind(), 0b11);
+/// ```
+///
+/// ## Support: Array Fields
+///
+/// Fields may be arrays of specifiers such as `lanes: [B4; 8]`. The elements are stored
+/// back to back and the getters and setters of such a field take the index of the element
+/// as their first argument. The index is checked and out of bounds indices panic.
+///
+/// Additionally `f_array()`, `set_f_array(values)`, `set_f_array_checked(values)` and
+/// `with_f_array(values)` get or set all elements of an array field `f` at once.
+///
+/// ### Example
+///
+/// ```
+/// # use modular_bitfield::prelude::*;
+/// #[bitfield]
+/// pub struct Lanes {
+///     enabled: bool,    //  1 bit
+///     lanes: [B4; 8],   // 32 bits
+///     rest: B7,         //  7 bits
+/// }
+///
+/// let mut lanes = Lanes::new().with_lanes(3, 0xA);
+/// assert_eq!(lanes.lanes(3), 0xA);
+/// lanes.set_lanes_array([1, 2, 3, 4, 5, 6, 7, 8]);
+/// assert_eq!(lanes.lanes(7), 8);
+/// assert_eq!(lanes.lanes_array(), [1, 2, 3, 4, 5, 6, 7, 8]);
+/// ```
+///
+/// ## Support: `Option<T>` Fields
+///
+/// Fields may be of type `Option<T>` without requiring additional bits if `T` leaves a bit
+/// pattern unused that can represent `None`. This is the case for `#[derive(BitfieldSpecifier)]`
+/// enums with fewer variants than their `#[bits = N]` allow, which implement `NicheSpecifier`.
+///
+/// For any other specifier the bit pattern representing `None` is given explicitly with a
+/// `#[none = N]` field attribute. Setters then reject `Some(value)` if `value` is encoded
+/// with the same bit pattern.
+///
+/// ### Example
+///
+/// ```
+/// # use modular_bitfield::prelude::*;
+/// #[derive(BitfieldSpecifier, Debug, PartialEq)]
+/// #[bits = 2]
+/// pub enum Speed {
+///     Slow,
+///     Fast,
+///     Turbo,
+/// }
+///
+/// #[bitfield]
+/// pub struct Config {
+///     speed: Option<Speed>, // 2 bits, `None` is encoded as `0b11`
+///     #[none = 0b111]
+///     level: Option<B3>,    // 3 bits
+///     rest: B3,             // 3 bits
+/// }
+///
+/// let mut config = Config::new().with_speed(None);
+/// assert_eq!(config.speed(), None);
+/// assert_eq!(config.level(), Some(0));
+/// config.set_level(None);
+/// assert_eq!(config.level(), None);
+/// assert!(config.set_level_checked(Some(0b111)).is_err());
+/// ```
+///
+/// ## Support: Validation
+///
+/// The getters of a `#[bitfield]` struct only detect invalid bit patterns when they are called.
+/// At trust boundaries such as parsing untrusted packets the generated `validate` and
+/// `from_bytes_validated` methods check all fields at once. The returned `InvalidFields`
+/// error lists the name and raw bits of every field with an invalid bit pattern, including
+/// `#[reserved = N]` fields that differ from their mandated value. Array fields are listed
+/// once with their first invalid element and the number of their invalid elements.
+///
+/// ### Example
+///
+/// ```
+/// # use modular_bitfield::prelude::*;
+/// #[derive(BitfieldSpecifier, Debug)]
+/// #[bits = 2]
+/// pub enum Kind {
+///     Data,
+///     Ack,
+///     Nack,
+/// }
+///
+/// #[bitfield]
+/// pub struct Packet {
+///     kind: Kind,
+///     lanes: [Kind; 3],
+/// }
+///
+/// assert!(Packet::from_bytes_validated([0b0010_0100]).is_ok());
+/// let errors = Packet::from_bytes_validated([0b1100_0011]).map(|_| ()).unwrap_err();
+/// assert_eq!(
+///     errors.to_string(),
+///     "encountered invalid bit patterns: kind = 0x3, lanes[2] = 0x3",
+/// );
+/// ```
+///
+/// ## Support: Field Metadata
+///
+/// Every `#[bitfield]` struct provides the associated constant `FIELDS` with a
+/// `modular_bitfield::layout::FieldInfo` per field in declaration order. It holds the name,
+/// bit offset, bit width, array length and byte order of the field as well as whether its
+/// getters or setters have been skipped. This allows tooling such as register dumps to walk
+/// the fields of a bitfield generically.
+///
+/// Each entry also holds the specifier type of the field as written in the struct and the
+/// variant names and bit patterns of `#[derive(BitfieldSpecifier)]` enums. The generated
+/// `layout_json()` function returns a `modular_bitfield::layout::LayoutJson` whose `Display`
+/// impl writes all of this together with the total bits, bytes and byte order of the bitfield
+/// as a JSON object, e.g. for documentation generators or test benches in other languages.
+///
+/// ### Example
+///
+/// ```
+/// # use modular_bitfield::prelude::*;
+/// #[bitfield]
+/// pub struct Status {
+///     ready: bool,
+///     #[endian = "big"]
+///     code: B12,
+///     #[skip]
+///     __: B3,
+/// }
+///
+/// let fields = Status::FIELDS
+///     .iter()
+///     .map(|field| (field.name(), field.offset(), field.bits()))
+///     .collect::<Vec<_>>();
+/// assert_eq!(fields, [("ready", 0, 1), ("code", 1, 12), ("__", 13, 3)]);
+/// assert!(Status::FIELDS[2].skip_getters());
+///
+/// let json = Status::layout_json().to_string();
+/// assert!(json.starts_with(r#"{"name":"Status","bits":16,"bytes":2,"endian":"native","bit_order":"lsb0","fields":["#));
+/// assert!(json.contains(r#"{"name":"code","offset":1,"bits":12,"array_len":null,"endian":"big","specifier":"B12","variants":[]}"#));
+/// ```
 #[proc_macro_attribute]
 pub fn bitfield(args: TokenStream, input: TokenStream) -> TokenStream {
     bitfield::analyse_and_expand(args.into(), input.into()).into()
 }
 
-/// Derive macro for Rust `enums` to implement `Specifier` trait.
+/// Derive macro for Rust `enums` and newtype `structs` to implement `Specifier` trait.
 ///
-/// This allows such an enum to be used as a field of a `#[bitfield]` struct.
-/// The annotated enum must not have any variants with associated data and
-/// by default must have a number of variants that is equal to the power of 2.
+/// This allows such an enum or newtype to be used as a field of a `#[bitfield]` struct.
+/// An enum without any variants with associated data by default must have
+/// a number of variants that is equal to the power of 2.
 ///
 /// If a user wants to circumvent the latter restriction they can add
 /// `#[bits = N]` below the `#[derive(BitfieldSpecifier)]` line in order to
@@ -364,6 +916,140 @@ pub fn bitfield(args: TokenStream, input: TokenStream) -> TokenStream {
 /// }
 /// ```
 ///
+/// ## Example: `#[fallback]`
+///
+/// Enums with fewer variants than `2^N` contain invalid bit patterns which make getters
+/// panic. A single variant with one unnamed field can be flagged with `#[fallback]` in
+/// order to capture all bit patterns that do not match any other variant. Such enums
+/// are required to specify `#[bits = N]` and decoding them never fails. The field of the
+/// fallback variant must be at least `N` bits wide and setting a fallback value that
+/// equals the discriminant of another variant fails with `OutOfBounds`.
+///
+/// ```
+/// # use modular_bitfield::prelude::*;
+/// #
+/// #[derive(BitfieldSpecifier, Debug, PartialEq)]
+/// #[bits = 3]
+/// pub enum Opcode {
+///     Load,
+///     Store,
+///     #[fallback]
+///     Unknown(u8),
+/// }
+///
+/// #[bitfield]
+/// pub struct Instruction {
+///     opcode: Opcode,
+///     operand: B5,
+/// }
+///
+/// let instr = Instruction::from_bytes([0b0000_0110]);
+/// assert_eq!(instr.opcode(), Opcode::Unknown(0b110));
+/// ```
+///
+/// ## Example: `#[default]`
+///
+/// A unit variant flagged with `#[default]` is stored by the constructors of `#[bitfield]`
+/// structs for fields of the enum that have no `#[default = N]` of their own. The attribute
+/// is shared with `#[derive(Default)]` so that both agree on the default variant.
+///
+/// ```
+/// # use modular_bitfield::prelude::*;
+/// #
+/// #[derive(BitfieldSpecifier, Debug, PartialEq, Default)]
+/// #[bits = 2]
+/// pub enum PowerMode {
+///     Off,
+///     #[default]
+///     Sleep,
+///     On,
+/// }
+///
+/// #[bitfield]
+/// pub struct Control {
+///     mode: PowerMode,
+///     level: B6,
+/// }
+///
+/// assert_eq!(Control::new().mode(), PowerMode::Sleep);
+/// assert_eq!(PowerMode::default(), PowerMode::Sleep);
+/// ```
+///
+/// ## Example: Data-Carrying Variants
+///
+/// Variants may carry a payload of fields whose types are themselves specifiers
+/// with `Self` as their in-out type, such as `bool`, primitive integers or other
+/// derived specifiers. Integer payload fields can be narrowed with `#[bits = N]`.
+///
+/// The index of the variant is stored as tag in the least significant bits followed
+/// by the fields of the payload in declaration order. The tag requires as many bits as
+/// needed to represent all variants and the payload as many bits as the biggest
+/// payload of all variants. Explicit discriminants are not supported for such enums
+/// and `#[bits = N]` on the enum declares its total bit width instead.
+///
+/// ```
+/// # use modular_bitfield::prelude::*;
+/// #
+/// #[derive(BitfieldSpecifier, Debug, PartialEq)]
+/// pub enum Cmd {
+///     Nop,
+///     Read(#[bits = 6] u8),
+///     Write { reg: B3Reg, val: bool },
+/// }
+///
+/// #[derive(BitfieldSpecifier, Debug, PartialEq)]
+/// #[bits = 3]
+/// pub enum B3Reg { R0, R1, R2, R3, R4 }
+///
+/// #[bitfield]
+/// pub struct Instruction {
+///     cmd: Cmd, // 2 bits tag + 6 bits payload
+///     arg: B8,
+/// }
+///
+/// let instr = Instruction::new().with_cmd(Cmd::Write { reg: B3Reg::R4, val: true });
+/// assert_eq!(instr.cmd(), Cmd::Write { reg: B3Reg::R4, val: true });
+/// ```
+///
+/// ## Example: Newtype Structs
+///
+/// Tuple structs with a single field forward to the `Specifier` of their field and
+/// are their own in-out type. This allows strongly typed fields without writing the
+/// conversions by hand. A `#[bits = N]` attribute on the field narrows its bit width.
+///
+/// Newtypes over specifiers that are not their own in-out type, such as `B1`, .. `B128`,
+/// `S1`, .. `S128` or `Range<T, MIN, MAX>`, are named specifiers that keep the in-out type
+/// of their field, e.g. `u8` for `B5`. This is decided by the `Specifier` impl of the field
+/// and not by its name.
+///
+/// ```
+/// # use modular_bitfield::prelude::*;
+/// #
+/// #[derive(BitfieldSpecifier, Debug, PartialEq)]
+/// pub struct Id(u16);
+///
+/// #[derive(BitfieldSpecifier, Debug, PartialEq)]
+/// pub struct Celsius(#[bits = 10] u16);
+///
+/// #[derive(BitfieldSpecifier)]
+/// pub struct Channel(B6);
+///
+/// #[bitfield]
+/// pub struct Reading {
+///     id: Id,               // 16 bits
+///     temperature: Celsius, // 10 bits
+///     channel: Channel,     //  6 bits
+/// }
+///
+/// let reading = Reading::new()
+///     .with_id(Id(42))
+///     .with_temperature(Celsius(1000))
+///     .with_channel(63);
+/// assert_eq!(reading.id(), Id(42));
+/// assert_eq!(reading.temperature(), Celsius(1000));
+/// assert_eq!(reading.channel(), 63);
+/// ```
+///
 /// ## Example: Use in `#[bitfield]`
 ///
 /// Given the above `Weekday` enum that starts at `Sunday` and uses 3 bits in total
@@ -432,7 +1118,7 @@ pub fn bitfield(args: TokenStream, input: TokenStream) -> TokenStream {
 /// assert_eq!(slot.to(), 15);
 /// assert!(!slot.expired());
 /// ```
-#[proc_macro_derive(BitfieldSpecifier, attributes(bits, endian))]
+#[proc_macro_derive(BitfieldSpecifier, attributes(bits, endian, fallback, default, arbitrary, proptest))]
 pub fn bitfield_specifier(input: TokenStream) -> TokenStream {
     bitfield_specifier::generate(input.into()).into()
 }
@@ -4,6 +4,7 @@ use core::fmt::Debug;
 
 /// The given value was out of range for the bitfield.
 #[derive(Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
 pub struct OutOfBounds;
 
 impl core::fmt::Display for OutOfBounds {
@@ -12,8 +13,22 @@ impl core::fmt::Display for OutOfBounds {
     }
 }
 
+/// The given name did not denote a field of the bitfield.
+///
+/// Returned when parsing the field enums generated by `#[bitfield(reflect)]`.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct UnknownField;
+
+impl core::fmt::Display for UnknownField {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        write!(f, "encountered an unknown field name")
+    }
+}
+
 /// The bitfield contained an invalid bit pattern.
 #[derive(Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
 pub struct InvalidBitPattern<Bytes> {
     pub invalid_bytes: Bytes,
 }
@@ -34,7 +49,7 @@ where
 impl<Bytes> InvalidBitPattern<Bytes> {
     /// Creates a new invalid bit pattern error.
     #[inline]
-    pub fn new(invalid_bytes: Bytes) -> Self {
+    pub const fn new(invalid_bytes: Bytes) -> Self {
         Self { invalid_bytes }
     }
 
@@ -44,3 +59,175 @@ impl<Bytes> InvalidBitPattern<Bytes> {
         self.invalid_bytes
     }
 }
+
+/// A field of a bitfield that contained an invalid bit pattern.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct InvalidField {
+    name: &'static str,
+    index: Option<usize>,
+    raw: u128,
+    invalid_elements: usize,
+}
+
+impl InvalidField {
+    /// Placeholder for the unused entries of [`InvalidFields`].
+    const EMPTY: Self = Self {
+        name: "",
+        index: None,
+        raw: 0,
+        invalid_elements: 0,
+    };
+
+    /// Returns the name of the invalid field.
+    ///
+    /// Fields of tuple structs are named by their field number.
+    #[inline]
+    pub fn name(&self) -> &'static str {
+        self.name
+    }
+
+    /// Returns the index of the first invalid element if the field is an array field.
+    #[inline]
+    pub fn index(&self) -> Option<usize> {
+        self.index
+    }
+
+    /// Returns the raw bits of the invalid field or of the first invalid element of an array field.
+    ///
+    /// Bits of fields wider than 128 bits are truncated.
+    #[inline]
+    pub fn raw(&self) -> u128 {
+        self.raw
+    }
+
+    /// Returns the number of invalid elements if the field is an array field or 1 otherwise.
+    #[inline]
+    pub fn invalid_elements(&self) -> usize {
+        self.invalid_elements
+    }
+}
+
+impl core::fmt::Display for InvalidField {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        match self.index {
+            Some(index) => write!(f, "{}[{}] = {:#X}", self.name, index, self.raw)?,
+            None => write!(f, "{} = {:#X}", self.name, self.raw)?,
+        }
+        match self.invalid_elements {
+            0 | 1 => Ok(()),
+            2 => write!(f, " and 1 more element"),
+            n => write!(f, " and {} more elements", n - 1),
+        }
+    }
+}
+
+/// The fields of a bitfield that contained invalid bit patterns.
+///
+/// Returned by the generated `validate` and `from_bytes_validated` methods of
+/// `#[bitfield]` structs where `N` is the number of fields of the bitfield.
+/// Array fields are listed once with their first invalid element and the number
+/// of their invalid elements.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct InvalidFields<const N: usize> {
+    fields: [InvalidField; N],
+    len: usize,
+    undefined_bits: bool,
+}
+
+impl<const N: usize> InvalidFields<N> {
+    /// Creates a new empty list of invalid fields.
+    #[doc(hidden)]
+    #[inline]
+    pub const fn new() -> Self {
+        Self {
+            fields: [InvalidField::EMPTY; N],
+            len: 0,
+            undefined_bits: false,
+        }
+    }
+
+    /// Records the invalid raw bits of the field with the given name.
+    ///
+    /// For array fields these are the bits of the first of `invalid_elements` invalid elements.
+    #[doc(hidden)]
+    #[inline]
+    pub fn push(
+        &mut self,
+        name: &'static str,
+        index: Option<usize>,
+        raw: u128,
+        invalid_elements: usize,
+    ) {
+        self.fields[self.len] = InvalidField {
+            name,
+            index,
+            raw,
+            invalid_elements,
+        };
+        self.len += 1;
+    }
+
+    /// Records that bits at positions that are undefined for the bitfield were set.
+    #[doc(hidden)]
+    #[inline]
+    pub fn set_undefined_bits(&mut self) {
+        self.undefined_bits = true;
+    }
+
+    /// Returns `Ok` if nothing invalid has been recorded.
+    #[doc(hidden)]
+    #[inline]
+    pub fn into_result(self) -> Result<(), Self> {
+        if self.len == 0 && !self.undefined_bits {
+            return Ok(())
+        }
+        Err(self)
+    }
+
+    /// Returns the fields that contained invalid bit patterns in declaration order.
+    #[inline]
+    pub fn fields(&self) -> &[InvalidField] {
+        &self.fields[..self.len]
+    }
+
+    /// Returns an iterator over the fields that contained invalid bit patterns.
+    #[inline]
+    pub fn iter(&self) -> core::slice::Iter<'_, InvalidField> {
+        self.fields().iter()
+    }
+
+    /// Returns `true` if bits at positions that are undefined for the bitfield were set.
+    ///
+    /// This can only happen for `#[bitfield(filled = false)]` structs.
+    #[inline]
+    pub fn has_undefined_bits(&self) -> bool {
+        self.undefined_bits
+    }
+}
+
+impl<'a, const N: usize> IntoIterator for &'a InvalidFields<N> {
+    type Item = &'a InvalidField;
+    type IntoIter = core::slice::Iter<'a, InvalidField>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<const N: usize> core::fmt::Display for InvalidFields<N> {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        if self.len == 0 {
+            return write!(f, "encountered bits at undefined positions")
+        }
+        write!(f, "encountered invalid bit patterns")?;
+        for (n, field) in self.iter().enumerate() {
+            let separator = if n == 0 { ": " } else { ", " };
+            write!(f, "{}{}", separator, field)?;
+        }
+        if self.undefined_bits {
+            write!(f, " and bits at undefined positions")?;
+        }
+        Ok(())
+    }
+}
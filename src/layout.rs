@@ -0,0 +1,269 @@
+//! Static descriptions of the fields of `#[bitfield]` structs.
+
+use core::fmt::{
+    self,
+    Write as _,
+};
+
+/// The byte order of a field as declared with `#[endian = ".."]` or the `endian` parameter.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum Endian {
+    /// The field is stored in little endian byte order.
+    Little,
+    /// The field is stored in big endian byte order.
+    Big,
+    /// The field is stored in the byte order of the target.
+    Native,
+}
+
+/// The numbering of the bits that the fields are allocated from as declared with the
+/// `bit_order` parameter.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum BitOrder {
+    /// Bit offset 0 is the least significant bit of the first byte.
+    Lsb0,
+    /// Bit offset 0 is the most significant bit of the first byte.
+    Msb0,
+}
+
+impl BitOrder {
+    /// Returns the name of the bit order as used by `LayoutJson`.
+    fn json_name(self) -> &'static str {
+        match self {
+            Self::Lsb0 => "lsb0",
+            Self::Msb0 => "msb0",
+        }
+    }
+}
+
+impl Endian {
+    /// Returns the name of the byte order as used by `LayoutJson`.
+    fn json_name(self) -> &'static str {
+        match self {
+            Self::Little => "little",
+            Self::Big => "big",
+            Self::Native => "native",
+        }
+    }
+}
+
+/// Describes a single field of a `#[bitfield]` struct.
+///
+/// Every `#[bitfield]` struct provides an entry per field in declaration order
+/// through its generated `FIELDS` constant.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct FieldInfo {
+    name: &'static str,
+    offset: usize,
+    bits: usize,
+    array_len: Option<usize>,
+    endian: Endian,
+    skip_getters: bool,
+    skip_setters: bool,
+    specifier: &'static str,
+    variants: &'static [(&'static str, u128)],
+}
+
+impl FieldInfo {
+    /// Creates a new description of a field.
+    #[doc(hidden)]
+    #[inline]
+    #[allow(clippy::too_many_arguments)]
+    pub const fn new(
+        name: &'static str,
+        offset: usize,
+        bits: usize,
+        array_len: Option<usize>,
+        endian: Endian,
+        skip_getters: bool,
+        skip_setters: bool,
+        specifier: &'static str,
+        variants: &'static [(&'static str, u128)],
+    ) -> Self {
+        Self {
+            name,
+            offset,
+            bits,
+            array_len,
+            endian,
+            skip_getters,
+            skip_setters,
+            specifier,
+            variants,
+        }
+    }
+
+    /// Returns the name of the field.
+    ///
+    /// Fields of tuple structs are named by their field number.
+    #[inline]
+    pub const fn name(&self) -> &'static str {
+        self.name
+    }
+
+    /// Returns the bit offset of the field within the bitfield.
+    ///
+    /// Offsets are counted in the bit order of the bitfield, i.e. from the most
+    /// significant bit of the first byte for `bit_order = "msb0"`.
+    #[inline]
+    pub const fn offset(&self) -> usize {
+        self.offset
+    }
+
+    /// Returns the bit width of the field.
+    ///
+    /// The bit width of an array field is the width of its elements times its length.
+    #[inline]
+    pub const fn bits(&self) -> usize {
+        self.bits
+    }
+
+    /// Returns the length of the field if it is an array field such as `[B4; 8]`.
+    #[inline]
+    pub const fn array_len(&self) -> Option<usize> {
+        self.array_len
+    }
+
+    /// Returns the byte order of the field.
+    #[inline]
+    pub const fn endian(&self) -> Endian {
+        self.endian
+    }
+
+    /// Returns `true` if no getters have been generated for the field.
+    #[inline]
+    pub const fn skip_getters(&self) -> bool {
+        self.skip_getters
+    }
+
+    /// Returns `true` if no setters have been generated for the field.
+    ///
+    /// This is always the case for `#[reserved = N]` fields.
+    #[inline]
+    pub const fn skip_setters(&self) -> bool {
+        self.skip_setters
+    }
+
+    /// Returns the specifier type of the field as written in the `#[bitfield]` struct.
+    ///
+    /// For array fields this is the type of the elements and for fields of generic
+    /// type this is the name of the type parameter.
+    #[inline]
+    pub const fn specifier(&self) -> &'static str {
+        self.specifier
+    }
+
+    /// Returns the names of the variants of the specifier of the field and their bit patterns.
+    ///
+    /// This is empty unless the specifier is an enum deriving `BitfieldSpecifier`,
+    /// see `Specifier::VARIANTS`.
+    #[inline]
+    pub const fn variants(&self) -> &'static [(&'static str, u128)] {
+        self.variants
+    }
+}
+
+/// The layout of a `#[bitfield]` struct that is displayed as a JSON object.
+///
+/// Returned by the generated `layout_json` function of `#[bitfield]` structs.
+/// The JSON object has the following form:
+///
+/// ```json
+/// {
+///     "name": "Ctrl", "bits": 8, "bytes": 1, "endian": "native", "bit_order": "lsb0",
+///     "fields": [
+///         {
+///             "name": "mode", "offset": 0, "bits": 2, "array_len": null, "endian": "native",
+///             "specifier": "Mode", "variants": [{ "name": "Off", "value": 0 }, ..]
+///         },
+///         ..
+///     ]
+/// }
+/// ```
+///
+/// All fields are listed in declaration order, including `#[skip]` and `#[reserved = N]` fields.
+/// Their offsets are counted in the given `bit_order`.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct LayoutJson {
+    name: &'static str,
+    bits: usize,
+    bytes: usize,
+    endian: Endian,
+    bit_order: BitOrder,
+    fields: &'static [FieldInfo],
+}
+
+impl LayoutJson {
+    /// Creates a new layout of a `#[bitfield]` struct.
+    #[doc(hidden)]
+    #[inline]
+    pub const fn new(
+        name: &'static str,
+        bits: usize,
+        bytes: usize,
+        endian: Endian,
+        bit_order: BitOrder,
+        fields: &'static [FieldInfo],
+    ) -> Self {
+        Self {
+            name,
+            bits,
+            bytes,
+            endian,
+            bit_order,
+            fields,
+        }
+    }
+}
+
+/// Writes the given string as a JSON string literal.
+fn write_json_str(f: &mut fmt::Formatter, s: &str) -> fmt::Result {
+    f.write_char('"')?;
+    for c in s.chars() {
+        match c {
+            '"' => f.write_str("\\\"")?,
+            '\\' => f.write_str("\\\\")?,
+            c => f.write_char(c)?,
+        }
+    }
+    f.write_char('"')
+}
+
+impl fmt::Display for LayoutJson {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("{\"name\":")?;
+        write_json_str(f, self.name)?;
+        write!(f, ",\"bits\":{},\"bytes\":{},\"endian\":", self.bits, self.bytes)?;
+        write_json_str(f, self.endian.json_name())?;
+        f.write_str(",\"bit_order\":")?;
+        write_json_str(f, self.bit_order.json_name())?;
+        f.write_str(",\"fields\":[")?;
+        for (n, field) in self.fields.iter().enumerate() {
+            if n != 0 {
+                f.write_char(',')?;
+            }
+            f.write_str("{\"name\":")?;
+            write_json_str(f, field.name)?;
+            write!(f, ",\"offset\":{},\"bits\":{},\"array_len\":", field.offset, field.bits)?;
+            match field.array_len {
+                Some(len) => write!(f, "{}", len)?,
+                None => f.write_str("null")?,
+            }
+            f.write_str(",\"endian\":")?;
+            write_json_str(f, field.endian.json_name())?;
+            f.write_str(",\"specifier\":")?;
+            write_json_str(f, field.specifier)?;
+            f.write_str(",\"variants\":[")?;
+            for (n, (name, value)) in field.variants.iter().enumerate() {
+                if n != 0 {
+                    f.write_char(',')?;
+                }
+                f.write_str("{\"name\":")?;
+                write_json_str(f, name)?;
+                write!(f, ",\"value\":{}}}", value)?;
+            }
+            f.write_str("]}")?;
+        }
+        f.write_str("]}")
+    }
+}
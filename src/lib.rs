@@ -82,6 +82,56 @@
 //! assert!(!data.is_alive());
 //! ```
 //!
+//! #### Example: Signed Specifiers
+//!
+//! The `S1`, `S2`, ... `S128` prelude types declare two's complement signed integers
+//! of the given number of bits. Their getters sign extend into the smallest fitting
+//! `i8`, `i16`, `i32`, `i64` or `i128` and their setters reject values outside of the
+//! signed range of the field:
+//!
+//! ```
+//! # use modular_bitfield::prelude::*;
+//! # use modular_bitfield::error::OutOfBounds;
+//! #
+//! #[bitfield]
+//! pub struct Sample {
+//!     x: S12,
+//!     y: S12,
+//!     status: B8,
+//! }
+//!
+//! let mut sample = Sample::new()
+//!     .with_x(-5)
+//!     .with_y(2047);
+//! assert_eq!(sample.x(), -5);
+//! assert_eq!(sample.y(), 2047);
+//! assert_eq!(sample.set_x_checked(-2049), Err(OutOfBounds));
+//! ```
+//!
+//! #### Example: Range Specifiers
+//!
+//! The `Range<T, MIN, MAX>` prelude type stores values of `T` in `MIN..=MAX` as their
+//! offset to `MIN` using only as many bits as required for `MAX - MIN`. Setters reject
+//! values outside of the range:
+//!
+//! ```
+//! # use modular_bitfield::prelude::*;
+//! # use modular_bitfield::error::OutOfBounds;
+//! #
+//! #[bitfield]
+//! pub struct Request {
+//!     burst_length: Range<u8, 1, 16>, // 4 bits
+//!     offset: Range<i8, -8, 7>,       // 4 bits
+//! }
+//!
+//! let mut request = Request::new();
+//! assert_eq!(request.burst_length(), 1);
+//! request.set_burst_length(16);
+//! assert_eq!(request.into_bytes(), [0b0000_1111]);
+//! # let mut request = Request::new();
+//! assert_eq!(request.set_burst_length_checked(0), Err(OutOfBounds));
+//! ```
+//!
 //! #### Example: Enum Specifiers
 //!
 //! It is possible to derive the `Specifier` trait for `enum` types very easily to make
@@ -336,6 +386,11 @@
 //! assert_eq!(data.status_or_err(), Ok(Status::Green));
 //! ```
 //!
+//! Instead of checking fields one by one the generated `validate` method checks all fields at
+//! once and returns an [`InvalidFields`](error::InvalidFields) error listing every invalid field.
+//! The generated `from_bytes_validated` constructor validates the bitfield right away which is
+//! useful when parsing untrusted data.
+//!
 //! ## Generated Implementations
 //!
 //! For the example `#[bitfield]` struct the following implementations are going to be generated:
@@ -355,6 +410,10 @@
 //! | `fn new() -> Self` | Creates a new instance of the bitfield with all bits initialized to 0. |
 //! | `fn from_bytes([u8; 1]) -> Self` | Creates a new instance of the bitfield from the given raw bytes. |
 //! | `fn into_bytes(self) -> [u8; 1]` | Returns the underlying bytes of the bitfield. |
+//! | `fn validate(&self) -> Result<(), InvalidFields<2>>` | Checks the bit patterns of all fields of the bitfield. |
+//! | `fn from_bytes_validated([u8; 1]) -> Result<Self, InvalidFields<2>>` | Creates a new instance of the bitfield from the given raw bytes and validates it. |
+//! | `const FIELDS: &[FieldInfo]` | Describes the name, bit offset, bit width and byte order of every field. |
+//! | `const fn layout_json() -> LayoutJson` | Returns the layout of the bitfield which is displayed as a JSON object. |
 //!
 //! And below the generated signatures for field `a`:
 //!
@@ -411,6 +470,35 @@
 //!                  ┊                                             ┊
 //!                least significant bit of d         most significant
 //! ```
+//!
+//! With `#[bitfield(bit_order = "msb0")]` the fields are instead allocated starting at the
+//! most significant bit of the first byte.
+//!
+//! ## Crate Features
+//!
+//! All crate features are disabled by default.
+//!
+//! - `bytemuck`: Enables the `#[bitfield(bytemuck)]` parameter implementing `bytemuck::Zeroable`
+//!   for filled `#[bitfield]` structs and `bytemuck::Pod` for those that also derive `Copy` and have
+//!   no `#[repr(..)]` of their own. Such bitfields are made `#[repr(transparent)]` so that slices of
+//!   them can be cast from and to byte slices with `bytemuck::cast_slice`. Bitfields without the
+//!   parameter contain no unsafe code, also in crates with `#![forbid(unsafe_code)]`.
+//! - `zerocopy`: Forwards the `zerocopy` derives such as `FromBytes`, `IntoBytes` and `KnownLayout`
+//!   of filled `#[bitfield]` structs to their underlying byte array and makes them `#[repr(transparent)]`.
+//!   Since any bytes may then be reinterpreted as the bitfield, fields with invalid bit patterns
+//!   according to `Specifier::ALL_BIT_PATTERNS_VALID`, `#[reserved = N]` fields as well as generic
+//!   bitfields are rejected at compile time. So are derives such as `FromBytes` and `IntoBytes` that
+//!   access the internal bytes if the `bit_order` differs from the layout of the `endian` parameter.
+//! - `serde`: Enables the `#[bitfield(serde = "fields" | "bytes")]` parameter implementing
+//!   `serde::Serialize` and `serde::Deserialize` for `#[bitfield]` structs either as a map of
+//!   their fields or as their packed bytes.
+//! - `defmt`: Generates a `defmt::Format` implementation for `#[bitfield]` structs with a
+//!   `#[derive(defmt::Format)]` that formats their fields like `#[derive(Debug)]` does.
+//! - `arbitrary` and `proptest`: Enable the `#[bitfield(arbitrary = "fields" | "bytes")]` and
+//!   `#[bitfield(proptest = "fields" | "bytes")]` parameters as well as the `#[arbitrary]` and
+//!   `#[proptest]` attributes of `#[derive(BitfieldSpecifier)]` enums. These implement
+//!   `arbitrary::Arbitrary` and `proptest::arbitrary::Arbitrary` from valid bit patterns of
+//!   their fields so that generated values always round-trip, or from raw bytes.
 
 #![no_std]
 #![forbid(unsafe_code)]
@@ -418,6 +506,7 @@
 extern crate static_assertions;
 
 pub mod error;
+pub mod layout;
 #[doc(hidden)]
 pub mod private;
 
@@ -436,6 +525,7 @@ pub mod prelude {
         bitfield,
         specifiers::*,
         BitfieldSpecifier,
+        NicheSpecifier,
         Specifier,
     };
 }
@@ -448,7 +538,8 @@ pub mod prelude {
 /// # Note
 ///
 /// These can be all unsigned fixed-size primitives,
-/// represented by `B1, B2, ... B64` and enums that
+/// represented by `B1, B2, ... B128`, all signed fixed-size
+/// primitives, represented by `S1, S2, ... S128` and enums that
 /// derive from `BitfieldSpecifier`.
 pub trait Specifier {
     /// The amount of bits used by the specifier.
@@ -460,6 +551,8 @@ pub trait Specifier {
     /// # Note
     ///
     /// This is the type that is used internally for computations.
+    /// For `#[bitfield]` specifiers wider than 128 bits this is a byte array
+    /// representing a little endian integer.
     type Bytes;
 
     /// The interface type of the specifier.
@@ -469,6 +562,44 @@ pub trait Specifier {
     /// This is the type that is used for the getters and setters.
     type InOut;
 
+    /// Whether `from_bytes` accepts every bit pattern of `BITS` bits.
+    ///
+    /// # Note
+    ///
+    /// Defaults to `false` which is always sound. It is used to reject fields with
+    /// invalid bit patterns where those patterns cannot be guarded against,
+    /// e.g. for `#[bitfield]` structs deriving the `zerocopy` traits.
+    const ALL_BIT_PATTERNS_VALID: bool = false;
+
+    /// The bit pattern that `#[bitfield]` constructors store for fields of this specifier.
+    ///
+    /// # Note
+    ///
+    /// Defaults to `0`. `#[derive(BitfieldSpecifier)]` enums set it to the discriminant of
+    /// their `#[default]` variant and `#[bitfield]` specifiers to the bits of their `new`.
+    /// Only the `BITS` least significant bits are used. Bits beyond 128 are taken from
+    /// `DEFAULT_BYTES`.
+    const DEFAULT_BIT_PATTERN: u128 = 0;
+
+    /// The little endian bytes of the default bit pattern of specifiers wider than 128 bits.
+    ///
+    /// Only the bits beyond the 128 bits of `DEFAULT_BIT_PATTERN` are used and missing bits are zero.
+    #[doc(hidden)]
+    const DEFAULT_BYTES: &'static [u8] = &[];
+
+    /// The names of the variants of this specifier and their bit patterns.
+    ///
+    /// # Note
+    ///
+    /// Defaults to no variants. `#[derive(BitfieldSpecifier)]` enums list their unit variants
+    /// with their discriminants and their data-carrying variants with their tags. It is used
+    /// to describe enum fields in the layouts of `#[bitfield]` structs.
+    const VARIANTS: &'static [(&'static str, u128)] = &[];
+
+    /// How `#[bitfield]` fields of this specifier interpret the `N` of `#[default = N]`.
+    #[doc(hidden)]
+    const DEFAULT_VALUE: private::DefaultValue = private::DefaultValue::BitPattern;
+
     /// Converts some bytes into the in-out type.
     ///
     /// # Errors
@@ -489,9 +620,51 @@ pub trait Specifier {
     fn from_bytes(
         bytes: Self::Bytes,
     ) -> Result<Self::InOut, InvalidBitPattern<Self::Bytes>>;
+
+    /// Maps the given raw bits onto a bit pattern that `from_bytes` accepts.
+    ///
+    /// # Note
+    ///
+    /// Used to generate arbitrary valid values, e.g. by the `arbitrary` and `proptest`
+    /// crate features. Smaller raw values map to simpler bit patterns so that shrinking
+    /// converges towards them.
+    ///
+    /// Defaults to `None` which signals that the specifier does not support this.
+    /// This is also the case for generic `#[bitfield]` structs and for `#[bitfield]`
+    /// structs if neither the `arbitrary` nor the `proptest` crate feature is enabled.
+    #[doc(hidden)]
+    #[inline]
+    fn valid_bit_pattern(raw: u128) -> Option<Self::Bytes> {
+        let _ = raw;
+        None
+    }
+}
+
+/// Trait implemented by bitfield specifiers that have at least one invalid bit pattern.
+///
+/// This allows `Option<T>` to be used as bitfield specifier for any such `T`
+/// where the invalid bit pattern represents `None`.
+///
+/// # Note
+///
+/// Implemented by `#[derive(BitfieldSpecifier)]` for enums with a `#[bits = N]`
+/// attribute that have fewer than `2^N` variants.
+pub trait NicheSpecifier: Specifier {
+    /// A bit pattern that `Specifier::from_bytes` rejects for `Self`.
+    const NICHE: <Self as Specifier>::Bytes;
 }
 
 /// The default set of predefined specifiers.
 pub mod specifiers {
     ::modular_bitfield_impl::define_specifiers!();
+
+    /// Specifier for values of `T` within the inclusive range `MIN..=MAX`.
+    ///
+    /// Values are stored as their offset to `MIN` and therefore only require as many
+    /// bits as needed to represent `MAX - MIN`, e.g. 4 bits for `Range<u8, 1, 16>`.
+    /// Setters reject values outside of the range with `OutOfBounds`.
+    ///
+    /// `T` can be any of `u8`, `u16`, `u32`, `u64`, `i8`, `i16`, `i32` or `i64`.
+    /// Bounds that are not representable by `T` or with `MIN >= MAX` fail to compile.
+    pub struct Range<T, const MIN: i128, const MAX: i128>(::core::marker::PhantomData<T>);
 }
@@ -0,0 +1,48 @@
+use crate::Specifier;
+use arbitrary::{
+    Error,
+    Result,
+    Unstructured,
+};
+
+/// Reads the raw bits for a specifier with the given amount of bits.
+///
+/// Reads one byte more than needed so that specifiers that split the raw bits,
+/// e.g. into the tag and the payload of an enum, still draw from all of them.
+/// Exhausted data yields zero bits.
+fn raw_bits(u: &mut Unstructured, bits: usize) -> Result<u128> {
+    let mut bytes = [0x00_u8; 16];
+    let len = core::cmp::min(bits / 8 + 1, bytes.len());
+    u.fill_buffer(&mut bytes[..len])?;
+    Ok(u128::from_le_bytes(bytes))
+}
+
+/// Generates an arbitrary value of the specifier `T` from one of its valid bit patterns.
+#[doc(hidden)]
+pub fn arbitrary_specifier<T>(u: &mut Unstructured) -> Result<T::InOut>
+where
+    T: Specifier,
+{
+    let raw = raw_bits(u, T::BITS)?;
+    let bytes = T::valid_bit_pattern(raw).ok_or(Error::IncorrectFormat)?;
+    T::from_bytes(bytes).map_err(|_| Error::IncorrectFormat)
+}
+
+/// Generates an arbitrary `#[bitfield]` struct whose fields are set to valid bit patterns.
+///
+/// The given constructor requests the raw bits of each field with the bit width of its specifier.
+#[doc(hidden)]
+pub fn arbitrary_fields<T>(
+    u: &mut Unstructured,
+    from_raw_fields: fn(&mut dyn FnMut(usize) -> u128) -> Option<T>,
+) -> Result<T> {
+    let mut result = Ok(());
+    let value = from_raw_fields(&mut |bits| {
+        raw_bits(u, bits).unwrap_or_else(|error| {
+            result = Err(error);
+            0
+        })
+    });
+    result?;
+    value.ok_or(Error::IncorrectFormat)
+}
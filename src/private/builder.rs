@@ -0,0 +1,7 @@
+/// Marks a required field of a `#[bitfield(builder)]` builder that has not been set yet.
+#[doc(hidden)]
+pub enum Unset {}
+
+/// Marks a required field of a `#[bitfield(builder)]` builder that has been set.
+#[doc(hidden)]
+pub enum Set {}
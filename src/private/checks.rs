@@ -10,7 +10,7 @@ macro_rules! impl_sealed_for {
         )*
     }
 }
-impl_sealed_for!(bool, u8, u16, u32, u64, u128);
+impl_sealed_for!(bool, u8, u16, u32, u64, u128, i8, i16, i32, i64);
 
 /// Helper trait to check whether the size of bitfield structs
 /// is a multiple of 8 to form complete bytes.
@@ -89,6 +89,14 @@ pub trait DiscriminantInRange: private::Sealed {}
 /// at most 128 bits.
 pub trait SpecifierHasAtMost128Bits: private::Sealed {}
 
+/// Helper trait to check if the tag and the biggest payload of a data-carrying
+/// `#[derive(BitfieldSpecifier)]` enum fit into its bit width.
+pub trait VariantsFitIntoBits: private::Sealed {}
+
+/// Helper trait to check if a field of a `#[bitfield]` struct deriving `zerocopy`
+/// traits accepts all of its bit patterns.
+pub trait HasNoInvalidBitPatterns: private::Sealed {}
+
 /// Helper type to state that something is `true`.
 ///
 /// # Note
@@ -104,8 +112,11 @@ pub enum True {}
 pub enum False {}
 
 impl private::Sealed for True {}
+impl private::Sealed for False {}
 impl DiscriminantInRange for True {}
 impl SpecifierHasAtMost128Bits for True {}
+impl VariantsFitIntoBits for True {}
+impl HasNoInvalidBitPatterns for True {}
 impl FillsUnalignedBits for True {}
 impl DoesNotFillUnalignedBits for True {}
 
@@ -143,6 +154,24 @@ where
     type CheckType: DispatchTrueFalse;
 }
 
+/// Traits to check at compile-time if the tag and all payloads of a data-carrying
+/// `#[derive(BitfieldSpecifier)]` enum fit into its specified bit width.
+pub trait CheckVariantsFitIntoBits
+where
+    <Self::CheckType as DispatchTrueFalse>::Out: VariantsFitIntoBits,
+{
+    type CheckType: DispatchTrueFalse;
+}
+
+/// Traits to check at compile-time if the field `A` of a `#[bitfield]` struct deriving
+/// `zerocopy` traits accepts all of its bit patterns.
+pub trait CheckHasNoInvalidBitPatterns<A>
+where
+    <Self::CheckType as DispatchTrueFalse>::Out: HasNoInvalidBitPatterns,
+{
+    type CheckType: DispatchTrueFalse;
+}
+
 /// Helper type to check whether a bitfield member aligns to
 /// the specified bits.
 pub struct BitsCheck<A> {
@@ -0,0 +1,47 @@
+use core::fmt::{
+    Debug,
+    Formatter,
+    Result,
+};
+
+/// Formats the result of a checked getter of a `#[bitfield]` field.
+///
+/// Valid values are formatted as is and invalid bit patterns as their error.
+#[doc(hidden)]
+pub struct DebugResult<T, E>(pub core::result::Result<T, E>);
+
+impl<T, E> Debug for DebugResult<T, E>
+where
+    T: Debug,
+    E: Debug,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        match &self.0 {
+            Ok(value) => value.fmt(f),
+            Err(error) => error.fmt(f),
+        }
+    }
+}
+
+/// Formats the result of a checked getter of a `#[bitfield]` field via `defmt`.
+///
+/// Valid values are formatted as is and invalid bit patterns as their error.
+#[cfg(feature = "defmt")]
+#[doc(hidden)]
+pub struct DefmtResult<T, E>(pub core::result::Result<T, E>);
+
+#[cfg(feature = "defmt")]
+impl<T, E> defmt::Format for DefmtResult<T, E>
+where
+    T: defmt::Format,
+    E: defmt::Format,
+{
+    fn format(&self, f: defmt::Formatter<'_>) {
+        // Derived `defmt::Format` impls encode themselves without `format` which panics
+        // for them. Therefore the values are forwarded as arguments instead.
+        match &self.0 {
+            Ok(value) => defmt::write!(f, "{}", value),
+            Err(error) => defmt::write!(f, "{}", error),
+        }
+    }
+}
@@ -3,14 +3,23 @@ use crate::{
         InvalidBitPattern,
         OutOfBounds,
     },
+    private::{
+        DefaultValue,
+        RangeValue,
+        SpecifierBytesOps,
+    },
+    specifiers::Range,
+    NicheSpecifier,
     Specifier,
 };
+use core::marker::PhantomData;
 
 impl Specifier for bool {
     const BITS: usize = 1;
     const STRUCT: bool = false;
     type Bytes = u8;
     type InOut = bool;
+    const ALL_BIT_PATTERNS_VALID: bool = true;
 
     #[inline]
     fn into_bytes(input: Self::InOut) -> Result<Self::Bytes, OutOfBounds> {
@@ -27,16 +36,23 @@ impl Specifier for bool {
             invalid_bytes => Err(InvalidBitPattern { invalid_bytes }),
         }
     }
+
+    #[inline]
+    fn valid_bit_pattern(raw: u128) -> Option<Self::Bytes> {
+        Some((raw & 0x01) as u8)
+    }
 }
 
 macro_rules! impl_specifier_for_primitive {
-    ( $( ($prim:ty: $bits:literal) ),* $(,)? ) => {
+    ( $( ($prim:ty: $bits:literal, $default_value:ident) ),* $(,)? ) => {
         $(
             impl Specifier for $prim {
                 const BITS: usize = $bits;
                 const STRUCT: bool = false;
                 type Bytes = $prim;
                 type InOut = $prim;
+                const ALL_BIT_PATTERNS_VALID: bool = true;
+                const DEFAULT_VALUE: DefaultValue = DefaultValue::$default_value;
 
                 #[inline]
                 fn into_bytes(input: Self::InOut) -> Result<Self::Bytes, OutOfBounds> {
@@ -47,19 +63,184 @@ macro_rules! impl_specifier_for_primitive {
                 fn from_bytes(bytes: Self::Bytes) -> Result<Self::InOut, InvalidBitPattern<Self::Bytes>> {
                     Ok(bytes)
                 }
+
+                #[inline]
+                fn valid_bit_pattern(raw: u128) -> Option<Self::Bytes> {
+                    Some(raw as $prim)
+                }
             }
         )*
     };
 }
 impl_specifier_for_primitive!(
-    (u8: 8),
-    (u16: 16),
-    (u32: 32),
-    (u64: 64),
-    (u128: 128),
-    (i8: 8),
-    (i16: 16),
-    (i32: 32),
-    (i64: 64),
-    (i128: 128),
+    (u8: 8, BitPattern),
+    (u16: 16, BitPattern),
+    (u32: 32, BitPattern),
+    (u64: 64, BitPattern),
+    (u128: 128, BitPattern),
+    (i8: 8, Signed),
+    (i16: 16, Signed),
+    (i32: 32, Signed),
+    (i64: 64, Signed),
+    (i128: 128, Signed),
 );
+
+impl<T> Specifier for Option<T>
+where
+    T: NicheSpecifier,
+    T::Bytes: PartialEq,
+{
+    const BITS: usize = T::BITS;
+    const STRUCT: bool = T::STRUCT;
+    type Bytes = T::Bytes;
+    type InOut = Option<T::InOut>;
+    const VARIANTS: &'static [(&'static str, u128)] = T::VARIANTS;
+
+    #[inline]
+    fn into_bytes(input: Self::InOut) -> Result<Self::Bytes, OutOfBounds> {
+        match input {
+            Some(input) => T::into_bytes(input),
+            None => Ok(T::NICHE),
+        }
+    }
+
+    #[inline]
+    fn from_bytes(bytes: Self::Bytes) -> Result<Self::InOut, InvalidBitPattern<Self::Bytes>> {
+        if bytes == T::NICHE {
+            return Ok(None)
+        }
+        T::from_bytes(bytes).map(Some)
+    }
+
+    #[inline]
+    fn valid_bit_pattern(raw: u128) -> Option<Self::Bytes> {
+        if raw & 0x01 == 0 {
+            return Some(T::NICHE)
+        }
+        T::valid_bit_pattern(raw >> 1)
+    }
+}
+
+/// Specifier of `Option<T>` fields with a `#[none = NONE]` attribute.
+///
+/// The bit pattern `NONE` represents `None` and is rejected as value of `Some`.
+#[doc(hidden)]
+pub struct NoneAt<T, const NONE: u128>(PhantomData<T>);
+
+impl<T, const NONE: u128> NoneAt<T, NONE>
+where
+    T: Specifier,
+    T::Bytes: SpecifierBytesOps,
+{
+    /// Asserts at compile time that `NONE` fits into the bit width of `T`.
+    const NONE_FITS_INTO_BITS: () = assert!(
+        T::BITS >= 128 || NONE >> T::BITS == 0,
+        "the #[none = N] bit pattern does not fit into the bit width of the field"
+    );
+
+    /// Returns the bit pattern representing `None`.
+    #[inline]
+    fn none() -> T::Bytes {
+        #[allow(clippy::let_unit_value)]
+        let () = Self::NONE_FITS_INTO_BITS;
+        <T::Bytes as SpecifierBytesOps>::from_le_slice(&NONE.to_le_bytes())
+    }
+}
+
+impl<T, const NONE: u128> Specifier for NoneAt<T, NONE>
+where
+    T: Specifier,
+    T::Bytes: SpecifierBytesOps + PartialEq,
+{
+    const BITS: usize = T::BITS;
+    const STRUCT: bool = T::STRUCT;
+    type Bytes = T::Bytes;
+    type InOut = Option<T::InOut>;
+    const ALL_BIT_PATTERNS_VALID: bool = T::ALL_BIT_PATTERNS_VALID;
+    const VARIANTS: &'static [(&'static str, u128)] = T::VARIANTS;
+
+    #[inline]
+    fn into_bytes(input: Self::InOut) -> Result<Self::Bytes, OutOfBounds> {
+        match input {
+            Some(input) => {
+                let bytes = T::into_bytes(input)?;
+                if bytes == Self::none() {
+                    return Err(OutOfBounds)
+                }
+                Ok(bytes)
+            }
+            None => Ok(Self::none()),
+        }
+    }
+
+    #[inline]
+    fn from_bytes(bytes: Self::Bytes) -> Result<Self::InOut, InvalidBitPattern<Self::Bytes>> {
+        if bytes == Self::none() {
+            return Ok(None)
+        }
+        T::from_bytes(bytes).map(Some)
+    }
+
+    #[inline]
+    fn valid_bit_pattern(raw: u128) -> Option<Self::Bytes> {
+        T::valid_bit_pattern(raw)
+    }
+}
+
+impl<T, const MIN: i128, const MAX: i128> Range<T, MIN, MAX>
+where
+    T: RangeValue,
+{
+    /// Asserts at compile time that `MIN..=MAX` is a non-empty range of values of `T`.
+    const BOUNDS_ARE_VALID: () = assert!(
+        MIN < MAX && T::MIN <= MIN && MAX <= T::MAX,
+        "the bounds of Range<T, MIN, MAX> must satisfy MIN < MAX and be representable by T"
+    );
+
+    /// The largest offset to `MIN` that is stored.
+    const MAX_OFFSET: u128 = (MAX as u128).wrapping_sub(MIN as u128);
+}
+
+impl<T, const MIN: i128, const MAX: i128> Specifier for Range<T, MIN, MAX>
+where
+    T: RangeValue,
+{
+    const BITS: usize = {
+        #[allow(clippy::let_unit_value)]
+        let () = Self::BOUNDS_ARE_VALID;
+        (u128::BITS - Self::MAX_OFFSET.leading_zeros()) as usize
+    };
+    const STRUCT: bool = false;
+    type Bytes = T::Bytes;
+    type InOut = T;
+    const ALL_BIT_PATTERNS_VALID: bool =
+        (Self::MAX_OFFSET & Self::MAX_OFFSET.wrapping_add(1)) == 0;
+    const DEFAULT_VALUE: DefaultValue = DefaultValue::Range { min: MIN, max: MAX };
+
+    #[inline]
+    fn into_bytes(input: Self::InOut) -> Result<Self::Bytes, OutOfBounds> {
+        let value = input.into_i128();
+        if !(MIN..=MAX).contains(&value) {
+            return Err(OutOfBounds)
+        }
+        Ok(T::bytes_from_offset((value - MIN) as u128))
+    }
+
+    #[inline]
+    fn from_bytes(bytes: Self::Bytes) -> Result<Self::InOut, InvalidBitPattern<Self::Bytes>> {
+        let offset = T::offset_from_bytes(bytes);
+        if offset > Self::MAX_OFFSET {
+            return Err(InvalidBitPattern::new(T::bytes_from_offset(offset)))
+        }
+        Ok(T::from_i128(MIN + offset as i128))
+    }
+
+    #[inline]
+    fn valid_bit_pattern(raw: u128) -> Option<Self::Bytes> {
+        let offset = match Self::MAX_OFFSET.checked_add(1) {
+            Some(count) => raw % count,
+            None => raw,
+        };
+        Some(T::bytes_from_offset(offset))
+    }
+}
@@ -1,17 +1,78 @@
-mod array_bytes_conv;
+mod builder;
 pub mod checks;
+mod debug;
 mod impls;
 mod proc;
 mod push_pop;
+#[cfg(feature = "arbitrary")]
+mod arbitrary_support;
+#[cfg(feature = "proptest")]
+mod proptest_support;
+#[cfg(feature = "serde")]
+mod serde_support;
 mod traits;
 
 pub mod static_assertions {
     pub use static_assertions::*;
 }
+#[cfg(feature = "bytemuck")]
+pub use bytemuck;
+#[cfg(feature = "serde")]
+pub use self::serde_support::{
+    FieldIndex,
+    SerdeArray,
+    SerdeBytes,
+};
+#[cfg(feature = "serde")]
+pub use serde;
+#[cfg(feature = "defmt")]
+pub use self::debug::DefmtResult;
+#[cfg(feature = "defmt")]
+pub use defmt;
+#[cfg(feature = "arbitrary")]
+pub use self::arbitrary_support::{
+    arbitrary_fields,
+    arbitrary_specifier,
+};
+#[cfg(feature = "arbitrary")]
+pub use arbitrary;
+#[cfg(feature = "proptest")]
+pub use self::proptest_support::{
+    proptest_bytes,
+    proptest_fields,
+    proptest_specifier,
+};
+#[cfg(feature = "proptest")]
+pub use proptest;
 pub use self::{
-    array_bytes_conv::ArrayBytesConversion,
+    builder::{
+        Set,
+        Unset,
+    },
+    debug::DebugResult,
+    impls::NoneAt,
     proc::{
+        DefaultValue,
+        bit_pattern_from_le_bytes,
+        bytes_from_u128,
+        bytes_to_u128,
+        default_bit_pattern,
+        matches_masked,
+        narrow_valid_bit_pattern,
+        parse_field_index,
+        read_bits_be,
+        read_bits_le,
         read_specifier_be,
+        reverse_bits_in_bytes,
+        set_bits_be,
+        set_bits_le,
+        set_wide_bits_be,
+        set_wide_bits_le,
+        sign_extend,
+        signed_bit_pattern,
+        unsigned_bit_pattern,
+        write_bits_be,
+        write_bits_le,
         write_specifier_be,
         read_specifier_le,
         write_specifier_le,
@@ -21,13 +82,19 @@ pub use self::{
         PushBuffer,
     },
     traits::{
+        GenericSpecifierBytes,
         IsU128Compatible,
         IsU16Compatible,
         IsU32Compatible,
         IsU64Compatible,
         IsU8Compatible,
+        Newtype,
+        NewtypeInOut,
         PopBits,
         PushBits,
+        RangeValue,
+        SelectSpecifierBytes,
         SpecifierBytes,
+        SpecifierBytesOps,
     },
 };
@@ -4,6 +4,7 @@ use crate::{
         PopBuffer,
         PushBits,
         PushBuffer,
+        SpecifierBytesOps,
     },
     Specifier,
 };
@@ -215,3 +216,382 @@ pub fn write_specifier_be<T>(
         }
     }
 }
+
+/// Returns bit `i` of the bit pattern given by `value` and the little endian `wide` bytes.
+///
+/// The first 128 bits are taken from `value` and all further bits from `wide`.
+const fn bit_of(value: u128, wide: &[u8], i: usize) -> bool {
+    if i < 128 {
+        (value >> i) & 0x01 == 0x01
+    } else {
+        i / 8 < wide.len() && (wide[i / 8] >> (i % 8)) & 0x01 == 0x01
+    }
+}
+
+/// Sets the `bits` least significant bits of `value` at the bit `offset` of `bytes`
+/// using the bit order of `write_specifier_le`.
+#[doc(hidden)]
+pub const fn set_bits_le<const N: usize>(
+    bytes: [u8; N],
+    offset: usize,
+    bits: usize,
+    value: u128,
+) -> [u8; N] {
+    set_wide_bits_le(bytes, offset, bits, value, &[])
+}
+
+/// Sets the `bits` least significant bits of `value` at the bit `offset` of `bytes`
+/// using the bit order of `write_specifier_be`.
+#[doc(hidden)]
+pub const fn set_bits_be<const N: usize>(
+    bytes: [u8; N],
+    offset: usize,
+    bits: usize,
+    value: u128,
+) -> [u8; N] {
+    set_wide_bits_be(bytes, offset, bits, value, &[])
+}
+
+/// Sets the `bits` least significant bits of the bit pattern given by `value` and the
+/// little endian `wide` bytes at the bit `offset` of `bytes` using the bit order of
+/// `write_specifier_le`.
+///
+/// Used to store the `Specifier::DEFAULT_BYTES` of specifiers wider than 128 bits.
+#[doc(hidden)]
+pub const fn set_wide_bits_le<const N: usize>(
+    mut bytes: [u8; N],
+    offset: usize,
+    bits: usize,
+    value: u128,
+    wide: &[u8],
+) -> [u8; N] {
+    let mut i = 0;
+    while i < bits {
+        if bit_of(value, wide, i) {
+            let position = offset + i;
+            bytes[position / 8] |= 0x01 << (position % 8);
+        }
+        i += 1;
+    }
+    bytes
+}
+
+/// Sets the `bits` least significant bits of the bit pattern given by `value` and the
+/// little endian `wide` bytes at the bit `offset` of `bytes` using the bit order of
+/// `write_specifier_be`.
+///
+/// Used to store the `Specifier::DEFAULT_BYTES` of specifiers wider than 128 bits.
+#[doc(hidden)]
+pub const fn set_wide_bits_be<const N: usize>(
+    mut bytes: [u8; N],
+    offset: usize,
+    bits: usize,
+    value: u128,
+    wide: &[u8],
+) -> [u8; N] {
+    let mut i = 0;
+    while i < bits {
+        if bit_of(value, wide, i) {
+            let position = offset + bits - 1 - i;
+            bytes[position / 8] |= 0x80 >> (position % 8);
+        }
+        i += 1;
+    }
+    bytes
+}
+
+/// Returns the little endian integer represented by `bytes`, truncated to 128 bits.
+///
+/// Used to compute `Specifier::DEFAULT_BIT_PATTERN` of `#[bitfield]` specifiers in constant contexts.
+#[doc(hidden)]
+pub const fn bit_pattern_from_le_bytes<const N: usize>(bytes: [u8; N]) -> u128 {
+    let mut pattern = 0_u128;
+    let mut i = 0;
+    while i < N && i < 16 {
+        pattern |= (bytes[i] as u128) << (i * 8);
+        i += 1;
+    }
+    pattern
+}
+
+/// Returns the number of bits that can be processed at the bit `position` of a byte
+/// if `remaining` bits are left to process.
+const fn bits_in_byte(position: usize, remaining: usize) -> usize {
+    let available = 8 - position % 8;
+    if available < remaining {
+        available
+    } else {
+        remaining
+    }
+}
+
+/// Returns the `bits` wide value at the bit `offset` of `bytes`
+/// using the bit order of `read_specifier_le`.
+///
+/// Used by the `const fn` getters of `#[bitfield]` structs.
+#[doc(hidden)]
+#[inline]
+pub const fn read_bits_le(bytes: &[u8], offset: usize, bits: usize) -> u128 {
+    let mut value = 0_u128;
+    let mut read = 0;
+    while read < bits {
+        let position = offset + read;
+        let amount = bits_in_byte(position, bits - read);
+        let chunk = (bytes[position / 8] >> (position % 8)) as u128 & ((0x01 << amount) - 1);
+        value |= chunk << read;
+        read += amount;
+    }
+    value
+}
+
+/// Returns the `bits` wide value at the bit `offset` of `bytes`
+/// using the bit order of `read_specifier_be`.
+///
+/// Used by the `const fn` getters of `#[bitfield]` structs.
+#[doc(hidden)]
+#[inline]
+pub const fn read_bits_be(bytes: &[u8], offset: usize, bits: usize) -> u128 {
+    let mut value = 0_u128;
+    let mut read = 0;
+    while read < bits {
+        let position = offset + read;
+        let amount = bits_in_byte(position, bits - read);
+        let chunk = (bytes[position / 8] as u128 >> (8 - position % 8 - amount))
+            & ((0x01 << amount) - 1);
+        value = (value << amount) | chunk;
+        read += amount;
+    }
+    value
+}
+
+/// Replaces the `bits` wide value at the bit `offset` of `bytes` with the `bits`
+/// least significant bits of `value` using the bit order of `write_specifier_le`.
+///
+/// Used by the `const fn` setters of `#[bitfield]` structs.
+#[doc(hidden)]
+#[inline]
+pub const fn write_bits_le<const N: usize>(
+    mut bytes: [u8; N],
+    offset: usize,
+    bits: usize,
+    value: u128,
+) -> [u8; N] {
+    let mut written = 0;
+    while written < bits {
+        let position = offset + written;
+        let amount = bits_in_byte(position, bits - written);
+        let shift = position % 8;
+        let mask = (((0x01_u16 << amount) - 1) << shift) as u8;
+        let chunk = (((value >> written) as u16) << shift) as u8;
+        bytes[position / 8] = (bytes[position / 8] & !mask) | (chunk & mask);
+        written += amount;
+    }
+    bytes
+}
+
+/// Replaces the `bits` wide value at the bit `offset` of `bytes` with the `bits`
+/// least significant bits of `value` using the bit order of `write_specifier_be`.
+///
+/// Used by the `const fn` setters of `#[bitfield]` structs.
+#[doc(hidden)]
+#[inline]
+pub const fn write_bits_be<const N: usize>(
+    mut bytes: [u8; N],
+    offset: usize,
+    bits: usize,
+    value: u128,
+) -> [u8; N] {
+    let mut written = 0;
+    while written < bits {
+        let position = offset + written;
+        let amount = bits_in_byte(position, bits - written);
+        let shift = 8 - position % 8 - amount;
+        let mask = (((0x01_u16 << amount) - 1) << shift) as u8;
+        let chunk = (((value >> (bits - written - amount)) as u16) << shift) as u8;
+        bytes[position / 8] = (bytes[position / 8] & !mask) | (chunk & mask);
+        written += amount;
+    }
+    bytes
+}
+
+/// Returns `value` if it fits into `bits` bits.
+#[doc(hidden)]
+#[inline]
+pub const fn unsigned_bit_pattern(value: u128, bits: usize) -> Option<u128> {
+    if bits < 128 && value >> bits != 0 {
+        return None
+    }
+    Some(value)
+}
+
+/// Returns the `bits` wide two's complement bit pattern of `value` if it fits into `bits` bits.
+#[doc(hidden)]
+#[inline]
+pub const fn signed_bit_pattern(value: i128, bits: usize) -> Option<u128> {
+    if bits < 128 {
+        let min = -(0x01_i128 << (bits - 1));
+        let max = (0x01_i128 << (bits - 1)) - 1;
+        if value < min || value > max {
+            return None
+        }
+        return Some(value as u128 & ((0x01 << bits) - 1))
+    }
+    Some(value as u128)
+}
+
+/// How the `N` of a `#[default = N]` attribute is converted into a bit pattern.
+#[doc(hidden)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum DefaultValue {
+    /// `N` is the bit pattern itself.
+    BitPattern,
+    /// `N` is a two's complement signed value.
+    Signed,
+    /// `N` is a value within `min..=max` stored as its offset to `min`.
+    Range { min: i128, max: i128 },
+    /// `N` is the bit pattern of one of the `Specifier::VARIANTS`.
+    Variant,
+}
+
+/// Returns the bit pattern of the `#[default = N]` value `N` of a field of specifier `T`.
+///
+/// `N` is given by its absolute value and sign. Returns `None` if `N` is not a valid
+/// value of `T` according to its `Specifier::DEFAULT_VALUE`.
+#[doc(hidden)]
+#[inline]
+pub const fn default_bit_pattern<T>(negative: bool, magnitude: u128) -> Option<u128>
+where
+    T: Specifier,
+{
+    let signed = match (negative, magnitude) {
+        (true, magnitude) if magnitude <= i128::MIN.unsigned_abs() => {
+            Some((magnitude as i128).wrapping_neg())
+        }
+        (false, magnitude) if magnitude <= i128::MAX as u128 => Some(magnitude as i128),
+        _ => None,
+    };
+    let pattern = match (T::DEFAULT_VALUE, signed) {
+        (DefaultValue::BitPattern, _) if !negative => magnitude,
+        (DefaultValue::Signed, Some(value)) => {
+            match signed_bit_pattern(value, T::BITS) {
+                Some(pattern) => pattern,
+                None => return None,
+            }
+        }
+        (DefaultValue::Range { min, max }, Some(value)) if min <= value && value <= max => {
+            value.wrapping_sub(min) as u128
+        }
+        (DefaultValue::Variant, _) if !negative => {
+            let mut index = 0;
+            loop {
+                if index == T::VARIANTS.len() {
+                    return None
+                }
+                if T::VARIANTS[index].1 == magnitude {
+                    break magnitude
+                }
+                index += 1;
+            }
+        }
+        _ => return None,
+    };
+    unsigned_bit_pattern(pattern, T::BITS)
+}
+
+/// Sign extends the `bits` wide two's complement bit pattern `raw`.
+#[doc(hidden)]
+#[inline]
+pub const fn sign_extend(raw: u128, bits: usize) -> i128 {
+    ((raw << (128 - bits)) as i128) >> (128 - bits)
+}
+
+/// Returns `true` if the bits of `bytes` selected by `mask` equal those of `pattern`.
+#[doc(hidden)]
+#[inline]
+pub fn matches_masked(bytes: &[u8], mask: &[u8], pattern: &[u8]) -> bool {
+    bytes
+        .iter()
+        .zip(mask)
+        .zip(pattern)
+        .all(|((byte, mask), pattern)| byte & mask == *pattern)
+}
+
+/// Reverses the order of the bits within each of the given bytes.
+///
+/// Converts between the `lsb0` and `msb0` bit numbering of `#[bitfield]` structs.
+#[doc(hidden)]
+pub const fn reverse_bits_in_bytes<const N: usize>(mut bytes: [u8; N]) -> [u8; N] {
+    let mut i = 0;
+    while i < N {
+        bytes[i] = bytes[i].reverse_bits();
+        i += 1;
+    }
+    bytes
+}
+
+/// Returns the given specifier bytes as `u128`, truncating them if needed.
+#[doc(hidden)]
+#[inline]
+pub fn bytes_to_u128<T>(bytes: T) -> u128
+where
+    T: SpecifierBytesOps,
+{
+    let mut buffer = [0x00; 16];
+    bytes.write_le_slice(&mut buffer);
+    u128::from_le_bytes(buffer)
+}
+
+/// Converts the given raw bits into the `Bytes` of `T`.
+///
+/// Returns `None` if the raw bits do not fit into `T::BITS` bits.
+#[doc(hidden)]
+#[inline]
+pub fn bytes_from_u128<T>(raw: u128) -> Option<<T as Specifier>::Bytes>
+where
+    T: Specifier,
+    T::Bytes: SpecifierBytesOps,
+{
+    if raw.checked_shr(T::BITS as u32).unwrap_or(0) != 0 {
+        return None
+    }
+    Some(<T::Bytes as SpecifierBytesOps>::from_le_slice(&raw.to_le_bytes()))
+}
+
+/// Parses the given indexed field name of the form `name[index]` into its index.
+///
+/// Returns `None` if `field` does not name an element of the array field `name`.
+#[doc(hidden)]
+#[inline]
+pub fn parse_field_index(field: &str, name: &str) -> Option<usize> {
+    field
+        .strip_prefix(name)?
+        .strip_prefix('[')?
+        .strip_suffix(']')?
+        .parse()
+        .ok()
+}
+
+/// Returns a valid bit pattern of `T` for the given raw bits that fits into `bits` bits.
+///
+/// Used by specifiers that narrow the bit width of `T` via `#[bits = N]`.
+/// Tries the `bits` least significant raw bits and then successively halves them.
+#[doc(hidden)]
+#[inline]
+pub fn narrow_valid_bit_pattern<T>(raw: u128, bits: usize) -> Option<<T as Specifier>::Bytes>
+where
+    T: Specifier,
+    T::Bytes: SpecifierBytesOps,
+{
+    let mut raw = raw & (!0_u128).checked_shr(128 - bits as u32).unwrap_or(0);
+    loop {
+        if let Some(bytes) = T::valid_bit_pattern(raw) {
+            if bytes.fits_in_bits(bits) {
+                return Some(bytes)
+            }
+        }
+        if raw == 0 {
+            return None
+        }
+        raw >>= 1;
+    }
+}
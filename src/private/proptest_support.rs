@@ -0,0 +1,60 @@
+use crate::Specifier;
+use core::fmt::Debug;
+use proptest::{
+    arbitrary::any,
+    collection::vec,
+    strategy::{
+        BoxedStrategy,
+        Strategy,
+    },
+};
+
+/// Returns the strategy generating values of the specifier `T` from its valid bit patterns.
+///
+/// Shrinking the raw bits shrinks towards simpler bit patterns.
+#[doc(hidden)]
+pub fn proptest_specifier<T>() -> BoxedStrategy<T::InOut>
+where
+    T: Specifier + 'static,
+    T::InOut: Debug,
+{
+    any::<u128>()
+        .prop_filter_map("invalid bit pattern", |raw| {
+            T::from_bytes(T::valid_bit_pattern(raw)?).ok()
+        })
+        .boxed()
+}
+
+/// Returns the strategy generating `#[bitfield]` structs whose fields are set to valid bit patterns.
+///
+/// The given constructor requests the raw bits of up to `count` fields.
+#[doc(hidden)]
+pub fn proptest_fields<T>(
+    count: usize,
+    from_raw_fields: fn(&mut dyn FnMut(usize) -> u128) -> Option<T>,
+) -> BoxedStrategy<T>
+where
+    T: Debug + 'static,
+{
+    vec(any::<u128>(), count)
+        .prop_filter_map("invalid bit pattern", move |raws| {
+            let mut raws = raws.into_iter();
+            from_raw_fields(&mut |_| raws.next().unwrap_or(0))
+        })
+        .boxed()
+}
+
+/// Returns the strategy generating `#[bitfield]` structs from arbitrary bytes.
+#[doc(hidden)]
+pub fn proptest_bytes<T, const N: usize>(from_bytes: fn([u8; N]) -> T) -> BoxedStrategy<T>
+where
+    T: Debug + 'static,
+{
+    vec(any::<u8>(), N)
+        .prop_map(move |bytes| {
+            let mut array = [0x00_u8; N];
+            array.copy_from_slice(&bytes);
+            from_bytes(array)
+        })
+        .boxed()
+}
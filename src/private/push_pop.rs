@@ -2,6 +2,7 @@ use crate::private::{
     checks::private::Sealed,
     PopBits,
     PushBits,
+    SpecifierBytesOps,
 };
 
 /// A bit buffer that allows to pop bits from it.
@@ -24,7 +25,7 @@ impl PopBits for PopBuffer<u8> {
     fn pop_bits(&mut self, amount: u32) -> u8 {
         let Self { bytes } = self;
         let orig_ones = bytes.count_ones();
-        debug_assert!(1 <= amount && amount <= 8);
+        debug_assert!((1..=8).contains(&amount));
         let res = *bytes & ((0x01_u16.wrapping_shl(amount)).wrapping_sub(1) as u8);
         *bytes = bytes.checked_shr(amount).unwrap_or(0);
         debug_assert_eq!(res.count_ones() + bytes.count_ones(), orig_ones);
@@ -39,7 +40,7 @@ impl PopBits for PopBuffer<i8> {
     fn pop_bits(&mut self, amount: u32) -> u8 {
         let Self { bytes } = self;
         let orig_ones = bytes.count_ones();
-        debug_assert!(1 <= amount && amount <= 8);
+        debug_assert!((1..=8).contains(&amount));
         let res = (*bytes & ((0x01_i16.wrapping_shl(amount)).wrapping_sub(1) as i8)) as u8;
         *bytes = bytes.checked_shr(amount).unwrap_or(0);
         debug_assert_eq!(res.count_ones() + bytes.count_ones(), orig_ones);
@@ -82,6 +83,19 @@ macro_rules! impl_pop_bits {
 }
 impl_pop_bits!(u16, u32, u64, u128, i16, i32, i64, i128);
 
+impl<const N: usize> Sealed for PopBuffer<[u8; N]> {}
+
+impl<const N: usize> PopBits for PopBuffer<[u8; N]> {
+    #[inline]
+    fn pop_bits(&mut self, amount: u32) -> u8 {
+        let Self { bytes } = self;
+        debug_assert!((1..=8).contains(&amount));
+        let res = bytes[0] & (0xFF >> (8 - amount));
+        *bytes = bytes.shr_bits(amount as usize);
+        res
+    }
+}
+
 /// A bit buffer that allows to push bits onto it.
 pub struct PushBuffer<T> {
     bytes: T,
@@ -112,7 +126,7 @@ macro_rules! impl_push_bits {
                 fn push_bits(&mut self, amount: u32, bits: u8) {
                     let Self { bytes } = self;
                     let orig_ones = bytes.count_ones();
-                    debug_assert!(1 <= amount && amount <= 8);
+                    debug_assert!((1..=8).contains(&amount));
                     let bitmask = 0xFF >> (8 - amount as u8);
                     *bytes = bytes.wrapping_shl(amount) | ((bits & bitmask) as $type);
                     debug_assert_eq!((bits & bitmask).count_ones() + orig_ones, bytes.count_ones());
@@ -122,3 +136,22 @@ macro_rules! impl_push_bits {
     }
 }
 impl_push_bits!(u8, u16, u32, u64, u128, i8, i16, i32, i64, i128);
+
+impl<const N: usize> Sealed for PushBuffer<[u8; N]> {}
+
+impl<const N: usize> Default for PushBuffer<[u8; N]> {
+    #[inline]
+    fn default() -> Self {
+        Self { bytes: [0x00; N] }
+    }
+}
+
+impl<const N: usize> PushBits for PushBuffer<[u8; N]> {
+    #[inline]
+    fn push_bits(&mut self, amount: u32, bits: u8) {
+        let Self { bytes } = self;
+        debug_assert!((1..=8).contains(&amount));
+        *bytes = bytes.shl_bits(amount as usize);
+        bytes[0] |= bits & (0xFF >> (8 - amount));
+    }
+}
@@ -0,0 +1,201 @@
+use core::{
+    convert::{
+        TryFrom,
+        TryInto,
+    },
+    fmt::{
+        Formatter,
+        Result as FmtResult,
+    },
+    marker::PhantomData,
+};
+use serde::{
+    de::{
+        DeserializeSeed,
+        Error,
+        SeqAccess,
+        Unexpected,
+        Visitor,
+    },
+    ser::SerializeTuple,
+    Deserialize,
+    Deserializer,
+    Serialize,
+    Serializer,
+};
+
+/// Deserializes the name or position of a field of a `#[bitfield]` struct into its index.
+#[doc(hidden)]
+pub struct FieldIndex(pub &'static [&'static str]);
+
+impl<'de> DeserializeSeed<'de> for FieldIndex {
+    type Value = usize;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_identifier(self)
+    }
+}
+
+impl<'de> Visitor<'de> for FieldIndex {
+    type Value = usize;
+
+    fn expecting(&self, f: &mut Formatter) -> FmtResult {
+        f.write_str("a field identifier")
+    }
+
+    fn visit_u64<E>(self, value: u64) -> Result<Self::Value, E>
+    where
+        E: Error,
+    {
+        match usize::try_from(value) {
+            Ok(index) if index < self.0.len() => Ok(index),
+            _ => Err(E::invalid_value(Unexpected::Unsigned(value), &self)),
+        }
+    }
+
+    fn visit_str<E>(self, value: &str) -> Result<Self::Value, E>
+    where
+        E: Error,
+    {
+        self.0
+            .iter()
+            .position(|name| *name == value)
+            .ok_or_else(|| E::unknown_field(value, self.0))
+    }
+
+    fn visit_bytes<E>(self, value: &[u8]) -> Result<Self::Value, E>
+    where
+        E: Error,
+    {
+        match core::str::from_utf8(value) {
+            Ok(value) => self.visit_str(value),
+            Err(_) => Err(E::invalid_value(Unexpected::Bytes(value), &self)),
+        }
+    }
+}
+
+/// (De)serializes the elements of an array field of a `#[bitfield]` struct as a tuple.
+#[doc(hidden)]
+pub struct SerdeArray<T, const N: usize>(pub [T; N]);
+
+impl<T, const N: usize> Serialize for SerdeArray<T, N>
+where
+    T: Serialize,
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut tuple = serializer.serialize_tuple(N)?;
+        for element in &self.0 {
+            tuple.serialize_element(element)?;
+        }
+        tuple.end()
+    }
+}
+
+impl<'de, T, const N: usize> Deserialize<'de> for SerdeArray<T, N>
+where
+    T: Deserialize<'de>,
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        struct ArrayVisitor<T, const N: usize>(PhantomData<fn() -> T>);
+
+        impl<'de, T, const N: usize> Visitor<'de> for ArrayVisitor<T, N>
+        where
+            T: Deserialize<'de>,
+        {
+            type Value = [T; N];
+
+            fn expecting(&self, f: &mut Formatter) -> FmtResult {
+                write!(f, "an array of length {}", N)
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let mut elements: [Option<T>; N] = core::array::from_fn(|_| None);
+                for (index, element) in elements.iter_mut().enumerate() {
+                    *element = Some(
+                        seq.next_element()?
+                            .ok_or_else(|| A::Error::invalid_length(index, &self))?,
+                    );
+                }
+                if seq.next_element::<serde::de::IgnoredAny>()?.is_some() {
+                    return Err(A::Error::invalid_length(N + 1, &self))
+                }
+                Ok(elements.map(|element| {
+                    element.expect("all elements have been deserialized")
+                }))
+            }
+        }
+
+        deserializer
+            .deserialize_tuple(N, ArrayVisitor(PhantomData))
+            .map(Self)
+    }
+}
+
+/// (De)serializes the underlying byte array of a `#[bitfield]` struct.
+#[doc(hidden)]
+pub struct SerdeBytes<const N: usize>(pub [u8; N]);
+
+impl<const N: usize> Serialize for SerdeBytes<N> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_bytes(&self.0)
+    }
+}
+
+impl<'de, const N: usize> Deserialize<'de> for SerdeBytes<N> {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        struct BytesVisitor<const N: usize>;
+
+        impl<'de, const N: usize> Visitor<'de> for BytesVisitor<N> {
+            type Value = [u8; N];
+
+            fn expecting(&self, f: &mut Formatter) -> FmtResult {
+                write!(f, "{} bytes", N)
+            }
+
+            fn visit_bytes<E>(self, value: &[u8]) -> Result<Self::Value, E>
+            where
+                E: Error,
+            {
+                value
+                    .try_into()
+                    .map_err(|_| E::invalid_length(value.len(), &self))
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let mut bytes = [0x00_u8; N];
+                for (index, byte) in bytes.iter_mut().enumerate() {
+                    *byte = seq
+                        .next_element()?
+                        .ok_or_else(|| A::Error::invalid_length(index, &self))?;
+                }
+                if seq.next_element::<serde::de::IgnoredAny>()?.is_some() {
+                    return Err(A::Error::invalid_length(N + 1, &self))
+                }
+                Ok(bytes)
+            }
+        }
+
+        deserializer.deserialize_bytes(BytesVisitor).map(Self)
+    }
+}
@@ -1,4 +1,6 @@
 use super::checks;
+use crate::specifiers::Range;
+use core::fmt::Debug;
 
 /// Helper trait for underlying primitives handling of bitfields.
 ///
@@ -27,6 +29,290 @@ pub trait SpecifierBytes: checks::private::Sealed {
     type Bytes;
 }
 
+/// Operations on the `Bytes` of specifiers used by the generated getters and setters.
+///
+/// The `Bytes` are treated as unsigned little endian integers spanning all of their bits.
+/// This allows byte arrays to act as the `Bytes` of specifiers wider than 128 bits.
+#[doc(hidden)]
+pub trait SpecifierBytesOps: Sized {
+    /// Returns `true` if all bits at positions `bits` and above are zero.
+    fn fits_in_bits(&self, bits: usize) -> bool;
+
+    /// Shifts the bits by `amount` towards the most significant bit.
+    ///
+    /// Yields zero if `amount` is greater than or equal to the total amount of bits.
+    fn shl_bits(self, amount: usize) -> Self;
+
+    /// Shifts the bits by `amount` towards the least significant bit.
+    ///
+    /// Yields zero if `amount` is greater than or equal to the total amount of bits.
+    fn shr_bits(self, amount: usize) -> Self;
+
+    /// Creates the bytes from the given little endian bytes, zero extending or truncating them.
+    fn from_le_slice(bytes: &[u8]) -> Self;
+
+    /// Writes the bytes as little endian bytes into `bytes`, truncating them if needed.
+    fn write_le_slice(self, bytes: &mut [u8]);
+}
+
+macro_rules! impl_specifier_bytes_ops_for_prim {
+    ( $( ($prim:ty, $unsigned:ty) ),* $(,)? ) => {
+        $(
+            impl SpecifierBytesOps for $prim {
+                #[inline]
+                fn fits_in_bits(&self, bits: usize) -> bool {
+                    (*self as $unsigned).checked_shr(bits as u32).unwrap_or(0) == 0
+                }
+
+                #[inline]
+                fn shl_bits(self, amount: usize) -> Self {
+                    (self as $unsigned).checked_shl(amount as u32).unwrap_or(0) as $prim
+                }
+
+                #[inline]
+                fn shr_bits(self, amount: usize) -> Self {
+                    (self as $unsigned).checked_shr(amount as u32).unwrap_or(0) as $prim
+                }
+
+                #[inline]
+                fn from_le_slice(bytes: &[u8]) -> Self {
+                    let mut buffer = [0x00; core::mem::size_of::<$prim>()];
+                    let len = core::cmp::min(bytes.len(), buffer.len());
+                    buffer[..len].copy_from_slice(&bytes[..len]);
+                    <$prim>::from_le_bytes(buffer)
+                }
+
+                #[inline]
+                fn write_le_slice(self, bytes: &mut [u8]) {
+                    let buffer = self.to_le_bytes();
+                    let len = core::cmp::min(bytes.len(), buffer.len());
+                    bytes[..len].copy_from_slice(&buffer[..len]);
+                }
+            }
+        )*
+    };
+}
+impl_specifier_bytes_ops_for_prim!(
+    (u8, u8),
+    (u16, u16),
+    (u32, u32),
+    (u64, u64),
+    (u128, u128),
+    (i8, u8),
+    (i16, u16),
+    (i32, u32),
+    (i64, u64),
+    (i128, u128),
+);
+
+impl<const N: usize> SpecifierBytesOps for [u8; N] {
+    #[inline]
+    fn fits_in_bits(&self, bits: usize) -> bool {
+        let (index, offset) = (bits / 8, bits % 8);
+        index >= N || (self[index] >> offset == 0 && self[(index + 1)..].iter().all(|&byte| byte == 0))
+    }
+
+    #[inline]
+    fn shl_bits(self, amount: usize) -> Self {
+        let (bytes, bits) = (amount / 8, amount % 8);
+        let mut result = [0x00; N];
+        for (source, byte) in result.iter_mut().skip(bytes).enumerate() {
+            *byte = self[source] << bits;
+            if bits != 0 && source > 0 {
+                *byte |= self[source - 1] >> (8 - bits);
+            }
+        }
+        result
+    }
+
+    #[inline]
+    fn shr_bits(self, amount: usize) -> Self {
+        let (bytes, bits) = (amount / 8, amount % 8);
+        let mut result = [0x00; N];
+        for (index, byte) in result.iter_mut().take(N.saturating_sub(bytes)).enumerate() {
+            let source = index + bytes;
+            *byte = self[source] >> bits;
+            if bits != 0 && source + 1 < N {
+                *byte |= self[source + 1] << (8 - bits);
+            }
+        }
+        result
+    }
+
+    #[inline]
+    fn from_le_slice(bytes: &[u8]) -> Self {
+        let mut result = [0x00; N];
+        let len = core::cmp::min(bytes.len(), N);
+        result[..len].copy_from_slice(&bytes[..len]);
+        result
+    }
+
+    #[inline]
+    fn write_le_slice(self, bytes: &mut [u8]) {
+        let len = core::cmp::min(bytes.len(), N);
+        bytes[..len].copy_from_slice(&self[..len]);
+    }
+}
+
+/// Operations required on the `Bytes` of specifiers that are used as the
+/// type of generic fields of `#[bitfield]` structs.
+#[doc(hidden)]
+pub trait GenericSpecifierBytes: Debug + SpecifierBytesOps {}
+
+impl<T> GenericSpecifierBytes for T where T: Debug + SpecifierBytesOps {}
+
+/// Selects the `Bytes` of `#[bitfield]` struct specifiers depending on their bit width.
+///
+/// Implemented by `True` for bitfields wider than 128 bits selecting `Large`
+/// and by `False` selecting `Small` otherwise.
+#[doc(hidden)]
+pub trait SelectSpecifierBytes<Small, Large>: checks::private::Sealed {
+    type Bytes;
+}
+
+impl<Small, Large> SelectSpecifierBytes<Small, Large> for checks::True {
+    type Bytes = Large;
+}
+
+impl<Small, Large> SelectSpecifierBytes<Small, Large> for checks::False {
+    type Bytes = Small;
+}
+
+/// Primitive integers that can be used as the in-out type of `Range` specifiers.
+///
+/// # Note
+///
+/// Must not and cannot be implemented by dependencies.
+#[doc(hidden)]
+pub trait RangeValue: checks::private::Sealed + Copy {
+    /// The unsigned primitive of the same width storing the offset to the lower bound.
+    type Bytes;
+
+    /// The smallest value representable by `Self`.
+    const MIN: i128;
+    /// The largest value representable by `Self`.
+    const MAX: i128;
+
+    /// Converts `self` losslessly into an `i128`.
+    fn into_i128(self) -> i128;
+
+    /// Converts the given value into `Self`, truncating it if needed.
+    fn from_i128(value: i128) -> Self;
+
+    /// Converts the given offset into the bytes, truncating it if needed.
+    fn bytes_from_offset(offset: u128) -> Self::Bytes;
+
+    /// Converts the given bytes into the offset.
+    fn offset_from_bytes(bytes: Self::Bytes) -> u128;
+}
+
+macro_rules! impl_range_value_for_prim {
+    ( $( ($prim:ty, $unsigned:ty) ),* $(,)? ) => {
+        $(
+            impl RangeValue for $prim {
+                type Bytes = $unsigned;
+
+                const MIN: i128 = <$prim>::MIN as i128;
+                const MAX: i128 = <$prim>::MAX as i128;
+
+                #[inline]
+                fn into_i128(self) -> i128 {
+                    self as i128
+                }
+
+                #[inline]
+                fn from_i128(value: i128) -> Self {
+                    value as $prim
+                }
+
+                #[inline]
+                fn bytes_from_offset(offset: u128) -> Self::Bytes {
+                    offset as $unsigned
+                }
+
+                #[inline]
+                fn offset_from_bytes(bytes: Self::Bytes) -> u128 {
+                    bytes as u128
+                }
+            }
+        )*
+    };
+}
+impl_range_value_for_prim!(
+    (u8, u8),
+    (u16, u16),
+    (u32, u32),
+    (u64, u64),
+    (i8, u8),
+    (i16, u16),
+    (i32, u32),
+    (i64, u64),
+);
+
+/// Newtypes `struct Foo(F)` deriving `BitfieldSpecifier`.
+#[doc(hidden)]
+pub trait Newtype<F> {
+    /// Wraps the given field into the newtype.
+    fn from_field(field: F) -> Self;
+
+    /// Unwraps the field of the newtype.
+    fn into_field(self) -> F;
+}
+
+/// Selects the in-out type of a newtype `N` deriving `BitfieldSpecifier` over the
+/// specifier `F` whose in-out type is `Self`.
+///
+/// # Note
+///
+/// If `F` is its own in-out type the newtype is the in-out type of its `Specifier`
+/// impl. Otherwise, e.g. for the uninhabited `B1`, .. `B128` or `S1`, .. `S128`
+/// specifiers, the newtype names `F` and shares its in-out type.
+#[doc(hidden)]
+pub trait NewtypeInOut<F, N> {
+    /// The in-out type of the newtype.
+    type InOut;
+
+    /// Converts the in-out value of `F` into the in-out value of the newtype.
+    fn wrap(value: Self) -> Self::InOut;
+
+    /// Converts the in-out value of the newtype into the in-out value of `F`.
+    fn unwrap(value: Self::InOut) -> Self;
+}
+
+impl<F, N> NewtypeInOut<F, N> for F
+where
+    N: Newtype<F>,
+{
+    type InOut = N;
+
+    #[inline]
+    fn wrap(value: Self) -> Self::InOut {
+        N::from_field(value)
+    }
+
+    #[inline]
+    fn unwrap(value: Self::InOut) -> Self {
+        value.into_field()
+    }
+}
+
+impl<T, N, const MIN: i128, const MAX: i128> NewtypeInOut<Range<T, MIN, MAX>, N> for T
+where
+    T: RangeValue,
+{
+    type InOut = T;
+
+    #[inline]
+    fn wrap(value: Self) -> Self::InOut {
+        value
+    }
+
+    #[inline]
+    fn unwrap(value: Self::InOut) -> Self {
+        value
+    }
+}
+
 pub trait IsU8Compatible: checks::private::Sealed {}
 pub trait IsU16Compatible: checks::private::Sealed {}
 pub trait IsU32Compatible: checks::private::Sealed {}
@@ -0,0 +1,64 @@
+// Tests the signed `S1`, `S2`, ..., `S128` specifiers and their sign extension.
+
+use modular_bitfield::error::OutOfBounds;
+use modular_bitfield::prelude::*;
+
+#[bitfield]
+pub struct SensorFrame {
+    temperature: S12,
+    pressure: S21,
+    flag: bool,
+    full: S8,
+    wide: S86,
+}
+
+fn main() {
+    let mut frame = SensorFrame::new();
+
+    // Everything is initialized to zero.
+    assert_eq!(frame.temperature(), 0);
+    assert_eq!(frame.pressure(), 0);
+    assert_eq!(frame.full(), 0);
+    assert_eq!(frame.wide(), 0);
+
+    // Negative values are sign extended when read back.
+    frame.set_temperature(-1);
+    frame.set_pressure(-1_048_576);
+    frame.set_full(i8::MIN);
+    frame.set_wide(-42);
+    assert_eq!(frame.temperature(), -1);
+    assert_eq!(frame.pressure(), -1_048_576);
+    assert!(!frame.flag());
+    assert_eq!(frame.full(), i8::MIN);
+    assert_eq!(frame.wide(), -42);
+
+    // The full signed range is accepted.
+    assert_eq!(frame.set_temperature_checked(-2048), Ok(()));
+    assert_eq!(frame.temperature(), -2048);
+    assert_eq!(frame.set_temperature_checked(2047), Ok(()));
+    assert_eq!(frame.temperature(), 2047);
+    assert_eq!(frame.set_pressure_checked(1_048_575), Ok(()));
+    assert_eq!(frame.pressure(), 1_048_575);
+
+    // Values outside of the signed range are rejected.
+    assert_eq!(frame.set_temperature_checked(-2049), Err(OutOfBounds));
+    assert_eq!(frame.set_temperature_checked(2048), Err(OutOfBounds));
+    assert_eq!(frame.set_pressure_checked(-1_048_577), Err(OutOfBounds));
+    assert_eq!(frame.set_pressure_checked(1_048_576), Err(OutOfBounds));
+
+    // Failed setters leave the neighbouring fields untouched.
+    assert_eq!(frame.temperature(), 2047);
+    assert_eq!(frame.pressure(), 1_048_575);
+    assert_eq!(frame.full(), i8::MIN);
+
+    // Only the 12 bits of `temperature` are set for `-1`.
+    let bytes = SensorFrame::new().with_temperature(-1).into_bytes();
+    assert_eq!(bytes[0], 0xFF);
+    assert_eq!(bytes[1], 0x0F);
+
+    // A single signed bit covers `-1..=0`.
+    assert_eq!(<S1 as Specifier>::into_bytes(-1), Ok(1));
+    assert_eq!(<S1 as Specifier>::from_bytes(1), Ok(-1));
+    assert_eq!(<S1 as Specifier>::into_bytes(1), Err(OutOfBounds));
+    assert_eq!(<S128 as Specifier>::from_bytes(u128::MAX), Ok(-1));
+}
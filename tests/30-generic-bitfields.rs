@@ -0,0 +1,89 @@
+// Tests generic `#[bitfield]` structs that are parameterized over their field specifiers.
+
+use modular_bitfield::prelude::*;
+
+#[derive(BitfieldSpecifier, Debug, PartialEq, Eq, Copy, Clone)]
+pub enum Kind {
+    Data,
+    Ack,
+    Ping,
+    Pong,
+}
+
+#[derive(BitfieldSpecifier, Debug, PartialEq, Eq, Copy, Clone)]
+#[bits = 4]
+pub enum Opcode {
+    Read = 0,
+    Write = 1,
+    Erase = 15,
+}
+
+#[bitfield(bits = 16)]
+#[derive(Debug)]
+pub struct Header<K: Specifier> {
+    kind: K,
+    len: B12,
+    #[skip]
+    __: B2,
+}
+
+#[bitfield(bits = 16, filled = false)]
+#[derive(BitfieldSpecifier)]
+pub struct Partial<K>
+where
+    K: Specifier,
+{
+    #[bits = 4]
+    kind: K,
+    flag: bool,
+}
+
+#[bitfield(bits = 32)]
+#[repr(u32)]
+pub struct Pair<A: Specifier, B: Specifier> {
+    a: A,
+    b: B,
+    rest: B16,
+}
+
+#[bitfield(bits = 16, endian = "big")]
+pub struct BigHeader<K: Specifier> {
+    kind: K,
+    len: B14,
+}
+
+fn main() {
+    // The same struct definition with different bit widths.
+    let mut header = Header::<Kind>::new().with_len(42);
+    header.set_kind(Kind::Pong);
+    assert_eq!(header.kind(), Kind::Pong);
+    assert_eq!(header.len(), 42);
+    assert_eq!(
+        format!("{:?}", header),
+        "Header { kind: Pong, len: 42 }",
+    );
+
+    let header = Header::<B2>::from_bytes([0b1010_1011, 0b0000_0010]);
+    assert_eq!(header.kind(), 0b11);
+    assert_eq!(header.len(), 0b10_1010_10);
+    assert!(header.with_kind_checked(4).is_err());
+
+    // Generic fields with the `#[bits = N]` attribute and as specifiers themselves.
+    let partial = Partial::<Opcode>::new().with_kind(Opcode::Erase).with_flag(true);
+    assert_eq!(partial.kind(), Opcode::Erase);
+    assert!(partial.flag());
+    assert_eq!(<Partial<Opcode> as Specifier>::BITS, 16);
+
+    // Multiple type parameters and `#[repr(uN)]` conversions.
+    let pair = Pair::<B4, B12>::new().with_a(0xF).with_b(0xABC).with_rest(0x1234);
+    assert_eq!(u32::from(pair), 0x1234_ABCF);
+    let pair = Pair::<u8, u8>::from(0x1234_ABCF_u32);
+    assert_eq!(pair.a(), 0xCF);
+    assert_eq!(pair.b(), 0xAB);
+    assert_eq!(pair.rest(), 0x1234);
+
+    // Generic fields in big endian layout.
+    let big = BigHeader::<Kind>::new().with_kind(Kind::Ack).with_len(0x2ABC);
+    assert_eq!(big.kind(), Kind::Ack);
+    assert_eq!(big.len(), 0x2ABC);
+}
@@ -0,0 +1,9 @@
+use modular_bitfield::prelude::*;
+
+#[bitfield]
+pub struct Header<K: Specifier> {
+    kind: K,
+    len: B12,
+}
+
+fn main() {}
@@ -0,0 +1,12 @@
+use modular_bitfield::prelude::*;
+
+#[bitfield(bits = 16)]
+pub struct Header<K: Specifier> {
+    kind: K,
+    len: B12,
+}
+
+fn main() {
+    // `B2` only fills 14 of the 16 bits.
+    let _ = Header::<B2>::new();
+}
@@ -0,0 +1,74 @@
+// Tests array fields such as `[B4; 8]` and their indexed and whole-array accessors.
+
+use modular_bitfield::error::OutOfBounds;
+use modular_bitfield::prelude::*;
+
+#[derive(BitfieldSpecifier, Debug, PartialEq, Eq, Copy, Clone)]
+pub enum Mode {
+    Off,
+    Low,
+    High,
+    Auto,
+}
+
+#[bitfield]
+#[derive(Debug)]
+pub struct Lanes {
+    enabled: bool,
+    lanes: [B4; 8],
+    #[bits = 6]
+    modes: [Mode; 3],
+    tail: B1,
+}
+
+#[bitfield(endian = "big")]
+pub struct BigLanes {
+    lanes: [B12; 2],
+}
+
+#[bitfield]
+pub struct Tuple(B4, [B2; 2]);
+
+fn main() {
+    assert_eq!(core::mem::size_of::<Lanes>(), 5);
+
+    let mut lanes = Lanes::new().with_enabled(true).with_lanes(3, 0xA);
+    assert_eq!(lanes.lanes(3), 0xA);
+    assert_eq!(lanes.lanes_array(), [0, 0, 0, 0xA, 0, 0, 0, 0]);
+
+    // Indexed setters only touch their own element.
+    for i in 0..8 {
+        lanes.set_lanes(i, i as u8 + 1);
+    }
+    assert_eq!(lanes.lanes_array(), [1, 2, 3, 4, 5, 6, 7, 8]);
+    assert!(lanes.enabled());
+    assert_eq!(lanes.tail(), 0);
+    assert_eq!(lanes.set_lanes_checked(0, 0x10), Err(OutOfBounds));
+    assert_eq!(lanes.lanes(0), 1);
+
+    // Whole-array setters.
+    lanes.set_modes_array([Mode::Auto, Mode::Off, Mode::High]);
+    assert_eq!(lanes.modes(0), Mode::Auto);
+    assert_eq!(lanes.modes(2), Mode::High);
+    let lanes = lanes.with_lanes_array([8, 7, 6, 5, 4, 3, 2, 1]).with_tail(1);
+    assert_eq!(lanes.lanes_array(), [8, 7, 6, 5, 4, 3, 2, 1]);
+    assert_eq!(
+        format!("{:?}", lanes),
+        "Lanes { enabled: true, lanes: [8, 7, 6, 5, 4, 3, 2, 1], modes: [Auto, Off, High], tail: 1 }",
+    );
+
+    // A failing whole-array setter leaves the bitfield unchanged.
+    let mut lanes = lanes;
+    assert_eq!(
+        lanes.set_lanes_array_checked([0, 0, 0, 0, 0, 0, 0, 0x10]),
+        Err(OutOfBounds),
+    );
+    assert_eq!(lanes.lanes_array(), [8, 7, 6, 5, 4, 3, 2, 1]);
+
+    let big = BigLanes::new().with_lanes(0, 0xABC).with_lanes(1, 0x123);
+    assert_eq!(big.lanes_array(), [0xABC, 0x123]);
+
+    let tuple = Tuple::new().with_0(0xF).with_1(1, 0b10);
+    assert_eq!(tuple.get_1_array(), [0, 0b10]);
+    assert_eq!(tuple.into_bytes(), [0b1000_1111]);
+}
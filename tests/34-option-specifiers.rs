@@ -0,0 +1,97 @@
+// Tests `Option<T>` fields that encode `None` with an unused bit pattern of `T`
+// or with an explicit `#[none = N]` bit pattern.
+
+use modular_bitfield::error::OutOfBounds;
+use modular_bitfield::prelude::*;
+
+#[derive(BitfieldSpecifier, Debug, PartialEq, Eq, Copy, Clone)]
+#[bits = 2]
+pub enum Speed {
+    Slow = 0,
+    Fast = 1,
+    Turbo = 3,
+}
+
+/// Enums of 64 bits and more leave bit patterns unused for any number of variants.
+#[derive(BitfieldSpecifier, Debug, PartialEq, Eq, Copy, Clone)]
+#[bits = 64]
+#[repr(u64)]
+pub enum Wide {
+    Low = 0,
+    High = 1,
+    Max = 0xFFFF_FFFF_FFFF_FFFF,
+}
+
+#[derive(BitfieldSpecifier, Debug, PartialEq, Eq, Copy, Clone)]
+#[bits = 72]
+pub enum Wider {
+    Zero = 0,
+    Two = 2,
+}
+
+#[bitfield]
+#[derive(Debug)]
+pub struct WideConfig {
+    wide: Option<Wide>,
+    wider: Option<Wider>,
+}
+
+#[bitfield]
+#[derive(Debug)]
+pub struct Config {
+    speed: Option<Speed>,
+    #[none = 0b111]
+    level: Option<B3>,
+    #[none = 0]
+    id: Option<u8>,
+    rest: B3,
+}
+
+fn main() {
+    assert_eq!(<Speed as NicheSpecifier>::NICHE, 2);
+    assert_eq!(<Option<Speed> as Specifier>::BITS, 2);
+
+    let mut config = Config::new();
+    assert_eq!(config.speed(), Some(Speed::Slow));
+    assert_eq!(config.level(), Some(0));
+    assert_eq!(config.id(), None);
+
+    config.set_speed(None);
+    config.set_level(None);
+    config.set_id(Some(42));
+    assert_eq!(config.speed(), None);
+    assert_eq!(config.level(), None);
+    assert_eq!(config.id(), Some(42));
+    let bytes = config.into_bytes();
+    assert_eq!(bytes, [0b0101_1110, 0b0000_0101]);
+    let mut config = Config::from_bytes(bytes);
+
+    config.set_speed(Some(Speed::Turbo));
+    config.set_level(Some(0b110));
+    assert_eq!(config.speed(), Some(Speed::Turbo));
+    assert_eq!(config.level(), Some(0b110));
+
+    // `Some` must not collide with the bit pattern reserved for `None`.
+    assert_eq!(config.set_level_checked(Some(0b111)), Err(OutOfBounds));
+    assert_eq!(config.set_id_checked(Some(0)), Err(OutOfBounds));
+    assert_eq!(config.level(), Some(0b110));
+    assert_eq!(config.id(), Some(42));
+
+    assert_eq!(<Wide as NicheSpecifier>::NICHE, 2);
+    assert_eq!(<Wider as NicheSpecifier>::NICHE, 1);
+    assert_eq!(<Option<Wide> as Specifier>::BITS, 64);
+    let mut wide = WideConfig::new();
+    assert_eq!(wide.wide(), Some(Wide::Low));
+    assert_eq!(wide.wider(), Some(Wider::Zero));
+    wide.set_wide(None);
+    wide.set_wider(None);
+    assert_eq!(wide.wide(), None);
+    assert_eq!(wide.wider(), None);
+    let bytes = wide.into_bytes();
+    assert_eq!(bytes[..9], [2, 0, 0, 0, 0, 0, 0, 0, 1]);
+    let mut wide = WideConfig::from_bytes(bytes);
+    wide.set_wide(Some(Wide::Max));
+    wide.set_wider(Some(Wider::Two));
+    assert_eq!(wide.wide(), Some(Wide::Max));
+    assert_eq!(wide.wider(), Some(Wider::Two));
+}
@@ -0,0 +1,10 @@
+use modular_bitfield::prelude::*;
+
+#[bitfield]
+pub struct Config {
+    #[none = 0b111]
+    level: B3,
+    rest: B5,
+}
+
+fn main() {}
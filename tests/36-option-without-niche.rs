@@ -0,0 +1,9 @@
+use modular_bitfield::prelude::*;
+
+#[bitfield]
+pub struct Config {
+    level: Option<B3>,
+    rest: B5,
+}
+
+fn main() {}
@@ -0,0 +1,47 @@
+// Tests `Range<T, MIN, MAX>` specifiers that store the offset of a value to `MIN`.
+
+use modular_bitfield::error::OutOfBounds;
+use modular_bitfield::prelude::*;
+
+#[bitfield]
+#[derive(Debug)]
+pub struct Burst {
+    length: Range<u8, 1, 16>,
+    temperature: Range<i16, -40, 215>,
+    port: Range<u16, 1024, 1535>,
+    rest: B3,
+}
+
+fn main() {
+    assert_eq!(<Range<u8, 1, 16> as Specifier>::BITS, 4);
+    assert_eq!(<Range<i16, -40, 215> as Specifier>::BITS, 8);
+    assert_eq!(<Range<u16, 1024, 1535> as Specifier>::BITS, 9);
+    assert_eq!(<Range<i64, { i64::MIN as i128 }, { i64::MAX as i128 }> as Specifier>::BITS, 64);
+
+    let mut burst = Burst::new();
+    assert_eq!(burst.length(), 1);
+    assert_eq!(burst.temperature(), -40);
+    assert_eq!(burst.port(), 1024);
+
+    burst.set_length(16);
+    burst.set_temperature(-1);
+    burst.set_port(1535);
+    assert_eq!(burst.length(), 16);
+    assert_eq!(burst.temperature(), -1);
+    assert_eq!(burst.port(), 1535);
+
+    assert_eq!(burst.set_length_checked(0), Err(OutOfBounds));
+    assert_eq!(burst.set_length_checked(17), Err(OutOfBounds));
+    assert_eq!(burst.set_temperature_checked(-41), Err(OutOfBounds));
+    assert_eq!(burst.set_temperature_checked(216), Err(OutOfBounds));
+    assert_eq!(burst.set_port_checked(1023), Err(OutOfBounds));
+    assert_eq!(burst.length(), 16);
+    assert_eq!(burst.temperature(), -1);
+    assert_eq!(burst.port(), 1535);
+
+    // Offsets above `MAX - MIN` are invalid bit patterns.
+    assert_eq!(
+        <Range<u8, 1, 10> as Specifier>::from_bytes(0b1111),
+        Err(modular_bitfield::error::InvalidBitPattern::new(0b1111))
+    );
+}
@@ -0,0 +1,9 @@
+use modular_bitfield::prelude::*;
+
+#[bitfield]
+pub struct Burst {
+    length: Range<u8, 1, 256>,
+    rest: B7,
+}
+
+fn main() {}
@@ -0,0 +1,57 @@
+// Tests fields pinned to explicit bit positions with `#[at = N]` and `#[bits(N..=M)]`.
+
+use modular_bitfield::prelude::*;
+
+#[bitfield]
+#[derive(Debug)]
+pub struct Status {
+    ready: bool,
+    #[at = 4]
+    mode: B2,
+    #[bits(12..=15)]
+    channel: B4,
+    #[bits(16..24)]
+    counter: u8,
+}
+
+#[bitfield(bits = 16)]
+pub struct Control<K: Specifier> {
+    #[at = 2]
+    kind: K,
+    #[at = 12]
+    level: B4,
+}
+
+#[bitfield(endian = "big")]
+pub struct BigStatus {
+    ready: bool,
+    #[at = 3]
+    value: B5,
+    #[at = 12]
+    channel: B4,
+}
+
+fn main() {
+    assert_eq!(core::mem::size_of::<Status>(), 3);
+
+    let status = Status::new()
+        .with_ready(true)
+        .with_mode(0b11)
+        .with_channel(0b1010)
+        .with_counter(0xFF);
+    assert_eq!(status.ready(), true);
+    assert_eq!(status.mode(), 0b11);
+    assert_eq!(status.channel(), 0b1010);
+    assert_eq!(status.counter(), 0xFF);
+    assert_eq!(status.into_bytes(), [0b0011_0001, 0b1010_0000, 0xFF]);
+
+    let control = Control::<B3>::new().with_kind(0b101).with_level(0xF);
+    assert_eq!(control.kind(), 0b101);
+    assert_eq!(control.level(), 0xF);
+    assert_eq!(control.into_bytes(), [0b0001_0100, 0b1111_0000]);
+
+    let big = BigStatus::new().with_ready(true).with_value(0b1_0001).with_channel(0b1001);
+    assert_eq!(big.ready(), true);
+    assert_eq!(big.value(), 0b1_0001);
+    assert_eq!(big.channel(), 0b1001);
+}
@@ -0,0 +1,12 @@
+use modular_bitfield::prelude::*;
+
+#[bitfield]
+pub struct Status {
+    mode: B6,
+    #[at = 4]
+    channel: B4,
+    #[at = 10]
+    rest: B6,
+}
+
+fn main() {}
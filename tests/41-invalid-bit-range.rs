@@ -0,0 +1,10 @@
+use modular_bitfield::prelude::*;
+
+#[bitfield]
+pub struct Status {
+    #[bits(4..4)]
+    mode: B4,
+    rest: B4,
+}
+
+fn main() {}
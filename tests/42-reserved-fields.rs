@@ -0,0 +1,74 @@
+// Tests `#[reserved = N]` fields that are initialized to and checked against mandated values.
+
+use modular_bitfield::error::{InvalidBitPattern, OutOfBounds};
+use modular_bitfield::prelude::*;
+
+#[bitfield]
+#[derive(Debug)]
+pub struct Register {
+    enable: bool,
+    #[reserved = 0b10]
+    reserved: B2,
+    mode: B5,
+    #[skip(getters)]
+    #[reserved = 0xA5]
+    __: u8,
+}
+
+#[bitfield(filled = false)]
+#[derive(BitfieldSpecifier)]
+pub struct Unfilled {
+    value: B6,
+    #[bits(8..=11)]
+    #[reserved = 0b1001]
+    marker: B4,
+}
+
+#[bitfield(endian = "big")]
+pub struct BigRegister {
+    #[reserved = 0b101]
+    reserved: B3,
+    value: B13,
+}
+
+#[bitfield(bits = 8)]
+pub struct Generic<K: Specifier> {
+    kind: K,
+    #[reserved = 0b11]
+    reserved: B2,
+}
+
+fn main() {
+    let register = Register::new();
+    assert_eq!(register.reserved(), 0b10);
+    assert_eq!(register.into_bytes(), [0b0000_0100, 0xA5]);
+
+    let register = Register::new().with_enable(true).with_mode(0b11111);
+    assert_eq!(register.reserved(), 0b10);
+    assert!(Register::from_bytes_checked(register.into_bytes()).is_ok());
+    assert_eq!(Register::from_bytes_checked([0b0000_0000, 0xA5]).map(|_| ()), Err(OutOfBounds));
+    assert_eq!(Register::from_bytes_checked([0b0000_0100, 0xA4]).map(|_| ()), Err(OutOfBounds));
+    // `from_bytes` of filled bitfields stays infallible.
+    assert_eq!(Register::from_bytes([0x00, 0x00]).reserved(), 0b00);
+
+    let unfilled = Unfilled::new().with_value(0b11_1111);
+    assert_eq!(unfilled.marker(), 0b1001);
+    assert_eq!(unfilled.into_bytes(), [0b0011_1111, 0b0000_1001]);
+    assert!(Unfilled::from_bytes([0b0011_1111, 0b0000_1001]).is_ok());
+    assert_eq!(Unfilled::from_bytes([0b0011_1111, 0b0000_1000]).map(|_| ()), Err(OutOfBounds));
+    assert!(<Unfilled as Specifier>::from_bytes(0b1001_0000_0000).is_ok());
+    assert_eq!(
+        <Unfilled as Specifier>::from_bytes(0b1000_0000_0000).map(|_| ()),
+        Err(InvalidBitPattern::new(0b1000_0000_0000))
+    );
+
+    let big = BigRegister::new().with_value(0);
+    assert_eq!(big.reserved(), 0b101);
+    assert_eq!(big.into_bytes(), [0b1010_0000, 0x00]);
+    assert!(BigRegister::from_bytes_checked([0b1011_1111, 0xFF]).is_ok());
+    assert!(BigRegister::from_bytes_checked([0b0101_1111, 0xFF]).is_err());
+
+    let generic = Generic::<B6>::new().with_kind(0b10_1010);
+    assert_eq!(generic.reserved(), 0b11);
+    assert_eq!(generic.into_bytes(), [0b1110_1010]);
+}
@@ -0,0 +1,13 @@
+use modular_bitfield::prelude::*;
+
+#[bitfield]
+pub struct Register {
+    enable: bool,
+    #[reserved = 0b10]
+    reserved: B7,
+}
+
+fn main() {
+    let mut register = Register::new();
+    register.set_reserved(0);
+}
@@ -0,0 +1,11 @@
+use modular_bitfield::prelude::*;
+
+#[bitfield]
+pub struct Register {
+    enable: bool,
+    #[reserved = 0b100]
+    reserved: B2,
+    mode: B5,
+}
+
+fn main() {}
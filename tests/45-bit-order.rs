@@ -0,0 +1,102 @@
+// Tests the `bit_order` parameter allocating fields from the most or least significant bit.
+
+use modular_bitfield::error::OutOfBounds;
+use modular_bitfield::prelude::*;
+
+/// Network RFC style layout, implies `endian = "big"`.
+#[bitfield(bit_order = "msb0")]
+pub struct Ipv4Start {
+    version: B4,
+    ihl: B4,
+    dscp: B6,
+    ecn: B2,
+    total_length: u16,
+}
+
+#[bitfield(bit_order = "msb0", endian = "little")]
+pub struct MsbLittle {
+    a: bool,
+    b: bool,
+    c: B6,
+}
+
+#[bitfield(bit_order = "lsb0", endian = "big")]
+pub struct LsbBig {
+    a: bool,
+    b: B7,
+}
+
+#[bitfield(bit_order = "lsb0")]
+pub struct LsbDefault {
+    a: bool,
+    b: B7,
+}
+
+#[bitfield(bit_order = "msb0", endian = "native")]
+#[repr(u16)]
+pub struct MsbNative {
+    a: bool,
+    b: B15,
+}
+
+#[bitfield(bit_order = "msb0", filled = false)]
+pub struct Unfilled {
+    a: bool,
+    b: B6,
+}
+
+#[bitfield(bit_order = "msb0", endian = "little")]
+pub struct Reserved {
+    a: bool,
+    #[reserved = 0b01]
+    r: B2,
+    b: B5,
+}
+
+fn main() {
+    let header = Ipv4Start::new()
+        .with_version(4)
+        .with_ihl(5)
+        .with_dscp(0b10_1110)
+        .with_ecn(0b01)
+        .with_total_length(0x1234);
+    assert_eq!(header.into_bytes(), [0x45, 0b1011_1001, 0x12, 0x34]);
+    let header = Ipv4Start::from_bytes([0x45, 0b1011_1001, 0x12, 0x34]);
+    assert_eq!(header.version(), 4);
+    assert_eq!(header.ihl(), 5);
+    assert_eq!(header.dscp(), 0b10_1110);
+    assert_eq!(header.ecn(), 0b01);
+    assert_eq!(header.total_length(), 0x1234);
+
+    let msb_little = MsbLittle::new().with_a(true).with_c(1);
+    assert_eq!(msb_little.into_bytes(), [0b1010_0000]);
+    let msb_little = MsbLittle::from_bytes([0b0110_0000]);
+    assert!(!msb_little.a());
+    assert!(msb_little.b());
+    assert_eq!(msb_little.c(), 1);
+
+    let lsb_big = LsbBig::new().with_a(true).with_b(1);
+    assert_eq!(lsb_big.into_bytes(), [0b1000_0001]);
+    assert_eq!(LsbBig::from_bytes([0b1000_0000]).b(), 1);
+
+    let lsb_default = LsbDefault::new().with_a(true).with_b(1);
+    assert_eq!(lsb_default.into_bytes(), [0b0000_0011]);
+
+    let msb_native = MsbNative::new().with_a(true).with_b(1);
+    let bytes = msb_native.into_bytes();
+    let msb_native = MsbNative::from(u16::from_le_bytes(bytes));
+    assert!(msb_native.a());
+    assert_eq!(msb_native.b(), 1);
+    assert_eq!(u16::from(msb_native), u16::from_le_bytes(bytes));
+    assert_eq!(bytes[0] & 0b1000_0000, 0b1000_0000);
+
+    let unfilled = Unfilled::from_bytes([0b1000_0010]).unwrap();
+    assert!(unfilled.a());
+    assert_eq!(unfilled.b(), 1);
+    assert_eq!(Unfilled::from_bytes([0b0000_0001]).map(|_| ()), Err(OutOfBounds));
+
+    let reserved = Reserved::new().with_a(true);
+    assert_eq!(reserved.into_bytes(), [0b1100_0000]);
+    assert!(Reserved::from_bytes_checked([0b0100_0000]).is_ok());
+    assert_eq!(Reserved::from_bytes_checked([0b0010_0000]).map(|_| ()), Err(OutOfBounds));
+}
@@ -0,0 +1,8 @@
+use modular_bitfield::prelude::*;
+
+#[bitfield(bit_order = "msb")]
+pub struct Invalid {
+    a: B8,
+}
+
+fn main() {}
@@ -0,0 +1,86 @@
+// Tests the generated `validate` and `from_bytes_validated` methods reporting all invalid fields.
+
+use modular_bitfield::prelude::*;
+
+#[derive(BitfieldSpecifier, Debug, PartialEq, Eq)]
+#[bits = 2]
+pub enum Status {
+    Red,
+    Green,
+    Yellow,
+}
+
+#[bitfield]
+#[derive(Debug)]
+pub struct Packet {
+    status: Status,
+    #[reserved = 0b01]
+    reserved: B2,
+    lights: [Status; 2],
+    payload: u8,
+}
+
+#[bitfield(filled = false)]
+pub struct Unfilled {
+    status: Status,
+    flag: bool,
+}
+
+#[bitfield]
+pub struct Large {
+    flags: [B1; 4096],
+    lanes: [Status; 4],
+}
+
+#[bitfield(bits = 8)]
+pub struct Generic<S: Specifier> {
+    first: S,
+    second: B6,
+}
+
+fn main() {
+    let packet = Packet::new();
+    assert!(packet.validate().is_ok());
+    assert!(Packet::from_bytes_validated([0b0000_0100, 0xFF]).is_ok());
+
+    let packet = Packet::from_bytes([0b1100_0011, 0x00]);
+    let errors = packet.validate().unwrap_err();
+    let fields = errors.fields();
+    assert_eq!(fields.len(), 3);
+    assert_eq!((fields[0].name(), fields[0].index(), fields[0].raw()), ("status", None, 0b11));
+    assert_eq!((fields[1].name(), fields[1].index(), fields[1].raw()), ("reserved", None, 0b00));
+    assert_eq!((fields[2].name(), fields[2].index(), fields[2].raw()), ("lights", Some(1), 0b11));
+    assert!(!errors.has_undefined_bits());
+    assert_eq!(
+        errors.to_string(),
+        "encountered invalid bit patterns: status = 0x3, reserved = 0x0, lights[1] = 0x3",
+    );
+    assert_eq!(Packet::from_bytes_validated([0b1100_0011, 0x00]).map(|_| ()), Err(errors));
+
+    let mut bytes = [0x00; 513];
+    bytes[512] = 0b1100_1100;
+    let errors = Large::from_bytes(bytes).validate().unwrap_err();
+    assert!(core::mem::size_of_val(&errors) <= 2 * 128);
+    let fields = errors.fields();
+    assert_eq!(fields.len(), 1);
+    assert_eq!(
+        (fields[0].name(), fields[0].index(), fields[0].raw(), fields[0].invalid_elements()),
+        ("lanes", Some(1), 0b11, 2),
+    );
+    assert_eq!(
+        errors.to_string(),
+        "encountered invalid bit patterns: lanes[1] = 0x3 and 1 more element",
+    );
+
+    assert!(Unfilled::from_bytes_validated([0b0000_0101]).is_ok());
+    let errors = Unfilled::from_bytes_validated([0b0000_1011]).map(|_| ()).unwrap_err();
+    assert_eq!(errors.iter().map(|field| field.name()).collect::<Vec<_>>(), ["status"]);
+    assert!(errors.has_undefined_bits());
+    let errors = Unfilled::from_bytes_validated([0b0000_1001]).map(|_| ()).unwrap_err();
+    assert!(errors.fields().is_empty());
+    assert_eq!(errors.to_string(), "encountered bits at undefined positions");
+
+    assert!(Generic::<B2>::from_bytes_validated([0xFF]).is_ok());
+    let errors = Generic::<Status>::from_bytes_validated([0b1111_1111]).map(|_| ()).unwrap_err();
+    assert_eq!(errors.iter().map(|field| field.name()).collect::<Vec<_>>(), ["first"]);
+}
@@ -0,0 +1,70 @@
+// Tests the borrowed `FooRef<'a>` and `FooMut<'a>` views generated by `#[bitfield(views)]`.
+
+use modular_bitfield::error::OutOfBounds;
+use modular_bitfield::prelude::*;
+
+#[bitfield(views)]
+#[derive(Debug, Clone, Copy)]
+pub struct Header {
+    kind: B4,
+    flags: [bool; 4],
+    len: u8,
+}
+
+#[bitfield(views, endian = "big")]
+pub struct BigHeader {
+    kind: B4,
+    len: B12,
+}
+
+#[bitfield(views, bits = 8)]
+pub struct Generic<K: Specifier> {
+    kind: K,
+    #[at = 4]
+    rest: B4,
+}
+
+fn main() {
+    let mut buffer = [0x00_u8; 8];
+    let header = Header::new().with_kind(0xA).with_flags(1, true).with_len(42);
+    buffer[2..4].copy_from_slice(&header.into_bytes());
+
+    let view = HeaderRef::new(&buffer[2..]).unwrap();
+    assert_eq!(view.kind(), 0xA);
+    assert_eq!(view.flags_array(), [false, true, false, false]);
+    assert_eq!(view.len(), 42);
+    let view = HeaderRef::new_at(&buffer, 16).unwrap();
+    assert_eq!(view.len(), 42);
+
+    let mut view = HeaderMut::new_at(&mut buffer, 16).unwrap();
+    view.set_len(7);
+    view.set_flags(3, true);
+    assert_eq!(view.to_ref().flags(3), true);
+    let view = view.with_kind(0x5);
+    assert_eq!(view.to_ref().kind(), 0x5);
+    assert_eq!(buffer, [0x00, 0x00, 0b1010_0101, 7, 0x00, 0x00, 0x00, 0x00]);
+
+    // Views at unaligned bit offsets leave the surrounding bits untouched.
+    let mut buffer = [0xFF_u8; 3];
+    let mut view = HeaderMut::new_at(&mut buffer, 4).unwrap();
+    view.set_kind(0);
+    view.set_len(0);
+    assert_eq!(buffer, [0x0F, 0x0F, 0xF0]);
+    assert_eq!(HeaderRef::new_at(&buffer, 4).unwrap().kind(), 0);
+
+    assert!(HeaderRef::new(&buffer[..1]).is_err());
+    assert!(HeaderRef::new_at(&buffer, 9).is_err());
+    assert!(matches!(HeaderMut::new_at(&mut [0x00; 2], usize::MAX), Err(OutOfBounds)));
+
+    let mut buffer = BigHeader::new().with_kind(0x3).with_len(0x456).into_bytes();
+    assert_eq!(BigHeaderRef::new(&buffer).unwrap().len(), 0x456);
+    BigHeaderMut::new(&mut buffer).unwrap().set_kind(0x7);
+    assert_eq!(buffer, [0x74, 0x56]);
+
+    let mut buffer = [0x00_u8; 1];
+    let mut view = GenericMut::<B2>::new(&mut buffer).unwrap();
+    view.set_kind(0b11);
+    view.set_rest(0b1001);
+    assert_eq!(buffer, [0b1001_0011]);
+    assert_eq!(GenericRef::<B2>::new(&buffer).unwrap().rest(), 0b1001);
+}
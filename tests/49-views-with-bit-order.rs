@@ -0,0 +1,9 @@
+use modular_bitfield::prelude::*;
+
+#[bitfield(views, bit_order = "msb0", endian = "little")]
+pub struct Header {
+    kind: B4,
+    len: B12,
+}
+
+fn main() {}
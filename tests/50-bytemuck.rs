@@ -0,0 +1,60 @@
+// Tests the `bytemuck::Pod` and `bytemuck::Zeroable` impls of the `bytemuck` parameter.
+
+use modular_bitfield::prelude::*;
+
+#[bitfield(bytemuck)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Descriptor {
+    ready: bool,
+    owner: B7,
+    len: u8,
+}
+
+// Only `Zeroable` since `Pod` requires `Copy`.
+#[bitfield(bytemuck)]
+pub struct NotCopy {
+    value: u16,
+}
+
+#[bitfield(bits = 8, bytemuck)]
+#[derive(Clone, Copy)]
+pub struct Generic<K: Specifier> {
+    kind: K,
+    rest: B4,
+}
+
+// The bit order matches the implied big endian layout so the internal bytes are
+// the bytes of `into_bytes`.
+#[bitfield(bytemuck, bit_order = "msb0")]
+#[derive(Clone, Copy)]
+pub struct Msb0 {
+    a: bool,
+    b: B7,
+}
+
+fn main() {
+    let ring = [
+        Descriptor::new().with_ready(true).with_len(1),
+        Descriptor::new().with_owner(0x7F).with_len(2),
+    ];
+    let bytes: &[u8] = bytemuck::cast_slice(&ring);
+    assert_eq!(bytes, &[0x01, 0x01, 0xFE, 0x02]);
+
+    let mut raw = [0x00_u8; 4];
+    let descriptors: &mut [Descriptor] = bytemuck::cast_slice_mut(&mut raw);
+    descriptors[1].set_len(42);
+    assert_eq!(raw, [0x00, 0x00, 0x00, 42]);
+
+    let descriptor: Descriptor = bytemuck::Zeroable::zeroed();
+    assert_eq!(descriptor, Descriptor::new());
+    let not_copy: NotCopy = bytemuck::Zeroable::zeroed();
+    assert_eq!(not_copy.value(), 0);
+
+    let generic: Generic<B4> = bytemuck::cast(0xA5_u8);
+    assert_eq!(generic.kind(), 0x5);
+    assert_eq!(generic.rest(), 0xA);
+
+    let msb0 = Msb0::new().with_a(true);
+    assert_eq!(bytemuck::bytes_of(&msb0), &msb0.into_bytes()[..]);
+    assert_eq!(msb0.into_bytes(), [0x80]);
+}
@@ -0,0 +1,78 @@
+// Tests forwarding the derives of the `zerocopy` crate feature.
+
+use modular_bitfield::prelude::*;
+use zerocopy::{
+    FromBytes,
+    Immutable,
+    IntoBytes,
+    KnownLayout,
+    Unaligned,
+};
+
+#[derive(BitfieldSpecifier, Debug, PartialEq)]
+pub enum Kind {
+    Data,
+    Ack,
+    Nack,
+    Reset,
+}
+
+#[derive(BitfieldSpecifier, Debug, PartialEq)]
+#[bits = 2]
+pub enum Opcode {
+    Read,
+    Write,
+    #[fallback]
+    Unknown(u8),
+}
+
+#[bitfield]
+#[derive(FromBytes, IntoBytes, KnownLayout, Immutable, Unaligned)]
+pub struct Header {
+    kind: Kind,
+    opcode: Opcode,
+    flags: B4,
+    len: u16,
+    seq: Range<u8, 1, 16>,
+    #[skip]
+    __: B4,
+}
+
+#[bitfield]
+#[derive(BitfieldSpecifier, Debug, PartialEq)]
+pub struct Flags {
+    kind: Kind,
+    rest: B6,
+}
+
+#[bitfield]
+#[derive(FromBytes, IntoBytes, Immutable)]
+pub struct Nested {
+    flags: Flags,
+    value: u8,
+}
+
+fn main() {
+    let bytes = [0b0101_0110, 0x02, 0x01, 0x0F];
+    let header = Header::ref_from_bytes(&bytes[..]).unwrap();
+    assert_eq!(header.kind(), Kind::Nack);
+    assert_eq!(header.opcode(), Opcode::Write);
+    assert_eq!(header.flags(), 0b0101);
+    assert_eq!(header.len(), 0x0102);
+    assert_eq!(header.seq(), 16);
+
+    let mut packet = [0x00_u8; 8];
+    let (header, payload) = Header::mut_from_prefix(&mut packet[..]).unwrap();
+    header.set_kind(Kind::Ack);
+    header.set_len(4);
+    payload.copy_from_slice(&[1, 2, 3, 4]);
+    assert_eq!(packet, [0x01, 0x04, 0x00, 0x00, 1, 2, 3, 4]);
+
+    let header = Header::read_from_bytes(&[0xFF; 4][..]).unwrap();
+    assert_eq!(header.opcode(), Opcode::Unknown(3));
+    assert_eq!(header.as_bytes(), &[0xFF; 4]);
+
+    let nested = Nested::read_from_bytes(&[0xFF, 0x01][..]).unwrap();
+    assert_eq!(nested.flags().kind(), Kind::Reset);
+    assert_eq!(nested.value(), 1);
+}
@@ -0,0 +1,50 @@
+// Fields with invalid bit patterns cannot be used with the `zerocopy` derives.
+
+use modular_bitfield::prelude::*;
+use zerocopy::FromBytes;
+
+#[derive(BitfieldSpecifier, Debug)]
+#[bits = 2]
+pub enum Mode {
+    A,
+    B,
+    C,
+}
+
+#[bitfield]
+#[derive(FromBytes)]
+pub struct Packet {
+    mode: Mode,
+    rest: B6,
+}
+
+#[bitfield(filled = false)]
+#[derive(FromBytes)]
+pub struct Unfilled {
+    value: B7,
+}
+
+#[bitfield]
+#[derive(FromBytes)]
+pub struct Reserved {
+    value: B6,
+    #[reserved = 0b10]
+    version: B2,
+}
+
+// Nested bitfields are only valid for all bit patterns if all of their fields are.
+#[bitfield]
+#[derive(BitfieldSpecifier)]
+pub struct Inner {
+    mode: Mode,
+    rest: B6,
+}
+
+#[bitfield]
+#[derive(FromBytes)]
+pub struct Outer {
+    inner: Inner,
+    value: u8,
+}
+
+fn main() {}
@@ -0,0 +1,88 @@
+// Tests the `serde = "fields"` and `serde = "bytes"` parameters of the `serde` crate feature.
+
+use modular_bitfield::prelude::*;
+use serde::{
+    Deserialize,
+    Serialize,
+};
+
+#[derive(BitfieldSpecifier, Serialize, Deserialize, Debug, PartialEq)]
+pub enum Mode {
+    Off,
+    Sleep,
+    On,
+    Boost,
+}
+
+#[bitfield(serde = "fields")]
+#[derive(Debug, PartialEq)]
+pub struct Config {
+    enabled: bool,
+    mode: Mode,
+    level: B5,
+    gains: [B4; 2],
+    #[skip]
+    __: B8,
+}
+
+#[bitfield(serde = "bytes", bits = 12, filled = false)]
+#[derive(Debug, PartialEq)]
+pub struct Packed {
+    a: B4,
+    b: B7,
+}
+
+#[bitfield(serde = "fields", bits = 8)]
+pub struct Generic<K: Specifier> {
+    kind: K,
+    rest: B4,
+}
+
+fn main() {
+    let config = Config::new()
+        .with_enabled(true)
+        .with_mode(Mode::Boost)
+        .with_level(17)
+        .with_gains_array([3, 15]);
+    let json = serde_json::to_string(&config).unwrap();
+    assert_eq!(
+        json,
+        r#"{"enabled":true,"mode":"Boost","level":17,"gains":[3,15]}"#
+    );
+    assert_eq!(serde_json::from_str::<Config>(&json).unwrap(), config);
+
+    // Out of bounds values are rejected by the checked setters.
+    let err = serde_json::from_str::<Config>(
+        r#"{"enabled":true,"mode":"On","level":32,"gains":[0,0]}"#,
+    )
+    .unwrap_err();
+    assert!(err.to_string().starts_with("value out of bounds for field Config.level"));
+    let err = serde_json::from_str::<Config>(
+        r#"{"enabled":true,"mode":"On","level":0,"gains":[16,0]}"#,
+    )
+    .unwrap_err();
+    assert!(err.to_string().starts_with("value out of bounds for field Config.gains"));
+    let err = serde_json::from_str::<Config>(r#"{"enabled":true,"mode":"On"}"#).unwrap_err();
+    assert!(err.to_string().starts_with("missing field `level`"));
+    let err = serde_json::from_str::<Config>(r#"{"enabled":true,"__":0}"#).unwrap_err();
+    assert!(err.to_string().starts_with("unknown field `__`"));
+
+    // Sequences are deserialized in field order.
+    let config = serde_json::from_str::<Config>(r#"[false,"Sleep",3,[1,2]]"#).unwrap();
+    assert_eq!(config.mode(), Mode::Sleep);
+    assert_eq!(config.gains_array(), [1, 2]);
+
+    let packed = Packed::new().with_a(0xA).with_b(0x3C);
+    let json = serde_json::to_string(&packed).unwrap();
+    assert_eq!(json, "[202,3]");
+    assert_eq!(serde_json::from_str::<Packed>(&json).unwrap(), packed);
+    let err = serde_json::from_str::<Packed>("[202,19]").unwrap_err();
+    assert!(err.to_string().starts_with("bits at undefined positions are set for Packed"));
+    assert!(serde_json::from_str::<Packed>("[202]").is_err());
+
+    let generic = Generic::<B4>::new().with_kind(5).with_rest(9);
+    let json = serde_json::to_string(&generic).unwrap();
+    assert_eq!(json, r#"{"kind":5,"rest":9}"#);
+    let generic = serde_json::from_str::<Generic<B4>>(&json).unwrap();
+    assert_eq!((generic.kind(), generic.rest()), (5, 9));
+}
@@ -0,0 +1,246 @@
+// Tests the `defmt::Format` impls generated with the `defmt` crate feature.
+//
+// The logged frames are captured by a global logger and decoded with the format strings
+// that `defmt` interns into the `.defmt.*` sections of the test executable.
+
+use modular_bitfield::prelude::*;
+use std::sync::Mutex;
+
+#[derive(BitfieldSpecifier, Debug, defmt::Format)]
+#[bits = 2]
+pub enum Mode {
+    Off,
+    On,
+    Boost,
+}
+
+#[bitfield]
+#[derive(Debug, defmt::Format)]
+pub struct Status {
+    ready: bool,
+    mode: Mode,
+    gains: [B2; 2],
+    #[skip]
+    __: B1,
+}
+
+#[bitfield]
+#[derive(defmt::Format)]
+pub struct Pair(u8, u8);
+
+#[bitfield(bits = 8)]
+#[derive(defmt::Format)]
+pub struct Generic<K: Specifier> {
+    kind: K,
+    rest: B4,
+}
+
+static FRAMES: Mutex<Vec<u8>> = Mutex::new(Vec::new());
+
+#[defmt::global_logger]
+struct Logger;
+
+unsafe impl defmt::Logger for Logger {
+    fn acquire() {}
+    unsafe fn flush() {}
+    unsafe fn release() {}
+    unsafe fn write(bytes: &[u8]) {
+        FRAMES.lock().unwrap().extend_from_slice(bytes);
+    }
+}
+
+defmt::timestamp!("");
+
+#[defmt::panic_handler]
+fn defmt_panic() -> ! {
+    panic!("defmt panic")
+}
+
+/// Formats the given value via `defmt` and returns the decoded message.
+fn log<T: defmt::Format>(value: &T) -> String {
+    FRAMES.lock().unwrap().clear();
+    defmt::println!("{}", value);
+    let frames = core::mem::take(&mut *FRAMES.lock().unwrap());
+    decode(&frames)
+}
+
+#[cfg(not(target_os = "linux"))]
+fn decode(_frames: &[u8]) -> String {
+    String::from("<unsupported>")
+}
+
+#[cfg(target_os = "linux")]
+fn decode(frames: &[u8]) -> String {
+    let mut decoder = decode::Decoder {
+        strings: decode::interned_strings(),
+        frames,
+    };
+    let header = decoder.tag();
+    let message = decoder.format(&header);
+    assert!(decoder.frames.is_empty(), "trailing bytes after message: {:?}", decoder.frames);
+    message
+}
+
+#[cfg(target_os = "linux")]
+mod decode {
+    use std::collections::HashMap;
+    use std::convert::TryInto as _;
+
+    /// Anchors the load address of the executable to the addresses of its sections.
+    #[used]
+    #[link_section = ".bf_anchor"]
+    static ANCHOR: u8 = 0;
+
+    /// Returns the format strings interned by `defmt` by the 16-bit index they are logged with.
+    pub fn interned_strings() -> HashMap<u16, String> {
+        let elf = std::fs::read("/proc/self/exe").unwrap();
+        let u16_at = |at: usize| u16::from_le_bytes([elf[at], elf[at + 1]]) as usize;
+        let u32_at = |at: usize| u32::from_le_bytes(elf[at..at + 4].try_into().unwrap()) as usize;
+        let u64_at = |at: usize| u64::from_le_bytes(elf[at..at + 8].try_into().unwrap()) as usize;
+        let (shoff, shentsize, shnum, shstrndx) =
+            (u64_at(0x28), u16_at(0x3A), u16_at(0x3C), u16_at(0x3E));
+        let names = u64_at(shoff + shstrndx * shentsize + 0x18);
+        let sections = (0..shnum)
+            .map(|n| {
+                let header = shoff + n * shentsize;
+                let name = &elf[names + u32_at(header)..];
+                let name = &name[..name.iter().position(|&byte| byte == 0).unwrap()];
+                (String::from_utf8_lossy(name).into_owned(), u64_at(header + 0x10))
+            })
+            .collect::<Vec<_>>();
+        let anchor = sections.iter().find(|(name, _)| name == ".bf_anchor").unwrap().1;
+        let load_address = &ANCHOR as *const u8 as usize - anchor;
+        sections
+            .into_iter()
+            .filter_map(|(name, address)| {
+                let symbol = name.strip_prefix(".defmt.")?;
+                let symbol = symbol.strip_prefix("prim.").unwrap_or(symbol);
+                let data = symbol.split("\"data\":\"").nth(1)?;
+                let mut string = String::new();
+                let mut chars = data.chars();
+                while let Some(c) = chars.next() {
+                    match c {
+                        '"' => break,
+                        '\\' => string.extend(chars.next()),
+                        c => string.push(c),
+                    }
+                }
+                Some(((load_address + address) as u16, string))
+            })
+            .collect()
+    }
+
+    /// Decodes the subset of the `defmt` wire format used by the tests.
+    pub struct Decoder<'a> {
+        pub strings: HashMap<u16, String>,
+        pub frames: &'a [u8],
+    }
+
+    impl Decoder<'_> {
+        fn bytes(&mut self, len: usize) -> &[u8] {
+            let (bytes, rest) = self.frames.split_at(len);
+            self.frames = rest;
+            bytes
+        }
+
+        fn uint(&mut self, len: usize) -> u128 {
+            self.bytes(len)
+                .iter()
+                .rev()
+                .fold(0, |value, &byte| value << 8 | u128::from(byte))
+        }
+
+        /// Reads the index of an interned string and returns the string.
+        pub fn tag(&mut self) -> String {
+            let index = self.uint(2) as u16;
+            self.strings
+                .get(&index)
+                .unwrap_or_else(|| panic!("unknown interned string {:#06X}", index))
+                .clone()
+        }
+
+        /// Decodes the arguments of the given format string and returns the message.
+        pub fn format(&mut self, format: &str) -> String {
+            if format.contains('|') && !format.starts_with('{') {
+                // Derived enums select their variant by a leading discriminant.
+                let variant = self.uint(1) as usize;
+                return format.split('|').nth(variant).unwrap().to_string()
+            }
+            let mut message = String::new();
+            let mut chars = format.chars().peekable();
+            while let Some(c) = chars.next() {
+                match c {
+                    '{' if chars.peek() == Some(&'{') => message.extend(chars.next()),
+                    '}' if chars.peek() == Some(&'}') => message.extend(chars.next()),
+                    '{' => {
+                        let param = chars.by_ref().take_while(|&c| c != '}').collect::<String>();
+                        let ty = param.trim_start_matches('=').split(':').next().unwrap();
+                        let arg = self.argument(ty);
+                        message.push_str(&arg);
+                    }
+                    c => message.push(c),
+                }
+            }
+            message
+        }
+
+        fn argument(&mut self, ty: &str) -> String {
+            match ty {
+                "" | "?" => {
+                    let format = self.tag();
+                    self.format(&format)
+                }
+                "__internal_FormatSequence" => {
+                    let mut message = String::new();
+                    while !self.frames.starts_with(&[0, 0]) {
+                        let format = self.tag();
+                        message.push_str(&self.format(&format));
+                    }
+                    self.bytes(2);
+                    message
+                }
+                "bool" => (self.uint(1) != 0).to_string(),
+                "u8" => self.uint(1).to_string(),
+                "u16" => self.uint(2).to_string(),
+                "u32" => self.uint(4).to_string(),
+                "u64" => self.uint(8).to_string(),
+                "u128" => self.uint(16).to_string(),
+                array => {
+                    let len = array
+                        .strip_prefix("[?;")
+                        .and_then(|len| len.strip_suffix(']'))
+                        .and_then(|len| len.trim().parse::<usize>().ok())
+                        .unwrap_or_else(|| panic!("unsupported parameter type {}", array));
+                    let format = self.tag();
+                    let elements = (0..len).map(|_| self.format(&format)).collect::<Vec<_>>();
+                    format!("[{}]", elements.join(", "))
+                }
+            }
+        }
+    }
+}
+
+fn assert_format<T: defmt::Format>() {}
+
+fn main() {
+    assert_format::<Status>();
+    assert_format::<Pair>();
+    assert_format::<Generic<B4>>();
+    assert_format::<Generic<Mode>>();
+
+    if cfg!(not(target_os = "linux")) {
+        return
+    }
+    assert_eq!(log(&Pair::new().with_0(0x11).with_1(0x22)), "Pair { 0: 17, 1: 34 }");
+    let status = Status::new()
+        .with_ready(true)
+        .with_mode(Mode::Boost)
+        .with_gains_array([1, 3]);
+    assert_eq!(log(&status), "Status { ready: true, mode: Boost, gains: [1, 3] }");
+    assert_eq!(
+        log(&Status::from_bytes([0b1100_0110])),
+        "Status { ready: false, mode: InvalidBitPattern { invalid_bytes: 3 }, gains: [0, 2] }",
+    );
+    let generic = Generic::<B4>::new().with_kind(5).with_rest(9);
+    assert_eq!(log(&generic), "Generic { kind: 5, rest: 9 }");
+}
@@ -0,0 +1,90 @@
+// Tests the `arbitrary = "fields"` and `arbitrary = "bytes"` parameters and the
+// `#[arbitrary]` attribute of the `arbitrary` crate feature.
+
+use arbitrary::{
+    Arbitrary,
+    Unstructured,
+};
+use modular_bitfield::prelude::*;
+
+#[derive(BitfieldSpecifier, Debug, PartialEq)]
+#[arbitrary]
+#[bits = 2]
+pub enum Mode {
+    Off,
+    On,
+    Boost,
+}
+
+#[derive(BitfieldSpecifier, Debug, PartialEq)]
+#[arbitrary]
+pub enum Command {
+    Nop,
+    Move {
+        #[bits = 3]
+        dx: u8,
+        #[bits = 3]
+        dy: u8,
+    },
+    Set(Mode),
+}
+
+#[bitfield(arbitrary = "fields")]
+#[derive(BitfieldSpecifier, Debug, PartialEq)]
+pub struct Header {
+    mode: Mode,
+    level: Range<u8, 1, 12>,
+    fallback: Option<Mode>,
+    #[reserved = 0b11]
+    __: B8,
+}
+
+#[bitfield(arbitrary = "fields")]
+#[derive(Debug, PartialEq)]
+pub struct Packet {
+    header: Header,
+    command: Command,
+    gains: [Range<u8, 10, 14>; 3],
+    #[skip]
+    __: B7,
+}
+
+#[bitfield(arbitrary = "bytes", bits = 12, filled = false)]
+#[derive(Debug)]
+pub struct Raw {
+    mode: Mode,
+    level: B7,
+}
+
+fn main() {
+    let data = (0..=255_u8).cycle().step_by(7).take(4096).collect::<Vec<_>>();
+    let mut u = Unstructured::new(&data);
+    let mut modes = [false; 3];
+    while !u.is_empty() {
+        let mode = Mode::arbitrary(&mut u).unwrap();
+        modes[mode as usize] = true;
+
+        let command = Command::arbitrary(&mut u).unwrap();
+        assert!(Command::from_bytes(Command::into_bytes(command).unwrap()).is_ok());
+
+        let packet = Packet::arbitrary(&mut u).unwrap();
+        let packet = Packet::from_bytes(packet.into_bytes());
+        assert!(packet.header_or_err().is_ok());
+        assert!(packet.command_or_err().is_ok());
+        assert!(packet.gains_array().iter().all(|gain| (10..=14).contains(gain)));
+        let header = packet.header();
+        assert!(header.mode_or_err().is_ok());
+        assert!((1..=12).contains(&header.level()));
+        assert!(header.fallback_or_err().is_ok());
+
+        // Raw bytes may contain invalid bit patterns but never bits at undefined positions.
+        let raw = Raw::arbitrary(&mut u).unwrap();
+        assert!(Raw::from_bytes(raw.into_bytes()).is_ok());
+    }
+    assert_eq!(modes, [true; 3]);
+
+    let data = [0b0000_0011, 0b1111_1111];
+    let raw = Raw::arbitrary(&mut Unstructured::new(&data)).unwrap();
+    assert!(raw.mode_or_err().is_err());
+    assert_eq!(raw.into_bytes(), [0b0000_0011, 0b0000_1111]);
+}
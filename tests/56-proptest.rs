@@ -0,0 +1,87 @@
+// Tests the `proptest = "fields"` and `proptest = "bytes"` parameters and the
+// `#[proptest]` attribute of the `proptest` crate feature.
+
+use modular_bitfield::prelude::*;
+use proptest::{
+    arbitrary::any,
+    test_runner::TestRunner,
+};
+
+#[derive(BitfieldSpecifier, Debug, PartialEq)]
+#[proptest]
+#[bits = 2]
+pub enum Mode {
+    Off,
+    On,
+    Boost,
+}
+
+#[derive(BitfieldSpecifier, Debug, PartialEq)]
+#[bits = 4]
+pub enum Kind {
+    Data,
+    Control,
+    #[fallback]
+    Unknown(u8),
+}
+
+#[bitfield(proptest = "fields")]
+#[derive(Debug)]
+pub struct Header {
+    mode: Mode,
+    kind: Kind,
+    level: Range<i8, -3, 3>,
+    #[none = 0b11]
+    limit: Option<B2>,
+    gains: [Range<u16, 100, 104>; 2],
+    #[skip(setters)]
+    version: B7,
+}
+
+#[bitfield(proptest = "bytes", bits = 10, filled = false)]
+#[derive(Debug)]
+pub struct Raw {
+    mode: Mode,
+    level: B6,
+}
+
+#[bitfield(proptest = "fields", bits = 8)]
+#[derive(Debug)]
+pub struct Generic<K: Specifier> {
+    kind: K,
+    rest: B4,
+}
+
+fn main() {
+    let mut runner = TestRunner::default();
+    runner
+        .run(&any::<Mode>(), |mode| {
+            assert_ne!(Mode::into_bytes(mode).unwrap(), 0b11);
+            Ok(())
+        })
+        .unwrap();
+    runner
+        .run(&any::<Header>(), |header| {
+            let header = Header::from_bytes(header.into_bytes());
+            assert!(header.mode_or_err().is_ok());
+            assert!(header.kind_or_err().is_ok());
+            assert!((-3..=3).contains(&header.level()));
+            assert!(header.limit_or_err().is_ok());
+            assert!(header.gains_array().iter().all(|gain| (100..=104).contains(gain)));
+            assert_eq!(header.version(), 0);
+            Ok(())
+        })
+        .unwrap();
+    runner
+        .run(&any::<Raw>(), |raw| {
+            assert!(Raw::from_bytes(raw.into_bytes()).is_ok());
+            Ok(())
+        })
+        .unwrap();
+    runner
+        .run(&any::<Generic<B4>>(), |generic| {
+            assert!(generic.kind() < 16);
+            Ok(())
+        })
+        .unwrap();
+}
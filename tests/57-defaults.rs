@@ -0,0 +1,175 @@
+// Tests `#[default = N]` fields and `#[default]` enum variants initializing `new` and `Default`.
+
+use modular_bitfield::prelude::*;
+
+#[derive(BitfieldSpecifier, Debug, PartialEq, Default)]
+#[bits = 2]
+pub enum Mode {
+    Off,
+    #[default]
+    Idle,
+    Run,
+}
+
+#[derive(BitfieldSpecifier, Debug, PartialEq)]
+#[bits = 4]
+#[repr(u8)]
+pub enum Kind {
+    Ping = 2,
+    #[default]
+    Data = 5,
+    #[fallback]
+    Other(u8),
+}
+
+#[derive(BitfieldSpecifier, Debug, PartialEq)]
+pub enum Command {
+    Stop,
+    #[default]
+    Nop,
+    Move(#[bits = 3] u8),
+}
+
+#[derive(BitfieldSpecifier, Debug, PartialEq)]
+pub struct Wrapped(Mode);
+
+#[bitfield]
+#[derive(BitfieldSpecifier, Debug, Default)]
+pub struct Header {
+    #[default = 3]
+    version: B4,
+    #[default = true]
+    flag: bool,
+    mode: Mode,
+    kind: Kind,
+    #[reserved = 0b1]
+    __: B1,
+    #[default = 0b01]
+    lanes: [B2; 2],
+}
+
+#[bitfield]
+#[derive(Debug, Default)]
+pub struct Packet {
+    header: Header,
+    command: Command,
+    wrapped: Wrapped,
+    #[default = 0x2A]
+    id: B9,
+}
+
+#[bitfield(endian = "big")]
+pub struct BigRegister {
+    #[default = 0b101]
+    value: B3,
+    mode: Mode,
+    rest: B11,
+}
+
+// Narrowed newtypes over signed specifiers take bit patterns.
+#[derive(BitfieldSpecifier)]
+pub struct Offset(#[bits = 6] S8);
+
+// Values of signed, `Range` and enum fields are values of their types rather than bit patterns.
+#[bitfield]
+pub struct Values {
+    #[default = -3]
+    offset: S4,
+    #[default = -100]
+    delta: i8,
+    #[default = 3]
+    burst: Range<u8, 1, 16>,
+    #[default = -2]
+    shift: Range<i8, -4, 3>,
+    #[default = 2]
+    mode: Mode,
+    #[default = 0b11_1111]
+    narrow: Offset,
+    #[default = 15]
+    rest: B5,
+}
+
+#[bitfield(bits = 8)]
+#[derive(Default)]
+pub struct Generic<K: Specifier> {
+    kind: K,
+    #[default = 0b10_1010]
+    value: B6,
+}
+
+const HEADER: Header = Header::new();
+
+#[bitfield(bits = 256)]
+#[derive(BitfieldSpecifier)]
+pub struct Wide {
+    #[default = 1]
+    lo: B128,
+    #[default = 5]
+    hi: B128,
+}
+
+#[bitfield]
+pub struct Outer {
+    wide: Wide,
+    #[default = 0xAB]
+    tail: u8,
+}
+
+#[bitfield(endian = "big")]
+pub struct BigOuter {
+    wide: Wide,
+}
+
+fn main() {
+    assert_eq!(<Mode as Specifier>::DEFAULT_BIT_PATTERN, 1);
+    assert_eq!(<Kind as Specifier>::DEFAULT_BIT_PATTERN, 5);
+    assert_eq!(<Command as Specifier>::DEFAULT_BIT_PATTERN, 1);
+    assert_eq!(<Wrapped as Specifier>::DEFAULT_BIT_PATTERN, 1);
+    assert_eq!(<B8 as Specifier>::DEFAULT_BIT_PATTERN, 0);
+    assert_eq!(Mode::default(), Mode::Idle);
+
+    assert_eq!(HEADER.version(), 3);
+    assert!(HEADER.flag());
+    assert_eq!(HEADER.mode(), Mode::Idle);
+    assert_eq!(HEADER.kind(), Kind::Data);
+    assert_eq!(HEADER.lanes_array(), [0b01, 0b01]);
+    assert_eq!(HEADER.into_bytes(), [0b1011_0011, 0b0101_1010]);
+    assert_eq!(Header::default().into_bytes(), HEADER.into_bytes());
+    assert_eq!(
+        <Header as Specifier>::DEFAULT_BIT_PATTERN,
+        u16::from_le_bytes(HEADER.into_bytes()) as u128,
+    );
+
+    let packet = Packet::default();
+    assert_eq!(packet.header().into_bytes(), HEADER.into_bytes());
+    assert_eq!(packet.command(), Command::Nop);
+    assert_eq!(packet.wrapped(), Wrapped(Mode::Idle));
+    assert_eq!(packet.id(), 0x2A);
+
+    let big = BigRegister::new();
+    assert_eq!(big.value(), 0b101);
+    assert_eq!(big.mode(), Mode::Idle);
+    assert_eq!(big.into_bytes(), [0b1010_1000, 0x00]);
+
+    let values = Values::new();
+    assert_eq!(values.offset(), -3);
+    assert_eq!(values.delta(), -100);
+    assert_eq!(values.burst(), 3);
+    assert_eq!(values.shift(), -2);
+    assert_eq!(values.mode(), Mode::Run);
+    assert_eq!(values.narrow(), 0b11_1111);
+    assert_eq!(values.rest(), 15);
+
+    let generic = Generic::<Mode>::default();
+    assert_eq!(generic.kind(), Mode::Idle);
+    assert_eq!(generic.value(), 0b10_1010);
+
+    // Defaults of nested specifiers wider than 128 bits are kept in full.
+    let outer = Outer::new();
+    assert_eq!(outer.wide().lo(), 1);
+    assert_eq!(outer.wide().hi(), 5);
+    assert_eq!(outer.tail(), 0xAB);
+    let big = BigOuter::new();
+    assert_eq!(big.wide().lo(), 1);
+    assert_eq!(big.wide().hi(), 5);
+}
@@ -0,0 +1,11 @@
+use modular_bitfield::prelude::*;
+
+#[bitfield]
+pub struct Register {
+    enable: bool,
+    #[default = 0b100]
+    level: B2,
+    lanes: [B1; 5],
+}
+
+fn main() {}
@@ -0,0 +1,18 @@
+use modular_bitfield::prelude::*;
+
+#[derive(BitfieldSpecifier)]
+pub enum Command {
+    Stop,
+    #[default]
+    Move(#[bits = 3] u8),
+}
+
+#[derive(BitfieldSpecifier)]
+pub enum Mode {
+    #[default]
+    Off,
+    #[default]
+    On,
+}
+
+fn main() {}
@@ -0,0 +1,126 @@
+// Tests the typestate builder generated by `#[bitfield(builder)]`.
+
+use modular_bitfield::error::OutOfBounds;
+use modular_bitfield::prelude::*;
+
+#[derive(BitfieldSpecifier, Debug, PartialEq)]
+#[bits = 2]
+pub enum Mode {
+    Off,
+    Idle,
+    Run,
+}
+
+#[bitfield(builder)]
+#[derive(Debug)]
+pub struct Header {
+    #[default = 3]
+    version: B4,
+    mode: Mode,
+    lanes: [B1; 2],
+    #[skip(setters)]
+    __: B4,
+    #[reserved = 0b1]
+    marker: B1,
+    length: B3,
+}
+
+#[bitfield(builder)]
+pub struct Flags {
+    #[default = true]
+    enable: bool,
+    #[default = 0b101]
+    level: B7,
+}
+
+#[bitfield(bits = 8, builder)]
+pub struct Generic<K: Specifier> {
+    kind: K,
+    value: B6,
+}
+
+#[bitfield(builder)]
+pub struct Tuple(B4, bool, B3);
+
+#[bitfield(builder)]
+pub struct Lanes {
+    #[const_fn]
+    mode: Mode,
+    enabled: [bool; 3],
+    #[default = 0]
+    weights: [modular_bitfield::specifiers::B4; 2],
+    length: B3,
+    offset: i8,
+    extra: [u8; 2],
+}
+
+const FLAGS: Flags = Flags::builder().build();
+
+const LANES: Lanes = Lanes::builder()
+    .with_mode(Mode::Run)
+    .with_enabled([true, false, true])
+    .with_weights([3, 15])
+    .with_length(1)
+    .with_offset(-2)
+    .with_extra([0xAB, 0xCD])
+    .build();
+
+const INVALID_WEIGHTS: bool = Lanes::builder().with_weights_checked([16, 0]).is_err();
+
+fn main() {
+    let header = Header::builder()
+        .with_length(5)
+        .with_mode(Mode::Run)
+        .with_lanes([1, 0])
+        .build();
+    assert_eq!(header.version(), 3);
+    assert_eq!(header.mode(), Mode::Run);
+    assert_eq!(header.lanes_array(), [1, 0]);
+    assert_eq!(header.marker(), 0b1);
+    assert_eq!(header.length(), 5);
+
+    let header = Header::builder()
+        .with_version(7)
+        .with_mode(Mode::Idle)
+        .with_lanes_checked([0, 1])
+        .unwrap()
+        .with_version(9)
+        .with_length_checked(7)
+        .unwrap()
+        .build();
+    assert_eq!(header.version(), 9);
+    assert_eq!(header.lanes_array(), [0, 1]);
+    assert_eq!(header.length(), 7);
+    assert_eq!(
+        Header::builder().with_length_checked(8).map(|_| ()),
+        Err(OutOfBounds),
+    );
+    assert_eq!(
+        Header::builder().with_lanes_checked([2, 0]).map(|_| ()),
+        Err(OutOfBounds),
+    );
+
+    assert!(FLAGS.enable());
+    assert_eq!(FLAGS.level(), 0b101);
+    assert_eq!(Flags::builder().with_level(1).build().level(), 1);
+
+    let generic = Generic::<Mode>::builder()
+        .with_value(0b11_1111)
+        .with_kind(Mode::Idle)
+        .build();
+    assert_eq!(generic.kind(), Mode::Idle);
+    assert_eq!(generic.value(), 0b11_1111);
+
+    assert_eq!(LANES.mode(), Mode::Run);
+    assert_eq!(LANES.enabled_array(), [true, false, true]);
+    assert_eq!(LANES.weights_array(), [3, 15]);
+    assert_eq!(LANES.length(), 1);
+    assert_eq!(LANES.offset(), -2);
+    assert_eq!(LANES.extra_array(), [0xAB, 0xCD]);
+    assert!(INVALID_WEIGHTS);
+
+    let tuple = Tuple::builder().with_2(5).with_0(9).with_1(true).build();
+    assert_eq!(tuple.get_0(), 9);
+    assert!(tuple.get_1());
+    assert_eq!(tuple.get_2(), 5);
+}
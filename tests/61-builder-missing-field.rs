@@ -0,0 +1,14 @@
+use modular_bitfield::prelude::*;
+
+#[bitfield(builder)]
+pub struct Header {
+    #[default = 3]
+    version: B4,
+    flag: bool,
+    length: B3,
+}
+
+fn main() {
+    let _ = Header::builder().with_flag(true).build();
+    let _ = Header::builder().with_flag(true).with_flag(false);
+}
@@ -0,0 +1,151 @@
+// Tests the `const fn` getters and `with_*` methods of built-in specifiers and `#[const_fn]` fields.
+//
+// Enums deriving `BitfieldSpecifier` opt in with `#[const_fn]`.
+
+use modular_bitfield::error::OutOfBounds;
+use modular_bitfield::prelude::*;
+
+#[derive(BitfieldSpecifier, Debug, PartialEq, Copy, Clone)]
+#[bits = 2]
+pub enum Mode {
+    Off,
+    Idle,
+    Run,
+}
+
+#[derive(BitfieldSpecifier, Debug, PartialEq, Copy, Clone)]
+#[bits = 4]
+#[repr(u8)]
+pub enum Kind {
+    Ping = 2,
+    Data = 5,
+    #[fallback]
+    Other(u8),
+}
+
+#[bitfield(builder)]
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Register {
+    enable: bool,
+    level: B5,
+    #[const_fn]
+    mode: Mode,
+    id: u8,
+    delta: i8,
+    #[const_fn]
+    kind: Kind,
+    offset: modular_bitfield::specifiers::S4,
+    #[endian = "big"]
+    address: ::modular_bitfield::prelude::B8,
+    #[default = 0b010]
+    lanes: [B3; 4],
+    #[skip]
+    __: B12,
+}
+
+#[bitfield(endian = "big")]
+#[derive(Debug, Copy, Clone)]
+pub struct BigRegister {
+    value: B3,
+    #[const_fn]
+    mode: Mode,
+    rest: B11,
+}
+
+mod custom {
+    use modular_bitfield::prelude::*;
+
+    /// A user type that shares its name with the `B5` specifier.
+    #[derive(BitfieldSpecifier, Debug, PartialEq, Copy, Clone)]
+    pub struct B5(#[bits = 5] pub u8);
+}
+
+// Fields of user types named like built-in specifiers by a path get regular accessors.
+#[bitfield]
+pub struct Custom {
+    level: custom::B5,
+    rest: B3,
+}
+
+const fn register(level: u8, mode: Mode) -> Register {
+    Register::new()
+        .with_enable(true)
+        .with_level(level)
+        .with_mode(mode)
+        .with_kind(Kind::Other(9))
+        .with_offset(-3)
+        .with_address(0xAB)
+        .with_id(0x42)
+        .with_delta(-100)
+        .with_lanes(1, 0b101)
+}
+
+const TABLE: [Register; 3] = [
+    register(1, Mode::Off),
+    register(17, Mode::Idle),
+    register(31, Mode::Run),
+];
+
+const LEVEL: u8 = TABLE[1].level();
+const MODE: Mode = TABLE[2].mode();
+const OUT_OF_BOUNDS: bool = matches!(Register::new().with_level_checked(32), Err(OutOfBounds));
+const INVALID_MODE: bool = Register::from_bytes([0xC0, 0, 0, 0, 0, 0, 0, 0]).mode_or_err().is_err();
+const BUILT: Register = Register::builder()
+    .with_enable(false)
+    .with_level(3)
+    .with_mode(Mode::Idle)
+    .with_kind(Kind::Data)
+    .with_offset(7)
+    .with_address(0x12)
+    .with_id(0)
+    .with_delta(i8::MIN)
+    .build();
+const BIG: BigRegister = BigRegister::new().with_value(0b101).with_mode(Mode::Run).with_rest(0x3FF);
+
+fn main() {
+    assert_eq!(LEVEL, 17);
+    assert_eq!(MODE, Mode::Run);
+    assert!(OUT_OF_BOUNDS);
+
+    for (n, &(level, mode)) in [(1, Mode::Off), (17, Mode::Idle), (31, Mode::Run)].iter().enumerate() {
+        let mut expected = Register::new();
+        expected.set_enable(true);
+        expected.set_level(level);
+        expected.set_mode(mode);
+        expected.set_kind(Kind::Other(9));
+        expected.set_offset(-3);
+        expected.set_address(0xAB);
+        expected.set_id(0x42);
+        expected.set_delta(-100);
+        expected.set_lanes(1, 0b101);
+        assert_eq!(TABLE[n], expected);
+        assert_eq!(TABLE[n].mode(), mode);
+        assert_eq!(TABLE[n].kind(), Kind::Other(9));
+        assert_eq!(TABLE[n].offset(), -3);
+        assert_eq!(TABLE[n].address(), 0xAB);
+        assert_eq!(TABLE[n].delta(), -100);
+        assert_eq!(TABLE[n].lanes_array(), [0b010, 0b101, 0b010, 0b010]);
+    }
+
+    assert_eq!(BUILT.kind(), Kind::Data);
+    assert_eq!(BUILT.offset(), 7);
+    assert_eq!(BUILT.delta(), i8::MIN);
+    assert_eq!(BUILT.lanes_array(), [0b010; 4]);
+
+    let mut big = BigRegister::new();
+    big.set_value(0b101);
+    big.set_mode(Mode::Run);
+    big.set_rest(0x3FF);
+    assert_eq!(BIG.into_bytes(), big.into_bytes());
+    assert_eq!(BIG.mode(), Mode::Run);
+
+    assert_eq!(Register::new().with_offset_checked(8), Err(OutOfBounds));
+    assert_eq!(Register::new().with_offset_checked(-9), Err(OutOfBounds));
+    assert_eq!(Register::new().with_kind_checked(Kind::Other(16)), Err(OutOfBounds));
+    assert!(Register::new().with_lanes_checked(3, 0b1000).is_err());
+    assert!(INVALID_MODE);
+
+    let custom = Custom::new().with_level(custom::B5(21));
+    assert_eq!(custom.level(), custom::B5(21));
+    assert_eq!(custom.into_bytes(), [21]);
+}
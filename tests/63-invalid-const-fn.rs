@@ -0,0 +1,24 @@
+use modular_bitfield::prelude::*;
+
+#[derive(BitfieldSpecifier)]
+pub enum Mode {
+    Off,
+    On,
+}
+
+#[bitfield]
+pub struct Duplicate {
+    #[const_fn]
+    #[const_fn]
+    mode: Mode,
+    rest: B7,
+}
+
+#[bitfield]
+pub struct WithArguments {
+    #[const_fn(getters)]
+    mode: Mode,
+    rest: B7,
+}
+
+fn main() {}
@@ -0,0 +1,84 @@
+// Tests the `FIELDS` table describing the fields of `#[bitfield]` structs.
+
+use modular_bitfield::layout::{
+    Endian,
+    FieldInfo,
+};
+use modular_bitfield::prelude::*;
+
+#[derive(BitfieldSpecifier)]
+pub enum Mode {
+    Off,
+    Idle,
+    Run,
+    Sleep,
+}
+
+#[bitfield]
+pub struct Register {
+    enable: bool,
+    mode: Mode,
+    #[skip(getters)]
+    level: B5,
+    #[endian = "big"]
+    address: B12,
+    #[at = 24]
+    lanes: [B2; 3],
+    #[reserved = 0b01]
+    __: B2,
+}
+
+#[bitfield(endian = "little")]
+pub struct Tuple(B4, #[skip] B4);
+
+#[bitfield(bits = 8)]
+pub struct Generic<K: Specifier> {
+    kind: K,
+    value: B6,
+}
+
+const FIELDS: &[FieldInfo] = Register::FIELDS;
+const LANES_OFFSET: usize = FIELDS[4].offset();
+
+fn main() {
+    let names = Register::FIELDS.iter().map(FieldInfo::name).collect::<Vec<_>>();
+    assert_eq!(names, ["enable", "mode", "level", "address", "lanes", "__"]);
+    let layout = Register::FIELDS
+        .iter()
+        .map(|field| (field.offset(), field.bits(), field.array_len()))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        layout,
+        [
+            (0, 1, None),
+            (1, 2, None),
+            (3, 5, None),
+            (8, 12, None),
+            (24, 6, Some(3)),
+            (30, 2, None),
+        ],
+    );
+    assert_eq!(LANES_OFFSET, 24);
+    assert_eq!(FIELDS[3].endian(), Endian::Big);
+    assert_eq!(FIELDS[0].endian(), Endian::Native);
+    assert!(FIELDS[2].skip_getters());
+    assert!(!FIELDS[2].skip_setters());
+    assert!(!FIELDS[5].skip_getters());
+    assert!(FIELDS[5].skip_setters());
+
+    // Fields can be walked generically, e.g. to dump the raw bits of a register.
+    let register = Register::new().with_mode(Mode::Run).with_address(0xABC).with_lanes(2, 0b11);
+    let bits = u32::from_le_bytes(register.into_bytes());
+    let raw = |field: &FieldInfo| (bits >> field.offset()) & ((1 << field.bits()) - 1);
+    assert_eq!(raw(&FIELDS[1]), 2);
+    assert_eq!(raw(&FIELDS[4]), 0b11_00_00);
+    assert_eq!(raw(&FIELDS[5]), 0b01);
+
+    assert_eq!(Tuple::FIELDS[1].name(), "1");
+    assert_eq!(Tuple::FIELDS[1].offset(), 4);
+    assert_eq!(Tuple::FIELDS[1].endian(), Endian::Little);
+    assert!(Tuple::FIELDS[1].skip_getters() && Tuple::FIELDS[1].skip_setters());
+
+    assert_eq!(Generic::<Mode>::FIELDS[1].offset(), 2);
+    assert_eq!(Generic::<B2>::FIELDS[0].bits(), 2);
+}
@@ -0,0 +1,90 @@
+// Tests the `FooField` enum and the `get_raw` and `set_raw` methods generated
+// by `#[bitfield(reflect)]`.
+
+use modular_bitfield::error::{
+    OutOfBounds,
+    UnknownField,
+};
+use modular_bitfield::prelude::*;
+
+#[derive(BitfieldSpecifier, Debug, PartialEq)]
+#[bits = 2]
+pub enum Mode {
+    Off,
+    Idle,
+    Run,
+}
+
+#[bitfield(reflect)]
+pub struct Ctrl {
+    enable: bool,
+    mode: Mode,
+    bus_width: B5,
+    #[skip(getters)]
+    hidden: B4,
+    #[endian = "big"]
+    address: B12,
+    lanes: [B2; 3],
+    #[reserved = 0b01]
+    __: B2,
+}
+
+#[bitfield(reflect)]
+pub struct Tuple(B4, #[skip(setters)] B4);
+
+#[bitfield(bits = 8, reflect)]
+pub struct Generic<K: Specifier> {
+    kind: K,
+    value: B6,
+}
+
+fn main() {
+    // Fields are parsed from and displayed as their names.
+    assert_eq!("enable".parse(), Ok(CtrlField::Enable));
+    assert_eq!("bus_width".parse(), Ok(CtrlField::BusWidth));
+    assert_eq!("lanes[2]".parse(), Ok(CtrlField::Lanes(2)));
+    assert_eq!("lanes[3]".parse::<CtrlField>(), Err(UnknownField));
+    assert_eq!("lanes".parse::<CtrlField>(), Err(UnknownField));
+    assert_eq!("hidden".parse::<CtrlField>(), Err(UnknownField));
+    assert_eq!("busy".parse::<CtrlField>(), Err(UnknownField));
+    assert_eq!("__".parse(), Ok(CtrlField::Field6));
+    assert_eq!(CtrlField::BusWidth.to_string(), "bus_width");
+    assert_eq!(CtrlField::Lanes(1).to_string(), "lanes[1]");
+
+    let mut ctrl = Ctrl::new();
+    assert_eq!(ctrl.set_raw("mode".parse().unwrap(), 2), Ok(()));
+    assert_eq!(ctrl.mode(), Mode::Run);
+    assert_eq!(ctrl.get_raw(CtrlField::Mode), 2);
+    // The bit pattern `0b11` is not a valid `Mode`.
+    assert_eq!(ctrl.set_raw(CtrlField::Mode, 3), Err(OutOfBounds));
+    assert_eq!(ctrl.mode(), Mode::Run);
+
+    assert_eq!(ctrl.set_raw(CtrlField::BusWidth, 31), Ok(()));
+    assert_eq!(ctrl.set_raw(CtrlField::BusWidth, 32), Err(OutOfBounds));
+    assert_eq!(ctrl.bus_width(), 31);
+    assert_eq!(ctrl.set_raw(CtrlField::Enable, 1), Ok(()));
+    assert!(ctrl.enable());
+    assert_eq!(ctrl.set_raw(CtrlField::Address, 0xABC), Ok(()));
+    assert_eq!(ctrl.address(), 0xABC);
+    assert_eq!(ctrl.get_raw(CtrlField::Address), 0xABC);
+    assert_eq!(ctrl.set_raw(CtrlField::Lanes(1), 0b10), Ok(()));
+    assert_eq!(ctrl.lanes(1), 0b10);
+    assert_eq!(ctrl.get_raw(CtrlField::Lanes(1)), 0b10);
+    assert_eq!(ctrl.get_raw(CtrlField::Lanes(0)), 0);
+
+    // Fields without setters cannot be set.
+    assert_eq!(ctrl.set_raw(CtrlField::Field6, 0b01), Err(OutOfBounds));
+    assert_eq!(ctrl.get_raw(CtrlField::Field6), 0b01);
+
+    let mut tuple = Tuple::new();
+    assert_eq!("0".parse(), Ok(TupleField::Field0));
+    assert_eq!(tuple.set_raw(TupleField::Field0, 0xF), Ok(()));
+    assert_eq!(tuple.get_0(), 0xF);
+    assert_eq!(tuple.set_raw(TupleField::Field1, 0xF), Err(OutOfBounds));
+
+    let mut generic = Generic::<Mode>::new();
+    assert_eq!(generic.set_raw(GenericField::Kind, 1), Ok(()));
+    assert_eq!(generic.kind(), Mode::Idle);
+    assert_eq!(generic.set_raw(GenericField::Value, 0x3F), Ok(()));
+    assert_eq!(generic.get_raw(GenericField::Value), 0x3F);
+}
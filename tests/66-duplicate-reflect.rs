@@ -0,0 +1,9 @@
+use modular_bitfield::prelude::*;
+
+#[bitfield(reflect, reflect)]
+pub struct Ctrl {
+    enable: bool,
+    mode: B7,
+}
+
+fn main() {}
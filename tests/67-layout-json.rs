@@ -0,0 +1,84 @@
+// Tests the JSON layout of `#[bitfield]` structs returned by `layout_json`.
+
+use modular_bitfield::prelude::*;
+
+#[derive(BitfieldSpecifier)]
+#[bits = 2]
+pub enum Mode {
+    Off,
+    Idle = 2,
+    Run,
+}
+
+#[derive(BitfieldSpecifier)]
+#[bits = 3]
+pub enum Speed {
+    Slow = 1,
+    Fast = 4,
+}
+
+#[bitfield(endian = "little")]
+pub struct Ctrl {
+    enable: bool,
+    mode: Mode,
+    speed: Option<Speed>,
+    #[endian = "big"]
+    address: B12,
+    lanes: [B2; 2],
+    #[reserved = 0]
+    __: B10,
+}
+
+#[bitfield(bit_order = "msb0")]
+pub struct Msb0 {
+    #[none = 0b01]
+    mode: Option<Mode>,
+    rest: B6,
+}
+
+#[bitfield(bits = 8, filled = false)]
+pub struct Small<K: Specifier> {
+    kind: K,
+    #[skip]
+    value: B4,
+}
+
+fn main() {
+    assert_eq!(
+        Ctrl::layout_json().to_string(),
+        concat!(
+            r#"{"name":"Ctrl","bits":32,"bytes":4,"endian":"little","bit_order":"lsb0","fields":["#,
+            r#"{"name":"enable","offset":0,"bits":1,"array_len":null,"endian":"little","specifier":"bool","variants":[]},"#,
+            r#"{"name":"mode","offset":1,"bits":2,"array_len":null,"endian":"little","specifier":"Mode","variants":["#,
+            r#"{"name":"Off","value":0},{"name":"Idle","value":2},{"name":"Run","value":3}]},"#,
+            r#"{"name":"speed","offset":3,"bits":3,"array_len":null,"endian":"little","specifier":"Option<Speed>","variants":["#,
+            r#"{"name":"Slow","value":1},{"name":"Fast","value":4}]},"#,
+            r#"{"name":"address","offset":6,"bits":12,"array_len":null,"endian":"big","specifier":"B12","variants":[]},"#,
+            r#"{"name":"lanes","offset":18,"bits":4,"array_len":2,"endian":"little","specifier":"B2","variants":[]},"#,
+            r#"{"name":"__","offset":22,"bits":10,"array_len":null,"endian":"little","specifier":"B10","variants":[]}"#,
+            r#"]}"#,
+        ),
+    );
+    assert_eq!(
+        Small::<Speed>::layout_json().to_string(),
+        concat!(
+            r#"{"name":"Small","bits":8,"bytes":1,"endian":"native","bit_order":"lsb0","fields":["#,
+            r#"{"name":"kind","offset":0,"bits":3,"array_len":null,"endian":"native","specifier":"K","variants":["#,
+            r#"{"name":"Slow","value":1},{"name":"Fast","value":4}]},"#,
+            r#"{"name":"value","offset":3,"bits":4,"array_len":null,"endian":"native","specifier":"B4","variants":[]}"#,
+            r#"]}"#,
+        ),
+    );
+    assert_eq!(
+        Msb0::layout_json().to_string(),
+        concat!(
+            r#"{"name":"Msb0","bits":8,"bytes":1,"endian":"big","bit_order":"msb0","fields":["#,
+            r#"{"name":"mode","offset":0,"bits":2,"array_len":null,"endian":"big","specifier":"Option<Mode>","variants":["#,
+            r#"{"name":"Off","value":0},{"name":"Idle","value":2},{"name":"Run","value":3}]},"#,
+            r#"{"name":"rest","offset":2,"bits":6,"array_len":null,"endian":"big","specifier":"B6","variants":[]}"#,
+            r#"]}"#,
+        ),
+    );
+    assert_eq!(Ctrl::FIELDS[1].specifier(), "Mode");
+    assert_eq!(Ctrl::FIELDS[1].variants(), &[("Off", 0), ("Idle", 2), ("Run", 3)]);
+}
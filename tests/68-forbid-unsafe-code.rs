@@ -0,0 +1,23 @@
+// `#[bitfield]` structs compile in crates that forbid unsafe code
+// regardless of the enabled crate features.
+
+#![forbid(unsafe_code)]
+
+use modular_bitfield::prelude::*;
+
+#[bitfield]
+pub struct Plain {
+    a: u8,
+}
+
+#[bitfield]
+#[derive(Debug, Clone, Copy)]
+pub struct Copyable {
+    flag: bool,
+    rest: B7,
+}
+
+fn main() {
+    assert_eq!(Plain::new().with_a(3).a(), 3);
+    assert!(Copyable::new().with_flag(true).flag());
+}
@@ -0,0 +1,12 @@
+// `bytemuck::Pod` casts would expose the bit reversed internal bytes.
+
+use modular_bitfield::prelude::*;
+
+#[bitfield(bytemuck, bit_order = "msb0", endian = "little")]
+#[derive(Clone, Copy)]
+pub struct Status {
+    ready: bool,
+    code: B7,
+}
+
+fn main() {}
@@ -0,0 +1,17 @@
+// The `zerocopy` derives would expose the bit reversed internal bytes.
+
+use modular_bitfield::prelude::*;
+use zerocopy::{
+    FromBytes,
+    Immutable,
+    IntoBytes,
+};
+
+#[bitfield(bit_order = "msb0", endian = "little")]
+#[derive(FromBytes, IntoBytes, Immutable)]
+pub struct Status {
+    ready: bool,
+    code: B7,
+}
+
+fn main() {}
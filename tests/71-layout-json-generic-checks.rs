@@ -0,0 +1,13 @@
+// The layout of a generic `#[bitfield]` struct is only available for valid instantiations.
+
+use modular_bitfield::prelude::*;
+
+#[bitfield(bits = 8)]
+pub struct Generic<K: Specifier> {
+    kind: K,
+    value: B4,
+}
+
+fn main() {
+    let _ = Generic::<B5>::layout_json();
+}
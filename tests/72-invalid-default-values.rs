@@ -0,0 +1,47 @@
+// `#[default = N]` values must be valid values of the types of their fields.
+
+use modular_bitfield::prelude::*;
+
+#[derive(BitfieldSpecifier)]
+#[bits = 2]
+pub enum Mode {
+    Off,
+    Idle,
+    Run,
+}
+
+#[bitfield]
+pub struct EnumDefault {
+    #[default = 3]
+    mode: Mode,
+    rest: B6,
+}
+
+#[bitfield]
+pub struct SignedDefault {
+    #[default = -9]
+    offset: S4,
+    rest: B4,
+}
+
+#[bitfield]
+pub struct RangeDefault {
+    #[default = 17]
+    burst: Range<u8, 1, 16>,
+    rest: B4,
+}
+
+#[bitfield]
+pub struct ArrayDefault {
+    #[default = 0]
+    lanes: [Range<u8, 1, 4>; 2],
+    rest: B4,
+}
+
+#[bitfield]
+pub struct NegativeDefault {
+    #[default = -1]
+    level: B8,
+}
+
+fn main() {}
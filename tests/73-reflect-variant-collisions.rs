@@ -0,0 +1,35 @@
+// Fields of `#[bitfield(reflect)]` structs must map onto distinct variants of the field enum.
+
+use modular_bitfield::prelude::*;
+
+#[bitfield(reflect)]
+pub struct Lanes {
+    lane_0: B4,
+    lane0: B4,
+}
+
+#[bitfield(reflect)]
+pub struct Padding {
+    _pad: B4,
+    pad: B4,
+}
+
+#[bitfield(reflect)]
+pub struct Underscores {
+    foo__bar: B4,
+    foo_bar: B4,
+}
+
+#[bitfield(reflect)]
+pub struct Numbered {
+    a: B1,
+    b: B1,
+    c: B1,
+    d: B1,
+    e: B1,
+    field6: B1,
+    #[reserved = 0]
+    __: B2,
+}
+
+fn main() {}
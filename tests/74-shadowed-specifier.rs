@@ -0,0 +1,13 @@
+use modular_bitfield::prelude::*;
+
+/// A user type that shadows the `B5` specifier.
+#[derive(BitfieldSpecifier, Debug, Copy, Clone)]
+pub struct B5(#[bits = 5] pub u8);
+
+#[bitfield]
+pub struct Shadowed {
+    level: B5,
+    rest: B3,
+}
+
+fn main() {}
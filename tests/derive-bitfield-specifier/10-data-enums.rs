@@ -0,0 +1,77 @@
+// Enums with data-carrying variants store the index of their variant as tag in
+// the least significant bits followed by the fields of the variant's payload.
+// The tag requires as many bits as needed to represent all variants and the
+// payload requires as many bits as the biggest payload of all variants.
+
+use modular_bitfield::error::InvalidBitPattern;
+use modular_bitfield::prelude::*;
+
+#[derive(BitfieldSpecifier, Debug, PartialEq, Eq, Copy, Clone)]
+pub enum Register {
+    A,
+    B,
+    C,
+    D,
+}
+
+#[derive(BitfieldSpecifier, Debug, PartialEq, Eq, Copy, Clone)]
+pub enum Cmd {
+    Nop,
+    Read(#[bits = 6] u8),
+    Write { reg: Register, val: bool, #[bits = 3] extra: u8 },
+}
+
+#[bitfield]
+pub struct Instruction {
+    cmd: Cmd,
+    rest: B8,
+}
+
+#[derive(BitfieldSpecifier, Debug, PartialEq, Eq)]
+#[bits = 12]
+pub enum Padded {
+    Empty,
+    Value(u8),
+}
+
+fn main() {
+    // 2 bits of tag followed by 6 bits of payload.
+    assert_eq!(<Cmd as Specifier>::BITS, 8);
+    assert_eq!(<Padded as Specifier>::BITS, 12);
+
+    assert_eq!(<Cmd as Specifier>::into_bytes(Cmd::Nop), Ok(0b00));
+    assert_eq!(<Cmd as Specifier>::into_bytes(Cmd::Read(0b10_1010)), Ok(0b1010_1001));
+    assert_eq!(
+        <Cmd as Specifier>::into_bytes(Cmd::Write { reg: Register::C, val: true, extra: 0b101 }),
+        Ok(0b101_1_10_10)
+    );
+    assert!(<Cmd as Specifier>::into_bytes(Cmd::Read(0b100_0000)).is_err());
+
+    assert_eq!(<Cmd as Specifier>::from_bytes(0b1010_1001), Ok(Cmd::Read(0b10_1010)));
+    // The unused tag `0b11` is an invalid bit pattern.
+    assert_eq!(
+        <Cmd as Specifier>::from_bytes(0b0000_0011),
+        Err(InvalidBitPattern::new(0b0000_0011))
+    );
+    // Bits that are not covered by the payload of `Nop` must be zero.
+    assert_eq!(
+        <Cmd as Specifier>::from_bytes(0b0000_0100),
+        Err(InvalidBitPattern::new(0b0000_0100))
+    );
+
+    let mut instr = Instruction::new();
+    assert_eq!(instr.cmd(), Cmd::Nop);
+    instr.set_cmd(Cmd::Write { reg: Register::D, val: false, extra: 7 });
+    instr.set_rest(0xFF);
+    assert_eq!(instr.cmd(), Cmd::Write { reg: Register::D, val: false, extra: 7 });
+    assert_eq!(instr.rest(), 0xFF);
+    instr.set_cmd(Cmd::Read(42));
+    assert_eq!(instr.cmd(), Cmd::Read(42));
+    assert_eq!(instr.rest(), 0xFF);
+    assert!(instr.set_cmd_checked(Cmd::Read(64)).is_err());
+    assert_eq!(instr.cmd(), Cmd::Read(42));
+
+    assert_eq!(<Padded as Specifier>::into_bytes(Padded::Value(0xAB)), Ok(0x157));
+    assert_eq!(<Padded as Specifier>::from_bytes(0x157), Ok(Padded::Value(0xAB)));
+    assert!(<Padded as Specifier>::from_bytes(0x357).is_err());
+}
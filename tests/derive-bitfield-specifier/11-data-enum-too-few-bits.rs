@@ -0,0 +1,13 @@
+// Data-carrying enums with a #[bits = N] attribute that is too small to hold
+// their tag and their biggest payload should fail to compile.
+
+use modular_bitfield::prelude::*;
+
+#[derive(BitfieldSpecifier)]
+#[bits = 8]
+pub enum Cmd {
+    Nop,
+    Read(u8),
+}
+
+fn main() {}
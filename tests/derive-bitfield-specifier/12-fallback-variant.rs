@@ -0,0 +1,102 @@
+// Enums with a #[fallback] variant decode every bit pattern that does not match
+// the discriminant of any other variant into the fallback variant which carries
+// the raw bits. Therefore getters of such fields never panic.
+
+use modular_bitfield::error::OutOfBounds;
+use modular_bitfield::prelude::*;
+
+#[derive(BitfieldSpecifier, Debug, PartialEq, Eq, Copy, Clone)]
+#[bits = 3]
+#[repr(u8)]
+pub enum Opcode {
+    Load,
+    Store,
+    Jump = 5,
+    #[fallback]
+    Unknown(u8),
+    Halt,
+}
+
+#[derive(BitfieldSpecifier, Debug, PartialEq, Eq, Copy, Clone)]
+#[bits = 12]
+#[repr(u16)]
+pub enum Version {
+    V1 = 0x100,
+    V2 = 0x200,
+    #[fallback]
+    Other(u16),
+}
+
+#[derive(BitfieldSpecifier, Debug, PartialEq, Eq, Copy, Clone)]
+#[bits = 8]
+pub enum Delta {
+    Zero,
+    #[fallback]
+    Signed(i8),
+}
+
+#[bitfield]
+pub struct Deltas {
+    first: Delta,
+    #[const_fn]
+    second: Delta,
+}
+
+#[bitfield]
+pub struct Packet {
+    opcode: Opcode,
+    flags: B5,
+    version: Version,
+    rest: B4,
+}
+
+fn main() {
+    assert_eq!(<Opcode as Specifier>::from_bytes(0), Ok(Opcode::Load));
+    assert_eq!(<Opcode as Specifier>::from_bytes(1), Ok(Opcode::Store));
+    assert_eq!(<Opcode as Specifier>::from_bytes(5), Ok(Opcode::Jump));
+    // `Halt` follows `Unknown` which follows `Jump = 5`.
+    assert_eq!(<Opcode as Specifier>::from_bytes(7), Ok(Opcode::Halt));
+    assert_eq!(<Opcode as Specifier>::from_bytes(2), Ok(Opcode::Unknown(2)));
+    assert_eq!(<Opcode as Specifier>::from_bytes(6), Ok(Opcode::Unknown(6)));
+    assert_eq!(<Opcode as Specifier>::into_bytes(Opcode::Halt), Ok(7));
+    assert_eq!(<Opcode as Specifier>::into_bytes(Opcode::Unknown(3)), Ok(3));
+    assert_eq!(<Opcode as Specifier>::into_bytes(Opcode::Unknown(8)), Err(OutOfBounds));
+    // Fallback values that equal the discriminant of another variant would read back as
+    // that variant.
+    assert_eq!(<Opcode as Specifier>::into_bytes(Opcode::Unknown(5)), Err(OutOfBounds));
+    assert_eq!(<Opcode as Specifier>::into_bytes(Opcode::Unknown(7)), Err(OutOfBounds));
+    assert_eq!(<Version as Specifier>::into_bytes(Version::Other(0x100)), Err(OutOfBounds));
+
+    // Every bit pattern decodes without panicking.
+    for byte in 0..=u8::MAX {
+        let packet = Packet::from_bytes([byte, 0x00, byte]);
+        let _ = packet.opcode();
+        let _ = packet.version();
+    }
+
+    let mut packet = Packet::new()
+        .with_opcode(Opcode::Jump)
+        .with_version(Version::V2)
+        .with_flags(0b1_0101);
+    assert_eq!(packet.opcode(), Opcode::Jump);
+    assert_eq!(packet.version(), Version::V2);
+    assert_eq!(packet.flags(), 0b1_0101);
+
+    packet.set_version(Version::Other(0x123));
+    assert_eq!(packet.version(), Version::Other(0x123));
+    assert_eq!(packet.set_version_checked(Version::Other(0x1000)), Err(OutOfBounds));
+    assert_eq!(packet.version(), Version::Other(0x123));
+    assert_eq!(packet.set_version_checked(Version::Other(0x200)), Err(OutOfBounds));
+    assert_eq!(packet.version(), Version::Other(0x123));
+    assert_eq!(packet.flags(), 0b1_0101);
+
+    // Signed fallback values round trip through the bits of their unsigned counterpart.
+    let deltas = Deltas::from_bytes([0xFF, 0x80]);
+    assert_eq!(deltas.first(), Delta::Signed(-1));
+    assert_eq!(deltas.second(), Delta::Signed(-128));
+    let deltas = Deltas::new()
+        .with_first(deltas.first())
+        .with_second(deltas.second());
+    assert_eq!(deltas.into_bytes(), [0xFF, 0x80]);
+    assert_eq!(<Delta as Specifier>::into_bytes(Delta::Signed(0)), Err(OutOfBounds));
+}
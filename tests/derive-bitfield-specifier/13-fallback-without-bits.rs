@@ -0,0 +1,13 @@
+// Enums with a #[fallback] variant must specify their bit width via #[bits = N].
+
+use modular_bitfield::prelude::*;
+
+#[derive(BitfieldSpecifier)]
+pub enum Opcode {
+    Load,
+    Store,
+    #[fallback]
+    Unknown(u8),
+}
+
+fn main() {}
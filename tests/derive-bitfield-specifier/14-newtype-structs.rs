@@ -0,0 +1,103 @@
+// Tests `#[derive(BitfieldSpecifier)]` on newtype tuple structs.
+
+use modular_bitfield::error::{InvalidBitPattern, OutOfBounds};
+use modular_bitfield::prelude::*;
+
+#[derive(BitfieldSpecifier, Debug, PartialEq, Eq, Copy, Clone)]
+pub struct Id(u16);
+
+#[derive(BitfieldSpecifier, Debug, PartialEq, Eq, Copy, Clone)]
+pub struct Celsius(#[bits = 10] u16);
+
+#[derive(BitfieldSpecifier, Debug, PartialEq, Eq, Copy, Clone)]
+pub struct Enabled(bool);
+
+#[derive(BitfieldSpecifier, Debug, PartialEq, Eq, Copy, Clone)]
+#[bits = 2]
+pub enum Unit {
+    Kelvin,
+    Celsius,
+    Fahrenheit,
+}
+
+#[derive(BitfieldSpecifier, Debug, PartialEq, Eq, Copy, Clone)]
+pub struct DisplayUnit(Unit);
+
+/// A named specifier with the in-out type of `B5`.
+#[derive(BitfieldSpecifier)]
+pub struct Channel(B5);
+
+/// A named specifier with the in-out type of `Range<u8, 1, 16>`.
+#[derive(BitfieldSpecifier)]
+pub struct Burst(Range<u8, 1, 16>);
+
+mod custom {
+    use modular_bitfield::prelude::*;
+
+    /// A user type that shares its name with the `B10` specifier.
+    #[derive(BitfieldSpecifier, Debug, PartialEq, Eq, Copy, Clone)]
+    pub struct B10(#[bits = 10] pub u16);
+}
+
+/// The in-out type is decided by the `Specifier` impl of the field rather than its name.
+#[derive(BitfieldSpecifier, Debug, PartialEq, Eq, Copy, Clone)]
+pub struct Kelvin(custom::B10);
+
+#[bitfield]
+pub struct Extra {
+    kelvin: Kelvin,
+    burst: Burst,
+    rest: B2,
+}
+
+#[bitfield]
+#[derive(Debug)]
+pub struct Reading {
+    id: Id,
+    temperature: Celsius,
+    enabled: Enabled,
+    unit: DisplayUnit,
+    channel: Channel,
+    rest: B6,
+}
+
+fn main() {
+    assert_eq!(<Id as Specifier>::BITS, 16);
+    assert_eq!(<Celsius as Specifier>::BITS, 10);
+    assert_eq!(<Enabled as Specifier>::BITS, 1);
+    assert_eq!(<DisplayUnit as Specifier>::BITS, 2);
+    assert_eq!(<Channel as Specifier>::BITS, 5);
+
+    let mut reading = Reading::new()
+        .with_id(Id(0xBEEF))
+        .with_temperature(Celsius(1000))
+        .with_enabled(Enabled(true))
+        .with_unit(DisplayUnit(Unit::Fahrenheit))
+        .with_channel(31);
+    assert_eq!(reading.id(), Id(0xBEEF));
+    assert_eq!(reading.temperature(), Celsius(1000));
+    assert_eq!(reading.enabled(), Enabled(true));
+    assert_eq!(reading.unit(), DisplayUnit(Unit::Fahrenheit));
+    assert_eq!(reading.channel(), 31);
+
+    assert_eq!(reading.set_temperature_checked(Celsius(1024)), Err(OutOfBounds));
+    assert_eq!(reading.set_channel_checked(32), Err(OutOfBounds));
+    assert_eq!(reading.temperature(), Celsius(1000));
+
+    let extra = Extra::new()
+        .with_kelvin(Kelvin(custom::B10(300)))
+        .with_burst(16);
+    assert_eq!(extra.kelvin(), Kelvin(custom::B10(300)));
+    assert_eq!(extra.burst(), 16);
+    assert_eq!(<Kelvin as Specifier>::BITS, 10);
+    assert_eq!(<Burst as Specifier>::BITS, 4);
+
+    assert_eq!(
+        <Celsius as Specifier>::from_bytes(1024),
+        Err(InvalidBitPattern::new(1024))
+    );
+    assert_eq!(
+        <DisplayUnit as Specifier>::from_bytes(3),
+        Err(InvalidBitPattern::new(3))
+    );
+}
@@ -0,0 +1,6 @@
+use modular_bitfield::prelude::*;
+
+#[derive(BitfieldSpecifier)]
+pub struct Celsius(#[bits = 10] u8);
+
+fn main() {}
@@ -0,0 +1,15 @@
+// The field of the #[fallback] variant must be wide enough to hold all #[bits = N]
+// bits since decoding would otherwise truncate the bit pattern.
+
+use modular_bitfield::prelude::*;
+
+#[derive(BitfieldSpecifier)]
+#[bits = 12]
+pub enum Version {
+    V1,
+    V2,
+    #[fallback]
+    Other(u8),
+}
+
+fn main() {}
@@ -0,0 +1,103 @@
+// Tests `#[bitfield]` specifiers wider than 128 bits nested in other bitfields.
+
+use modular_bitfield::prelude::*;
+
+#[bitfield]
+#[derive(BitfieldSpecifier, Debug, Copy, Clone, PartialEq, Eq)]
+pub struct Descriptor {
+    address: u64,
+    length: u32,
+    flags: B12,
+    id: B52,
+}
+
+#[bitfield]
+#[derive(Debug)]
+pub struct CommandBlock {
+    opcode: B4,
+    descriptor: Descriptor,
+    reserved: B4,
+    tag: B88,
+}
+
+#[bitfield(filled = false)]
+#[derive(BitfieldSpecifier, Debug, Copy, Clone, PartialEq, Eq)]
+pub struct Odd {
+    a: B1,
+    b: B128,
+}
+
+#[bitfield]
+pub struct OddHolder {
+    head: B3,
+    odd: Odd,
+    tail: B4,
+}
+
+#[bitfield(endian = "big")]
+#[derive(BitfieldSpecifier, Copy, Clone, PartialEq, Eq, Debug)]
+pub struct BigDescriptor {
+    address: u64,
+    length: u32,
+    flags: B12,
+    id: B52,
+}
+
+#[bitfield(endian = "big")]
+pub struct BigCommandBlock {
+    opcode: B4,
+    descriptor: BigDescriptor,
+    reserved: B4,
+    tag: B88,
+}
+
+fn main() {
+    assert_eq!(<Descriptor as Specifier>::BITS, 160);
+    assert_eq!(core::mem::size_of::<CommandBlock>(), 32);
+
+    let descriptor = Descriptor::new()
+        .with_address(0xDEAD_BEEF_CAFE_BABE)
+        .with_length(0x1234_5678)
+        .with_flags(0xABC)
+        .with_id(0xF_FFFF_FFFF_FFFF);
+    let block = CommandBlock::new()
+        .with_opcode(0xF)
+        .with_descriptor(descriptor)
+        .with_reserved(0x5)
+        .with_tag(0xFF_FFFF_FFFF_FFFF_FFFF_FFFF);
+    assert_eq!(block.opcode(), 0xF);
+    assert_eq!(block.descriptor(), descriptor);
+    assert_eq!(block.descriptor().flags(), 0xABC);
+    assert_eq!(block.reserved(), 0x5);
+    assert_eq!(block.tag(), 0xFF_FFFF_FFFF_FFFF_FFFF_FFFF);
+
+    // Round trip through the raw bytes of the specifier.
+    let bytes = <Descriptor as Specifier>::into_bytes(descriptor).unwrap();
+    assert_eq!(bytes, descriptor.into_bytes());
+    assert_eq!(<Descriptor as Specifier>::from_bytes(bytes), Ok(descriptor));
+
+    // Unfilled specifiers reject bits beyond their bit width.
+    let odd = Odd::new().with_a(1).with_b(u128::MAX);
+    let holder = OddHolder::new().with_head(0b101).with_odd(odd).with_tail(0b1001);
+    assert_eq!(holder.head(), 0b101);
+    assert_eq!(holder.odd(), odd);
+    assert_eq!(holder.tail(), 0b1001);
+    let mut invalid = [0xFF; 17];
+    invalid[16] = 0b10;
+    assert!(<Odd as Specifier>::from_bytes(invalid).is_err());
+
+    let descriptor = BigDescriptor::new()
+        .with_address(0xDEAD_BEEF_CAFE_BABE)
+        .with_length(0x1234_5678)
+        .with_flags(0xABC)
+        .with_id(0xF_FFFF_FFFF_FFFF);
+    let block = BigCommandBlock::new()
+        .with_opcode(0xA)
+        .with_descriptor(descriptor)
+        .with_reserved(0x5)
+        .with_tag(0x12_3456_789A_BCDE_F012_3456);
+    assert_eq!(block.opcode(), 0xA);
+    assert_eq!(block.descriptor(), descriptor);
+    assert_eq!(block.reserved(), 0x5);
+    assert_eq!(block.tag(), 0x12_3456_789A_BCDE_F012_3456);
+}
@@ -37,3 +37,42 @@ fn invalid_access_d() {
     let mut bytes = EdgeCaseBytes::new();
     bytes.set_d(0b0001_0000_u8);
 }
+
+#[bitfield]
+pub struct ArrayField {
+    lanes: [B4; 4],
+}
+
+#[test]
+#[should_panic(expected = "index out of bounds for field ArrayField.lanes")]
+fn invalid_array_index_get() {
+    let lanes = ArrayField::new();
+    lanes.lanes(4);
+}
+
+#[test]
+#[should_panic(expected = "index out of bounds for field ArrayField.lanes")]
+fn invalid_array_index_set() {
+    let mut lanes = ArrayField::new();
+    lanes.set_lanes(4, 0);
+}
+
+#[bitfield(reflect)]
+pub struct Ctrl {
+    enable: bool,
+    lanes: [B2; 3],
+    #[skip]
+    __: B1,
+}
+
+#[test]
+#[should_panic(expected = "index out of bounds for field Ctrl.lanes")]
+fn invalid_reflect_index_get() {
+    Ctrl::new().get_raw(CtrlField::Lanes(3));
+}
+
+#[test]
+#[should_panic(expected = "index out of bounds for field Ctrl.lanes")]
+fn invalid_reflect_index_set() {
+    let _ = Ctrl::new().set_raw(CtrlField::Lanes(3), 0);
+}
@@ -27,12 +27,74 @@ fn tests() {
     t.compile_fail("tests/26-invalid-struct-specifier.rs");
     t.compile_fail("tests/27-invalid-union-specifier.rs");
     t.pass("tests/28-single-bit-enum.rs");
+    t.pass("tests/29-signed-specifiers.rs");
+    t.pass("tests/30-generic-bitfields.rs");
+    t.compile_fail("tests/31-generic-bitfield-without-bits.rs");
+    t.compile_fail("tests/32-generic-bitfield-wrong-bits.rs");
+    t.pass("tests/33-array-fields.rs");
+    t.pass("tests/34-option-specifiers.rs");
+    t.compile_fail("tests/35-none-on-non-option-field.rs");
+    t.compile_fail("tests/36-option-without-niche.rs");
+    t.pass("tests/37-range-specifiers.rs");
+    t.compile_fail("tests/38-range-invalid-bounds.rs");
+    t.pass("tests/39-explicit-bit-positions.rs");
+    t.compile_fail("tests/40-overlapping-bit-positions.rs");
+    t.compile_fail("tests/41-invalid-bit-range.rs");
+    t.pass("tests/42-reserved-fields.rs");
+    t.compile_fail("tests/43-reserved-field-setters.rs");
+    t.compile_fail("tests/44-reserved-value-too-wide.rs");
+    t.pass("tests/45-bit-order.rs");
+    t.compile_fail("tests/46-invalid-bit-order.rs");
+    t.pass("tests/47-validate.rs");
+    t.pass("tests/48-views.rs");
+    t.compile_fail("tests/49-views-with-bit-order.rs");
+    #[cfg(feature = "bytemuck")]
+    t.pass("tests/50-bytemuck.rs");
+    #[cfg(feature = "zerocopy")]
+    t.pass("tests/51-zerocopy.rs");
+    #[cfg(feature = "zerocopy")]
+    t.compile_fail("tests/52-zerocopy-invalid-bit-patterns.rs");
+    #[cfg(feature = "serde")]
+    t.pass("tests/53-serde.rs");
+    #[cfg(feature = "defmt")]
+    t.pass("tests/54-defmt.rs");
+    #[cfg(feature = "arbitrary")]
+    t.pass("tests/55-arbitrary.rs");
+    #[cfg(feature = "proptest")]
+    t.pass("tests/56-proptest.rs");
+    t.pass("tests/57-defaults.rs");
+    t.compile_fail("tests/58-default-value-too-wide.rs");
+    t.compile_fail("tests/59-invalid-default-variant.rs");
+    t.pass("tests/60-builder.rs");
+    t.compile_fail("tests/61-builder-missing-field.rs");
+    t.pass("tests/62-const-fn.rs");
+    t.compile_fail("tests/63-invalid-const-fn.rs");
+    t.pass("tests/64-field-table.rs");
+    t.pass("tests/65-reflect.rs");
+    t.compile_fail("tests/66-duplicate-reflect.rs");
+    t.pass("tests/67-layout-json.rs");
+    t.pass("tests/68-forbid-unsafe-code.rs");
+    #[cfg(feature = "bytemuck")]
+    t.compile_fail("tests/69-bytemuck-with-bit-order.rs");
+    #[cfg(feature = "zerocopy")]
+    t.compile_fail("tests/70-zerocopy-with-bit-order.rs");
+    t.compile_fail("tests/71-layout-json-generic-checks.rs");
+    t.compile_fail("tests/72-invalid-default-values.rs");
+    t.compile_fail("tests/73-reflect-variant-collisions.rs");
+    t.compile_fail("tests/74-shadowed-specifier.rs");
 
     // Tests specific to the `#[derive(BitfieldSpecifier)]` proc. macro:
     t.pass("tests/derive-bitfield-specifier/06-enums.rs");
     t.pass("tests/derive-bitfield-specifier/07-optional-discriminant.rs");
     t.compile_fail("tests/derive-bitfield-specifier/08-non-power-of-two.rs");
     t.compile_fail("tests/derive-bitfield-specifier/09-variant-out-of-range.rs");
+    t.pass("tests/derive-bitfield-specifier/10-data-enums.rs");
+    t.compile_fail("tests/derive-bitfield-specifier/11-data-enum-too-few-bits.rs");
+    t.pass("tests/derive-bitfield-specifier/12-fallback-variant.rs");
+    t.compile_fail("tests/derive-bitfield-specifier/13-fallback-without-bits.rs");
+    t.pass("tests/derive-bitfield-specifier/14-newtype-structs.rs");
+    t.compile_fail("tests/derive-bitfield-specifier/15-newtype-bits-too-wide.rs");
+    t.compile_fail("tests/derive-bitfield-specifier/16-fallback-too-narrow.rs");
 
     // Tests for regressions found in published versions:
     t.pass("tests/regressions/no-implicit-prelude.rs");
@@ -115,7 +177,7 @@ fn tests() {
     t.pass("tests/derive-specifier/valid-use.rs");
     t.pass("tests/derive-specifier/struct-in-struct.rs");
     t.pass("tests/derive-specifier/unfilled-from-bytes.rs");
-    t.compile_fail("tests/derive-specifier/out-of-bounds.rs");
+    t.pass("tests/derive-specifier/large-specifier.rs");
     t.compile_fail("tests/derive-specifier/duplicate-derive-1.rs");
     t.compile_fail("tests/derive-specifier/duplicate-derive-2.rs");
 